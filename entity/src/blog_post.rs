@@ -25,6 +25,8 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::blog_post_comment::Entity")]
     BlogPostComment,
+    #[sea_orm(has_one = "super::blog_post_draft::Entity")]
+    BlogPostDraft,
     #[sea_orm(has_many = "super::blog_post_image::Entity")]
     BlogPostImage,
     #[sea_orm(has_many = "super::blog_post_reaction::Entity")]
@@ -51,6 +53,12 @@ impl Related<super::blog_post_comment::Entity> for Entity {
     }
 }
 
+impl Related<super::blog_post_draft::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BlogPostDraft.def()
+    }
+}
+
 impl Related<super::blog_post_image::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::BlogPostImage.def()
@@ -18,6 +18,8 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::blog_post_tag::Entity")]
     BlogPostTag,
+    #[sea_orm(has_many = "super::common_tag_alias::Entity")]
+    CommonTagAlias,
 }
 
 impl Related<super::blog_post_tag::Entity> for Entity {
@@ -26,6 +28,12 @@ impl Related<super::blog_post_tag::Entity> for Entity {
     }
 }
 
+impl Related<super::common_tag_alias::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommonTagAlias.def()
+    }
+}
+
 #[async_trait::async_trait]
 impl ActiveModelBehavior for ActiveModel {
     async fn before_save<C: ConnectionTrait>(mut self, _: &C, insert: bool) -> Result<Self, DbErr> {
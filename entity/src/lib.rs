@@ -6,10 +6,12 @@
 
 pub mod prelude;
 
+pub mod audit_log;
 pub mod auth_provider;
 pub mod blog_post;
 pub mod blog_post_comment;
 pub mod blog_post_comment_reaction;
+pub mod blog_post_draft;
 pub mod blog_post_image;
 pub mod blog_post_reaction;
 pub mod blog_post_tag;
@@ -19,6 +21,7 @@ pub mod blog_series_post;
 pub mod blog_series_technology;
 pub mod certificate;
 pub mod common_tag;
+pub mod common_tag_alias;
 pub mod education;
 pub mod experience;
 pub mod open_source_contribution;
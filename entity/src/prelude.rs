@@ -1,9 +1,11 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.16
 
+pub use super::audit_log::Entity as AuditLog;
 pub use super::auth_provider::Entity as AuthProvider;
 pub use super::blog_post::Entity as BlogPost;
 pub use super::blog_post_comment::Entity as BlogPostComment;
 pub use super::blog_post_comment_reaction::Entity as BlogPostCommentReaction;
+pub use super::blog_post_draft::Entity as BlogPostDraft;
 pub use super::blog_post_image::Entity as BlogPostImage;
 pub use super::blog_post_reaction::Entity as BlogPostReaction;
 pub use super::blog_post_tag::Entity as BlogPostTag;
@@ -13,6 +15,7 @@ pub use super::blog_series_post::Entity as BlogSeriesPost;
 pub use super::blog_series_technology::Entity as BlogSeriesTechnology;
 pub use super::certificate::Entity as Certificate;
 pub use super::common_tag::Entity as CommonTag;
+pub use super::common_tag_alias::Entity as CommonTagAlias;
 pub use super::education::Entity as Education;
 pub use super::experience::Entity as Experience;
 pub use super::open_source_contribution::Entity as OpenSourceContribution;
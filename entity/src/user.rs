@@ -20,6 +20,8 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::audit_log::Entity")]
+    AuditLog,
     #[sea_orm(has_many = "super::auth_provider::Entity")]
     AuthProvider,
     #[sea_orm(has_many = "super::blog_post::Entity")]
@@ -28,6 +30,8 @@ pub enum Relation {
     BlogPostComment,
     #[sea_orm(has_many = "super::blog_post_comment_reaction::Entity")]
     BlogPostCommentReaction,
+    #[sea_orm(has_many = "super::blog_post_draft::Entity")]
+    BlogPostDraft,
     #[sea_orm(has_many = "super::blog_post_image::Entity")]
     BlogPostImage,
     #[sea_orm(has_many = "super::blog_post_reaction::Entity")]
@@ -60,6 +64,12 @@ pub enum Relation {
     UserSuspension,
 }
 
+impl Related<super::audit_log::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuditLog.def()
+    }
+}
+
 impl Related<super::auth_provider::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::AuthProvider.def()
@@ -72,6 +82,12 @@ impl Related<super::blog_post_comment_reaction::Entity> for Entity {
     }
 }
 
+impl Related<super::blog_post_draft::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BlogPostDraft.def()
+    }
+}
+
 impl Related<super::blog_post_image::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::BlogPostImage.def()
@@ -191,6 +207,9 @@ impl ActiveModelBehavior for ActiveModel {
         self.updated_at = ActiveValue::Set(current_time);
         if insert {
             self.created_at = ActiveValue::Set(current_time);
+        } else if let Some(version) = self.version.try_as_ref().copied() {
+            // optimistic concurrency, updates should also filter on the old version
+            self.version = ActiveValue::Set(version + 1);
         }
         Ok(self)
     }
@@ -45,6 +45,9 @@ impl ActiveModelBehavior for ActiveModel {
         self.updated_at = ActiveValue::Set(current_time);
         if insert {
             self.created_at = ActiveValue::Set(current_time);
+        } else if let Some(version) = self.version.try_as_ref().copied() {
+            // optimistic concurrency, updates should also filter on the old version
+            self.version = ActiveValue::Set(version + 1);
         }
         Ok(self)
     }
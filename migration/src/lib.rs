@@ -10,6 +10,11 @@ mod m20250908_030112_create_users;
 mod m20250909_050520_create_portfolio;
 mod m20250911_185836_create_cv;
 mod m20250911_205500_create_blog;
+mod m20261018_090000_create_audit_log;
+mod m20261018_120000_create_blog_post_draft;
+mod m20261018_140000_add_search_vectors;
+mod m20261018_160000_add_blog_series_post_position;
+mod m20261018_170000_create_common_tag_alias;
 
 pub struct Migrator;
 
@@ -21,6 +26,11 @@ impl MigratorTrait for Migrator {
             Box::new(m20250909_050520_create_portfolio::Migration),
             Box::new(m20250911_185836_create_cv::Migration),
             Box::new(m20250911_205500_create_blog::Migration),
+            Box::new(m20261018_090000_create_audit_log::Migration),
+            Box::new(m20261018_120000_create_blog_post_draft::Migration),
+            Box::new(m20261018_140000_add_search_vectors::Migration),
+            Box::new(m20261018_160000_add_blog_series_post_position::Migration),
+            Box::new(m20261018_170000_create_common_tag_alias::Migration),
         ]
     }
 }
@@ -430,6 +430,7 @@ impl MigrationTrait for Migration {
     }
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(DeriveIden)]
 #[sea_orm(table_name = "summaries")]
 enum Summary {
@@ -821,7 +821,7 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum CommonTag {
+pub enum CommonTag {
     Table,
     Id,
     Name,
@@ -830,7 +830,7 @@ enum CommonTag {
 }
 
 #[derive(DeriveIden)]
-enum BlogPost {
+pub enum BlogPost {
     Table,
     Id,
     Title,
@@ -915,7 +915,7 @@ enum BlogSeries {
 }
 
 #[derive(DeriveIden)]
-enum BlogSeriesPost {
+pub enum BlogSeriesPost {
     Table,
     BlogSeriesId,
     BlogPostId,
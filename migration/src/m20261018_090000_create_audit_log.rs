@@ -0,0 +1,132 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use sea_orm_migration::{
+    prelude::{extension::postgres::Type, *},
+    sea_orm::{EnumIter, Iterable},
+};
+
+use super::m20250908_030112_create_users::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(AuditActionEnum)
+                    .values(AuditAction::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create audit log table, entries outlive the user that made them
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditLog::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuditLog::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AuditLog::UserId).integer().null())
+                    .col(ColumnDef::new(AuditLog::Entity).string_len(50).not_null())
+                    .col(ColumnDef::new(AuditLog::EntityId).integer().not_null())
+                    .col(
+                        ColumnDef::new(AuditLog::Action)
+                            .enumeration(AuditActionEnum, AuditAction::iter())
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuditLog::Changes).json_binary().not_null())
+                    .col(ColumnDef::new(AuditLog::CreatedAt).timestamp().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("audit_log_user_id_fk")
+                            .from(AuditLog::Table, AuditLog::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("audit_log_entity_entity_id_idx")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::Entity)
+                    .col(AuditLog::EntityId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("audit_log_user_id_idx")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("audit_log_created_at_idx")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditLog::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(AuditActionEnum).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub struct AuditActionEnum;
+
+#[derive(Iden, EnumIter)]
+pub enum AuditAction {
+    #[iden = "create"]
+    Create,
+    #[iden = "update"]
+    Update,
+    #[iden = "delete"]
+    Delete,
+}
+
+#[derive(DeriveIden)]
+enum AuditLog {
+    Table,
+    Id,
+    UserId,
+    Entity,
+    EntityId,
+    Action,
+    Changes,
+    CreatedAt,
+}
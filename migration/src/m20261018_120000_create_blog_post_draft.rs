@@ -0,0 +1,76 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use sea_orm_migration::prelude::*;
+
+use super::{m20250908_030112_create_users::User, m20250911_205500_create_blog::BlogPost};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create blog post drafts table, the editor's autosaved, not yet
+        // saved, changes to a post
+        manager
+            .create_table(
+                Table::create()
+                    .table(BlogPostDraft::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BlogPostDraft::BlogPostId)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(BlogPostDraft::UpdatedById).integer().null())
+                    .col(
+                        ColumnDef::new(BlogPostDraft::Title)
+                            .string_len(200)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(BlogPostDraft::Content).text().not_null())
+                    .col(
+                        ColumnDef::new(BlogPostDraft::UpdatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("blog_post_draft_blog_post_id_fk")
+                            .from(BlogPostDraft::Table, BlogPostDraft::BlogPostId)
+                            .to(BlogPost::Table, BlogPost::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("blog_post_draft_updated_by_id_fk")
+                            .from(BlogPostDraft::Table, BlogPostDraft::UpdatedById)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BlogPostDraft::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum BlogPostDraft {
+    Table,
+    BlogPostId,
+    UpdatedById,
+    Title,
+    Content,
+    UpdatedAt,
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use sea_orm_migration::prelude::*;
+
+use super::{
+    m20250909_050520_create_portfolio::{Project, Technology},
+    m20250911_205500_create_blog::BlogPost,
+};
+
+/// Full-text search column of the searchable tables, kept up to date by
+/// PostgreSQL from their title and text, the title ranking higher.
+#[derive(DeriveIden)]
+enum SearchVector {
+    SearchVector,
+}
+
+/// Generated `tsvector` over `title`, weighted `A`, and `text`, weighted `B`.
+fn search_vector(title: &str, text: &str) -> ColumnDef {
+    ColumnDef::new(SearchVector::SearchVector)
+        .custom(Alias::new("tsvector"))
+        .not_null()
+        .extra(format!(
+            "GENERATED ALWAYS AS (\
+             setweight(to_tsvector('english', coalesce({title}, '')), 'A') || \
+             setweight(to_tsvector('english', coalesce({text}, '')), 'B')\
+             ) STORED"
+        ))
+        .to_owned()
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Add search vectors to blog posts, projects and technologies
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BlogPost::Table)
+                    .add_column_if_not_exists(&mut search_vector("title", "content"))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Project::Table)
+                    .add_column_if_not_exists(&mut search_vector("title", "description"))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Technology::Table)
+                    .add_column_if_not_exists(&mut search_vector("name", "description"))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Index them for `@@` queries
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("blog_post_search_vector_idx")
+                    .table(BlogPost::Table)
+                    .col(SearchVector::SearchVector)
+                    .full_text()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("project_search_vector_idx")
+                    .table(Project::Table)
+                    .col(SearchVector::SearchVector)
+                    .full_text()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("technology_search_vector_idx")
+                    .table(Technology::Table)
+                    .col(SearchVector::SearchVector)
+                    .full_text()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Dropping the columns drops their indexes
+        for table in [
+            BlogPost::Table.into_iden(),
+            Project::Table.into_iden(),
+            Technology::Table.into_iden(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(SearchVector::SearchVector)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
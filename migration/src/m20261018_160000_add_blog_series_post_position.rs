@@ -0,0 +1,91 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20250911_205500_create_blog::BlogSeriesPost;
+
+/// Place of a post in its series, from 1.
+#[derive(DeriveIden)]
+enum Position {
+    Position,
+}
+
+/// Numbers the posts already in a series by publication, the order readers
+/// saw them in.
+const BACKFILL_POSITIONS: &str = r#"
+UPDATE blog_series_post
+SET position = ordered.position
+FROM (
+    SELECT blog_series_post.blog_series_id, blog_series_post.blog_post_id,
+           row_number() OVER (
+               PARTITION BY blog_series_post.blog_series_id
+               ORDER BY coalesce(blog_post.first_published_at, blog_post.published_at, blog_post.created_at),
+                        blog_post.id
+           ) AS position
+    FROM blog_series_post
+    JOIN blog_post ON blog_post.id = blog_series_post.blog_post_id
+) AS ordered
+WHERE blog_series_post.blog_series_id = ordered.blog_series_id
+  AND blog_series_post.blog_post_id = ordered.blog_post_id
+"#;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Add the position of posts in their series, required once backfilled
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BlogSeriesPost::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Position::Position).integer().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared(BACKFILL_POSITIONS)
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BlogSeriesPost::Table)
+                    .modify_column(ColumnDef::new(Position::Position).integer().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // A position holds a single post, the index also reads a series in
+        // order
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("blog_series_post_position_uidx")
+                    .table(BlogSeriesPost::Table)
+                    .col(BlogSeriesPost::BlogSeriesId)
+                    .col(Position::Position)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Dropping the column drops its index
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BlogSeriesPost::Table)
+                    .drop_column(Position::Position)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20250911_205500_create_blog::CommonTag;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create common tag aliases table, the names of merged tags and the
+        // tag they were merged into
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommonTagAlias::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommonTagAlias::Name)
+                            .string_len(100)
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CommonTagAlias::CommonTagId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommonTagAlias::CreatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("common_tag_alias_common_tag_id_fk")
+                            .from(CommonTagAlias::Table, CommonTagAlias::CommonTagId)
+                            .to(CommonTag::Table, CommonTag::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Index it for repointing the aliases of a tag when it is merged too
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("common_tag_alias_common_tag_id_idx")
+                    .table(CommonTagAlias::Table)
+                    .col(CommonTagAlias::CommonTagId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommonTagAlias::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CommonTagAlias {
+    Table,
+    Name,
+    CommonTagId,
+    CreatedAt,
+}
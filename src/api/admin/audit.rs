@@ -0,0 +1,192 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ContentKind;
+use crate::api::ApiError;
+
+/// How many entries the audit log shows.
+#[cfg(feature = "ssr")]
+const PAGE_SIZE: u64 = 100;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditChange {
+    pub field: String,
+    /// `None` for created rows.
+    pub from: Option<String>,
+    /// `None` for deleted rows.
+    pub to: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub id: i32,
+    /// Name of the staff member, `None` once their account is deleted.
+    pub user: Option<String>,
+    pub kind: Option<ContentKind>,
+    pub entity_id: i32,
+    /// "created", "updated" or "deleted".
+    pub action: String,
+    pub changes: Vec<AuditChange>,
+    pub created_at: String,
+}
+
+/// Changed fields of a form, as `{ field: { from, to } }`. Created rows only
+/// have `to` values and deleted rows only `from` values.
+#[cfg(feature = "ssr")]
+fn diff(before: Option<serde_json::Value>, after: Option<serde_json::Value>) -> serde_json::Value {
+    use serde_json::{Map, Value, json};
+
+    let as_map = |value: Option<Value>| match value {
+        Some(Value::Object(map)) => map,
+        _ => Map::new(),
+    };
+    let before = as_map(before);
+    let mut after = as_map(after);
+
+    let mut changes = Map::new();
+    for (field, from) in before {
+        match after.remove(&field) {
+            Some(to) if to == from => {}
+            Some(to) => {
+                changes.insert(field, json!({ "from": from, "to": to }));
+            }
+            None => {
+                changes.insert(field, json!({ "from": from }));
+            }
+        }
+    }
+    for (field, to) in after {
+        changes.insert(field, json!({ "to": to }));
+    }
+    Value::Object(changes)
+}
+
+/// Records that `user_id` created, updated or deleted row `entity_id`, given
+/// its form before and after the change. Updates that change nothing are not
+/// recorded.
+#[cfg(feature = "ssr")]
+pub(crate) async fn record<C, T>(
+    db: &C,
+    user_id: i32,
+    kind: ContentKind,
+    entity_id: i32,
+    action: entity::sea_orm_active_enums::AuditActionEnum,
+    before: Option<&T>,
+    after: Option<&T>,
+) -> Result<(), ApiError>
+where
+    C: sea_orm::ConnectionTrait,
+    T: Serialize,
+{
+    use entity::audit_log;
+    use sea_orm::{ActiveModelTrait, ActiveValue::Set};
+
+    let to_json = |form: Option<&T>| {
+        form.map(serde_json::to_value)
+            .transpose()
+            .map_err(|err| ApiError::Internal(err.to_string()))
+    };
+    let changes = diff(to_json(before)?, to_json(after)?);
+    if changes
+        .as_object()
+        .is_some_and(|changes| changes.is_empty())
+    {
+        return Ok(());
+    }
+
+    audit_log::ActiveModel {
+        user_id: Set(Some(user_id)),
+        entity: Set(kind.as_str().to_string()),
+        entity_id: Set(entity_id),
+        action: Set(action),
+        changes: Set(changes),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    Ok(())
+}
+
+#[cfg(feature = "ssr")]
+fn display(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(value) if value.is_empty() => None,
+        serde_json::Value::String(value) => Some(value.clone()),
+        value => Some(value.to_string()),
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<(entity::audit_log::Model, Option<entity::user::Model>)> for AuditEntry {
+    fn from((entry, user): (entity::audit_log::Model, Option<entity::user::Model>)) -> Self {
+        use entity::sea_orm_active_enums::AuditActionEnum;
+
+        let changes = match entry.changes {
+            serde_json::Value::Object(changes) => changes
+                .into_iter()
+                .map(|(field, change)| AuditChange {
+                    from: change.get("from").and_then(display),
+                    to: change.get("to").and_then(display),
+                    field,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Self {
+            id: entry.id,
+            user: user.map(|user| user.name),
+            kind: entry.entity.parse().ok(),
+            entity_id: entry.entity_id,
+            action: match entry.action {
+                AuditActionEnum::Create => "created",
+                AuditActionEnum::Update => "updated",
+                AuditActionEnum::Delete => "deleted",
+            }
+            .to_string(),
+            changes,
+            created_at: entry.created_at.format("%d %b %Y, %H:%M UTC").to_string(),
+        }
+    }
+}
+
+/// Latest changes, optionally only those of `kind` or of one of its rows.
+#[server]
+pub async fn list_audit_log(
+    #[server(default)] kind: Option<ContentKind>,
+    #[server(default)] entity_id: Option<i32>,
+) -> Result<Vec<AuditEntry>, ApiError> {
+    use entity::{
+        audit_log,
+        prelude::{AuditLog, User},
+    };
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    require_staff().await?;
+    let db = use_database()?;
+
+    let mut query = AuditLog::find().find_also_related(User);
+    if let Some(kind) = kind {
+        query = query.filter(audit_log::Column::Entity.eq(kind.as_str()));
+    }
+    if let Some(entity_id) = entity_id {
+        query = query.filter(audit_log::Column::EntityId.eq(entity_id));
+    }
+    let entries = query
+        .order_by_desc(audit_log::Column::CreatedAt)
+        .order_by_desc(audit_log::Column::Id)
+        .limit(PAGE_SIZE)
+        .all(&db)
+        .await?;
+
+    Ok(entries.into_iter().map(AuditEntry::from).collect())
+}
@@ -0,0 +1,427 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Authoring of blog posts. The editor autosaves to a draft kept apart from
+//! the post, so readers only see changes once they are saved.
+
+use std::str::FromStr;
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use super::{NAME_LENGTH, URL_LENGTH, optional, required};
+use crate::api::ApiError;
+
+/// Where a post is in its publication.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PostStatus {
+    /// Only visible to staff.
+    #[default]
+    Draft,
+    /// Published by the background publisher once `published_at` comes.
+    Scheduled,
+    Published,
+}
+
+impl PostStatus {
+    pub const ALL: [Self; 3] = [Self::Draft, Self::Scheduled, Self::Published];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Scheduled => "scheduled",
+            Self::Published => "published",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Draft => "Draft",
+            Self::Scheduled => "Scheduled",
+            Self::Published => "Published",
+        }
+    }
+}
+
+impl FromStr for PostStatus {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str() == value)
+            .ok_or(())
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<&entity::blog_post::Model> for PostStatus {
+    fn from(post: &entity::blog_post::Model) -> Self {
+        let now = chrono::Utc::now().naive_utc();
+        match post.published_at {
+            Some(published_at) if published_at > now => Self::Scheduled,
+            Some(_) if post.is_published => Self::Published,
+            // due, waiting for the publisher
+            Some(_) => Self::Scheduled,
+            None => Self::Draft,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PostForm {
+    pub title: String,
+    /// Markdown of the post.
+    pub content: String,
+    pub cover_image_url: String,
+    #[serde(default)]
+    pub is_featured: bool,
+    pub status: PostStatus,
+    /// `datetime-local` value, in UTC, of when a scheduled post is published.
+    #[serde(default)]
+    pub publish_at: String,
+    /// Comma separated tags, normalized when saved.
+    #[serde(default)]
+    pub tags: String,
+}
+
+/// Autosaved, not yet saved, changes to a post.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PostDraft {
+    pub title: String,
+    pub content: String,
+    pub saved_at: String,
+}
+
+/// Uploaded image of a post, referenced from its markdown as `image:{id}`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PostImage {
+    pub id: i32,
+    pub url: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PostEditor {
+    pub id: i32,
+    pub slug: String,
+    pub form: PostForm,
+    pub first_published_at: Option<String>,
+    pub draft: Option<PostDraft>,
+    pub images: Vec<PostImage>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdminPostRow {
+    pub id: i32,
+    pub title: String,
+    pub status: PostStatus,
+    /// Publication date, or the scheduled one.
+    pub published_at: String,
+    pub updated_at: String,
+}
+
+#[cfg(feature = "ssr")]
+fn saved_at(date: chrono::NaiveDateTime) -> String {
+    date.format("%d %b %Y, %H:%M:%S UTC").to_string()
+}
+
+#[cfg(feature = "ssr")]
+async fn find_post<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    id: i32,
+) -> Result<entity::blog_post::Model, ApiError> {
+    use entity::prelude::BlogPost;
+    use sea_orm::EntityTrait;
+
+    BlogPost::find_by_id(id)
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Post not found."))
+}
+
+/// Every post, drafts included, newest first.
+#[server]
+pub async fn list_admin_posts() -> Result<Vec<AdminPostRow>, ApiError> {
+    use entity::{blog_post, prelude::BlogPost};
+    use sea_orm::{EntityTrait, QueryOrder};
+
+    use crate::{
+        server::{database::use_database, guards::require_staff},
+        utils::dates::day_month_year,
+    };
+
+    require_staff().await?;
+    let db = use_database()?;
+
+    let posts = BlogPost::find()
+        .order_by_desc(blog_post::Column::UpdatedAt)
+        .all(&db)
+        .await?;
+
+    Ok(posts
+        .into_iter()
+        .map(|post| AdminPostRow {
+            id: post.id,
+            status: PostStatus::from(&post),
+            published_at: post.published_at.map(day_month_year).unwrap_or_default(),
+            updated_at: day_month_year(post.updated_at),
+            title: post.title,
+        })
+        .collect())
+}
+
+/// Starts a draft post owned by the site owner, returning its id.
+#[server]
+pub async fn create_post(title: String) -> Result<i32, ApiError> {
+    use entity::blog_post;
+    use sea_orm::{ActiveModelTrait, ActiveValue::Set};
+
+    use crate::server::{
+        config::use_config, database::use_database, guards::require_staff, site::find_owner,
+    };
+
+    let staff = require_staff().await?;
+    let title = required("Title", title, Some(NAME_LENGTH))?;
+    let config = use_config()?;
+    let db = use_database()?;
+    let owner = find_owner(&db, &config.site).await?;
+
+    let post = blog_post::ActiveModel {
+        title: Set(title),
+        content: Set(String::new()),
+        created_by_id: Set(owner.id),
+        ..Default::default()
+    }
+    .insert(&db)
+    .await?;
+
+    log::info!("staff {} created post {}", staff.id, post.id);
+    Ok(post.id)
+}
+
+#[server]
+pub async fn get_post_editor(id: i32) -> Result<PostEditor, ApiError> {
+    use entity::{
+        blog_post_image,
+        prelude::{BlogPostDraft, BlogPostImage},
+    };
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+    use crate::{
+        api::tags::post_tags,
+        server::{database::use_database, guards::require_staff},
+        utils::{
+            dates::{datetime_input, day_month_year},
+            slug::id_slug,
+        },
+    };
+
+    require_staff().await?;
+    let db = use_database()?;
+    let post = find_post(&db, id).await?;
+    let draft = BlogPostDraft::find_by_id(id).one(&db).await?;
+    let images = BlogPostImage::find()
+        .filter(blog_post_image::Column::BlogPostId.eq(id))
+        .order_by_asc(blog_post_image::Column::Id)
+        .all(&db)
+        .await?
+        .into_iter()
+        .map(|image| PostImage {
+            id: image.id,
+            url: image.image_url,
+        })
+        .collect();
+    let tags = post_tags(&db, id).await?;
+
+    Ok(PostEditor {
+        id: post.id,
+        slug: id_slug(post.id, &post.title),
+        first_published_at: post.first_published_at.map(day_month_year),
+        draft: draft.map(|draft| PostDraft {
+            title: draft.title,
+            content: draft.content,
+            saved_at: saved_at(draft.updated_at),
+        }),
+        images,
+        form: PostForm {
+            status: PostStatus::from(&post),
+            publish_at: post.published_at.map(datetime_input).unwrap_or_default(),
+            title: post.title,
+            content: post.content,
+            cover_image_url: post.cover_image_url.unwrap_or_default(),
+            is_featured: post.is_featured,
+            tags: tags.join(", "),
+        },
+    })
+}
+
+/// Keeps the editor's unsaved title and content, returning when they were
+/// saved.
+#[server]
+pub async fn autosave_post(id: i32, title: String, content: String) -> Result<String, ApiError> {
+    use chrono::Utc;
+    use entity::{blog_post_draft, prelude::BlogPostDraft};
+    use sea_orm::{ActiveValue::Set, EntityTrait, sea_query::OnConflict};
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    let staff = require_staff().await?;
+    if title.chars().count() > NAME_LENGTH {
+        return Err(ApiError::bad_request(format!(
+            "Title must have at most {NAME_LENGTH} characters."
+        )));
+    }
+    let db = use_database()?;
+    find_post(&db, id).await?;
+
+    // one draft per post, `Entity::insert` skips `before_save`
+    let now = Utc::now().naive_utc();
+    BlogPostDraft::insert(blog_post_draft::ActiveModel {
+        blog_post_id: Set(id),
+        updated_by_id: Set(Some(staff.id)),
+        title: Set(title),
+        content: Set(content),
+        updated_at: Set(now),
+    })
+    .on_conflict(
+        OnConflict::column(blog_post_draft::Column::BlogPostId)
+            .update_columns([
+                blog_post_draft::Column::UpdatedById,
+                blog_post_draft::Column::Title,
+                blog_post_draft::Column::Content,
+                blog_post_draft::Column::UpdatedAt,
+            ])
+            .to_owned(),
+    )
+    .exec_without_returning(&db)
+    .await?;
+
+    Ok(saved_at(now))
+}
+
+#[server]
+pub async fn discard_draft(id: i32) -> Result<(), ApiError> {
+    use entity::prelude::BlogPostDraft;
+    use sea_orm::EntityTrait;
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    require_staff().await?;
+    let db = use_database()?;
+    BlogPostDraft::delete_by_id(id).exec(&db).await?;
+    Ok(())
+}
+
+/// Renders unsaved markdown of post `id` as readers would see it.
+#[server]
+pub async fn preview_post(id: i32, content: String) -> Result<String, ApiError> {
+    use crate::{
+        api::blog::render_content,
+        server::{database::use_database, guards::require_staff},
+    };
+
+    require_staff().await?;
+    let db = use_database()?;
+    Ok(render_content(&db, id, &content).await?.html)
+}
+
+/// Saves post `id`, replacing its draft, and moves it to the form's status.
+///
+/// Publishing sets `published_at` to now unless the post is already
+/// published, while `first_published_at` is only ever set once, so an edited
+/// or republished post keeps its original date. Drafts have no
+/// `published_at`, which would otherwise get them published by the
+/// background publisher. Tags are normalized and linked to their common tag.
+#[server]
+pub async fn save_post(id: i32, post: PostForm) -> Result<(), ApiError> {
+    use chrono::Utc;
+    use entity::prelude::BlogPostDraft;
+    use sea_orm::{ActiveModelTrait, ActiveValue::Set, EntityTrait, TransactionTrait};
+
+    use crate::{
+        api::tags::{parse_tags, set_post_tags},
+        server::{database::use_database, guards::require_staff},
+        utils::dates::parse_datetime_input,
+    };
+
+    let staff = require_staff().await?;
+    let title = required("Title", post.title, Some(NAME_LENGTH))?;
+    let cover_image_url = optional("Cover image URL", post.cover_image_url, Some(URL_LENGTH))?;
+    let tags = parse_tags(&post.tags)?;
+    if post.status != PostStatus::Draft && post.content.trim().is_empty() {
+        return Err(ApiError::bad_request("Content is required to publish."));
+    }
+
+    let db = use_database()?;
+    let txn = db.begin().await?;
+    let existing = find_post(&txn, id).await?;
+    let now = Utc::now().naive_utc();
+    let (is_published, published_at) = match post.status {
+        PostStatus::Draft => (false, None),
+        PostStatus::Scheduled => {
+            let publish_at = parse_datetime_input(&post.publish_at).ok_or_else(|| {
+                ApiError::bad_request("Publish date must be a valid date and time.")
+            })?;
+            if publish_at <= now {
+                return Err(ApiError::bad_request(
+                    "Scheduled posts must be published in the future.",
+                ));
+            }
+            (false, Some(publish_at))
+        }
+        PostStatus::Published => match existing.published_at {
+            Some(published_at) if existing.is_published && published_at <= now => {
+                (true, Some(published_at))
+            }
+            _ => (true, Some(now)),
+        },
+    };
+    let first_published_at = match (existing.first_published_at, is_published) {
+        (Some(first_published_at), _) => Some(first_published_at),
+        (None, true) => published_at,
+        (None, false) => None,
+    };
+
+    let mut model: entity::blog_post::ActiveModel = existing.into();
+    model.title = Set(title);
+    model.content = Set(post.content);
+    model.cover_image_url = Set(cover_image_url);
+    model.is_featured = Set(post.is_featured);
+    model.is_published = Set(is_published);
+    model.published_at = Set(published_at);
+    model.first_published_at = Set(first_published_at);
+    model.update(&txn).await?;
+    set_post_tags(&txn, id, staff.id, &tags).await?;
+    BlogPostDraft::delete_by_id(id).exec(&txn).await?;
+    txn.commit().await?;
+
+    log::info!(
+        "staff {} saved post {id} as {}",
+        staff.id,
+        post.status.as_str()
+    );
+    Ok(())
+}
+
+#[server]
+pub async fn delete_post(id: i32) -> Result<(), ApiError> {
+    use entity::prelude::BlogPost;
+    use sea_orm::EntityTrait;
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    let staff = require_staff().await?;
+    let db = use_database()?;
+    let result = BlogPost::delete_by_id(id).exec(&db).await?;
+    if result.rows_affected == 0 {
+        return Err(ApiError::not_found("Post not found."));
+    }
+
+    log::info!("staff {} deleted post {id}", staff.id);
+    Ok(())
+}
@@ -0,0 +1,560 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use super::{
+    ContentForm, ContentKind, ContentRow, GRADE_LENGTH, NAME_LENGTH, URL_LENGTH,
+    VERIFICATION_CODE_LENGTH, date, get_form, optional, optional_date, optional_date_input, period,
+    required, save_form,
+};
+use crate::api::{ApiError, cv::CodeSource};
+
+/// First line of a text, shortened for admin lists.
+#[cfg(feature = "ssr")]
+pub(super) fn preview(text: &str) -> String {
+    const PREVIEW_LENGTH: usize = 80;
+
+    let line = text.lines().next().unwrap_or_default().trim();
+    if line.chars().count() > PREVIEW_LENGTH {
+        let preview = line.chars().take(PREVIEW_LENGTH).collect::<String>();
+        format!("{}…", preview.trim_end())
+    } else {
+        line.to_string()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExperienceForm {
+    pub company: String,
+    pub location: String,
+    pub title: String,
+    pub start_date: String,
+    pub end_date: String,
+    /// One achievement per line.
+    pub achievements: String,
+    #[serde(default)]
+    pub is_current: bool,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::experience::Model> for ExperienceForm {
+    fn from(model: entity::experience::Model) -> Self {
+        use crate::utils::dates::date_input;
+
+        Self {
+            company: model.company,
+            location: model.location,
+            title: model.title,
+            start_date: date_input(model.start_date),
+            end_date: optional_date_input(model.end_date),
+            achievements: model.achievements.unwrap_or_default(),
+            is_current: model.is_current,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl ContentForm for ExperienceForm {
+    type Entity = entity::experience::Entity;
+    type ActiveModel = entity::experience::ActiveModel;
+
+    const KIND: ContentKind = ContentKind::Experience;
+
+    fn apply(self, model: &mut Self::ActiveModel) -> Result<(), ApiError> {
+        use sea_orm::ActiveValue::Set;
+
+        let start_date = date("Start date", &self.start_date)?;
+        let end_date = optional_date("End date", &self.end_date)?;
+        period(start_date, end_date, "End date")?;
+
+        model.company = Set(required("Company", self.company, Some(NAME_LENGTH))?);
+        model.location = Set(required("Location", self.location, Some(NAME_LENGTH))?);
+        model.title = Set(required("Title", self.title, Some(NAME_LENGTH))?);
+        model.start_date = Set(start_date);
+        model.end_date = Set(end_date);
+        model.achievements = Set(optional("Achievements", self.achievements, None)?);
+        model.is_current = Set(self.is_current);
+        Ok(())
+    }
+
+    fn set_created_by(model: &mut Self::ActiveModel, user_id: i32) {
+        model.created_by_id = sea_orm::ActiveValue::Set(user_id);
+    }
+
+    fn id(model: &entity::experience::Model) -> i32 {
+        model.id
+    }
+
+    fn row(model: &entity::experience::Model) -> ContentRow {
+        use crate::utils::dates::{date_range, day_month_year};
+
+        ContentRow {
+            id: model.id,
+            title: format!("{} at {}", model.title, model.company),
+            details: date_range(
+                model.start_date,
+                model.end_date.filter(|_| !model.is_current),
+            ),
+            updated_at: day_month_year(model.updated_at),
+        }
+    }
+
+    fn order_column() -> entity::experience::Column {
+        entity::experience::Column::StartDate
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EducationForm {
+    pub institution: String,
+    pub location: String,
+    pub degree: String,
+    pub start_date: String,
+    pub end_date: String,
+    pub grade: String,
+    pub description: String,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::education::Model> for EducationForm {
+    fn from(model: entity::education::Model) -> Self {
+        use crate::utils::dates::date_input;
+
+        Self {
+            institution: model.institution,
+            location: model.location,
+            degree: model.degree,
+            start_date: date_input(model.start_date),
+            end_date: optional_date_input(model.end_date),
+            grade: model.grade.unwrap_or_default(),
+            description: model.description.unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl ContentForm for EducationForm {
+    type Entity = entity::education::Entity;
+    type ActiveModel = entity::education::ActiveModel;
+
+    const KIND: ContentKind = ContentKind::Education;
+
+    fn apply(self, model: &mut Self::ActiveModel) -> Result<(), ApiError> {
+        use sea_orm::ActiveValue::Set;
+
+        let start_date = date("Start date", &self.start_date)?;
+        let end_date = optional_date("End date", &self.end_date)?;
+        period(start_date, end_date, "End date")?;
+
+        model.institution = Set(required(
+            "Institution",
+            self.institution,
+            Some(NAME_LENGTH),
+        )?);
+        model.location = Set(required("Location", self.location, Some(NAME_LENGTH))?);
+        model.degree = Set(required("Degree", self.degree, Some(NAME_LENGTH))?);
+        model.start_date = Set(start_date);
+        model.end_date = Set(end_date);
+        model.grade = Set(optional("Grade", self.grade, Some(GRADE_LENGTH))?);
+        model.description = Set(optional("Description", self.description, None)?);
+        Ok(())
+    }
+
+    fn set_created_by(model: &mut Self::ActiveModel, user_id: i32) {
+        model.created_by_id = sea_orm::ActiveValue::Set(user_id);
+    }
+
+    fn id(model: &entity::education::Model) -> i32 {
+        model.id
+    }
+
+    fn row(model: &entity::education::Model) -> ContentRow {
+        use crate::utils::dates::{date_range, day_month_year};
+
+        ContentRow {
+            id: model.id,
+            title: format!("{}, {}", model.degree, model.institution),
+            details: date_range(model.start_date, model.end_date),
+            updated_at: day_month_year(model.updated_at),
+        }
+    }
+
+    fn order_column() -> entity::education::Column {
+        entity::education::Column::StartDate
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CertificateForm {
+    pub title: String,
+    pub issuer: String,
+    pub issue_date: String,
+    pub expiration_date: String,
+    pub verification_code: String,
+    pub description: String,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::certificate::Model> for CertificateForm {
+    fn from(model: entity::certificate::Model) -> Self {
+        use crate::utils::dates::date_input;
+
+        Self {
+            title: model.title,
+            issuer: model.issuer,
+            issue_date: date_input(model.issue_date),
+            expiration_date: optional_date_input(model.expiration_date),
+            verification_code: model.verification_code.unwrap_or_default(),
+            description: model.description.unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl ContentForm for CertificateForm {
+    type Entity = entity::certificate::Entity;
+    type ActiveModel = entity::certificate::ActiveModel;
+
+    const KIND: ContentKind = ContentKind::Certificate;
+
+    fn apply(self, model: &mut Self::ActiveModel) -> Result<(), ApiError> {
+        use sea_orm::ActiveValue::Set;
+
+        let issue_date = date("Issue date", &self.issue_date)?;
+        let expiration_date = optional_date("Expiration date", &self.expiration_date)?;
+        period(issue_date, expiration_date, "Expiration date")?;
+
+        model.title = Set(required("Title", self.title, Some(NAME_LENGTH))?);
+        model.issuer = Set(required("Issuer", self.issuer, Some(NAME_LENGTH))?);
+        model.issue_date = Set(issue_date);
+        model.expiration_date = Set(expiration_date);
+        model.verification_code = Set(optional(
+            "Verification code",
+            self.verification_code,
+            Some(VERIFICATION_CODE_LENGTH),
+        )?);
+        model.description = Set(optional("Description", self.description, None)?);
+        Ok(())
+    }
+
+    fn set_created_by(model: &mut Self::ActiveModel, user_id: i32) {
+        model.created_by_id = sea_orm::ActiveValue::Set(user_id);
+    }
+
+    fn id(model: &entity::certificate::Model) -> i32 {
+        model.id
+    }
+
+    fn row(model: &entity::certificate::Model) -> ContentRow {
+        use crate::utils::dates::{day_month_year, month_year};
+
+        ContentRow {
+            id: model.id,
+            title: model.title.clone(),
+            details: format!("{} · {}", model.issuer, month_year(model.issue_date)),
+            updated_at: day_month_year(model.updated_at),
+        }
+    }
+
+    fn order_column() -> entity::certificate::Column {
+        entity::certificate::Column::IssueDate
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillForm {
+    pub technology_id: i32,
+    pub initial_usage_date: String,
+    pub last_usage_date: String,
+    #[serde(default)]
+    pub is_currently_used: bool,
+    pub description: String,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::skill::Model> for SkillForm {
+    fn from(model: entity::skill::Model) -> Self {
+        use crate::utils::dates::date_input;
+
+        Self {
+            technology_id: model.technology_id,
+            initial_usage_date: date_input(model.initial_usage_date),
+            last_usage_date: optional_date_input(model.last_usage_date),
+            is_currently_used: model.is_currently_used,
+            description: model.description.unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl ContentForm for SkillForm {
+    type Entity = entity::skill::Entity;
+    type ActiveModel = entity::skill::ActiveModel;
+
+    const KIND: ContentKind = ContentKind::Skill;
+
+    async fn check(&self, txn: &sea_orm::DatabaseTransaction) -> Result<(), ApiError> {
+        use entity::prelude::Technology;
+        use sea_orm::EntityTrait;
+
+        Technology::find_by_id(self.technology_id)
+            .one(txn)
+            .await?
+            .map(|_| ())
+            .ok_or_else(|| ApiError::bad_request("Unknown technology."))
+    }
+
+    fn apply(self, model: &mut Self::ActiveModel) -> Result<(), ApiError> {
+        use sea_orm::ActiveValue::Set;
+
+        let initial_usage_date = date("First used", &self.initial_usage_date)?;
+        let last_usage_date = optional_date("Last used", &self.last_usage_date)?;
+        period(initial_usage_date, last_usage_date, "Last used")?;
+        if !self.is_currently_used && last_usage_date.is_none() {
+            return Err(ApiError::bad_request(
+                "Last used is required for skills no longer used.",
+            ));
+        }
+
+        model.technology_id = Set(self.technology_id);
+        model.initial_usage_date = Set(initial_usage_date);
+        model.last_usage_date = Set(last_usage_date);
+        model.is_currently_used = Set(self.is_currently_used);
+        model.description = Set(optional("Description", self.description, None)?);
+        Ok(())
+    }
+
+    fn set_created_by(model: &mut Self::ActiveModel, user_id: i32) {
+        model.created_by_id = sea_orm::ActiveValue::Set(user_id);
+    }
+
+    fn id(model: &entity::skill::Model) -> i32 {
+        model.id
+    }
+
+    /// Titled with the technology id, which [`super::list_content`] replaces
+    /// with the technology's name.
+    fn row(model: &entity::skill::Model) -> ContentRow {
+        use crate::{
+            api::cv::skill_last_usage,
+            utils::dates::{date_range, day_month_year},
+        };
+
+        ContentRow {
+            id: model.id,
+            title: model.technology_id.to_string(),
+            details: date_range(model.initial_usage_date, skill_last_usage(model)),
+            updated_at: day_month_year(model.updated_at),
+        }
+    }
+
+    fn order_column() -> entity::skill::Column {
+        entity::skill::Column::InitialUsageDate
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SummaryForm {
+    pub summary: String,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::summary::Model> for SummaryForm {
+    fn from(model: entity::summary::Model) -> Self {
+        Self {
+            summary: model.summary,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl ContentForm for SummaryForm {
+    type Entity = entity::summary::Entity;
+    type ActiveModel = entity::summary::ActiveModel;
+
+    const KIND: ContentKind = ContentKind::Summary;
+
+    fn apply(self, model: &mut Self::ActiveModel) -> Result<(), ApiError> {
+        model.summary = sea_orm::ActiveValue::Set(required("Summary", self.summary, None)?);
+        Ok(())
+    }
+
+    fn set_created_by(model: &mut Self::ActiveModel, user_id: i32) {
+        model.created_by_id = sea_orm::ActiveValue::Set(user_id);
+    }
+
+    fn id(model: &entity::summary::Model) -> i32 {
+        model.id
+    }
+
+    fn row(model: &entity::summary::Model) -> ContentRow {
+        use crate::utils::dates::day_month_year;
+
+        ContentRow {
+            id: model.id,
+            title: preview(&model.summary),
+            details: String::new(),
+            updated_at: day_month_year(model.updated_at),
+        }
+    }
+
+    fn order_column() -> entity::summary::Column {
+        entity::summary::Column::UpdatedAt
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenSourceContributionForm {
+    pub url: String,
+    pub name: String,
+    pub source: CodeSource,
+    pub description: String,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::open_source_contribution::Model> for OpenSourceContributionForm {
+    fn from(model: entity::open_source_contribution::Model) -> Self {
+        Self {
+            url: model.url,
+            name: model.name,
+            source: model.source.into(),
+            description: model.description.unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl ContentForm for OpenSourceContributionForm {
+    type Entity = entity::open_source_contribution::Entity;
+    type ActiveModel = entity::open_source_contribution::ActiveModel;
+
+    const KIND: ContentKind = ContentKind::OpenSourceContribution;
+
+    fn apply(self, model: &mut Self::ActiveModel) -> Result<(), ApiError> {
+        use sea_orm::ActiveValue::Set;
+
+        let url = required("URL", self.url, Some(URL_LENGTH))?;
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return Err(ApiError::bad_request("URL must start with https://."));
+        }
+
+        model.url = Set(url);
+        model.name = Set(required("Name", self.name, Some(NAME_LENGTH))?);
+        model.source = Set(self.source.into());
+        model.description = Set(optional("Description", self.description, None)?);
+        Ok(())
+    }
+
+    fn set_created_by(model: &mut Self::ActiveModel, user_id: i32) {
+        model.created_by_id = sea_orm::ActiveValue::Set(user_id);
+    }
+
+    fn id(model: &entity::open_source_contribution::Model) -> i32 {
+        model.id
+    }
+
+    fn row(model: &entity::open_source_contribution::Model) -> ContentRow {
+        use crate::utils::dates::day_month_year;
+
+        ContentRow {
+            id: model.id,
+            title: model.name.clone(),
+            details: format!(
+                "{} · {}",
+                CodeSource::from(model.source.clone()).label(),
+                model.url
+            ),
+            updated_at: day_month_year(model.updated_at),
+        }
+    }
+
+    fn order_column() -> entity::open_source_contribution::Column {
+        entity::open_source_contribution::Column::UpdatedAt
+    }
+}
+
+#[server]
+pub async fn get_experience_form(id: i32) -> Result<ExperienceForm, ApiError> {
+    get_form(id).await
+}
+
+#[server]
+pub async fn save_experience(
+    #[server(default)] id: Option<i32>,
+    experience: ExperienceForm,
+) -> Result<i32, ApiError> {
+    save_form(id, experience).await
+}
+
+#[server]
+pub async fn get_education_form(id: i32) -> Result<EducationForm, ApiError> {
+    get_form(id).await
+}
+
+#[server]
+pub async fn save_education(
+    #[server(default)] id: Option<i32>,
+    education: EducationForm,
+) -> Result<i32, ApiError> {
+    save_form(id, education).await
+}
+
+#[server]
+pub async fn get_certificate_form(id: i32) -> Result<CertificateForm, ApiError> {
+    get_form(id).await
+}
+
+#[server]
+pub async fn save_certificate(
+    #[server(default)] id: Option<i32>,
+    certificate: CertificateForm,
+) -> Result<i32, ApiError> {
+    save_form(id, certificate).await
+}
+
+#[server]
+pub async fn get_skill_form(id: i32) -> Result<SkillForm, ApiError> {
+    get_form(id).await
+}
+
+#[server]
+pub async fn save_skill(
+    #[server(default)] id: Option<i32>,
+    skill: SkillForm,
+) -> Result<i32, ApiError> {
+    save_form(id, skill).await
+}
+
+#[server]
+pub async fn get_summary_form(id: i32) -> Result<SummaryForm, ApiError> {
+    get_form(id).await
+}
+
+#[server]
+pub async fn save_summary(
+    #[server(default)] id: Option<i32>,
+    summary: SummaryForm,
+) -> Result<i32, ApiError> {
+    save_form(id, summary).await
+}
+
+#[server]
+pub async fn get_open_source_contribution_form(
+    id: i32,
+) -> Result<OpenSourceContributionForm, ApiError> {
+    get_form(id).await
+}
+
+#[server]
+pub async fn save_open_source_contribution(
+    #[server(default)] id: Option<i32>,
+    contribution: OpenSourceContributionForm,
+) -> Result<i32, ApiError> {
+    save_form(id, contribution).await
+}
@@ -0,0 +1,470 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Staff-only editing of the site's content. Every change is recorded in the
+//! audit log with the staff member who made it.
+
+pub mod audit;
+pub mod blog;
+pub mod cv;
+pub mod portfolio;
+pub mod resume;
+pub mod series;
+pub mod tags;
+
+use std::str::FromStr;
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ApiError;
+
+/// Longest names, titles, companies, etc., the `string_len(200)` columns.
+pub const NAME_LENGTH: usize = 200;
+/// Longest project subtitle.
+pub const SUBTITLE_LENGTH: usize = 300;
+/// Longest education grade.
+pub const GRADE_LENGTH: usize = 50;
+/// Longest certificate verification code.
+pub const VERIFICATION_CODE_LENGTH: usize = 100;
+/// Longest URL.
+pub const URL_LENGTH: usize = 500;
+
+/// Content tables staff can edit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentKind {
+    Project,
+    Technology,
+    Experience,
+    Education,
+    Certificate,
+    Skill,
+    Summary,
+    OpenSourceContribution,
+}
+
+impl ContentKind {
+    pub const ALL: [Self; 8] = [
+        Self::Project,
+        Self::Technology,
+        Self::Experience,
+        Self::Education,
+        Self::Certificate,
+        Self::Skill,
+        Self::Summary,
+        Self::OpenSourceContribution,
+    ];
+
+    /// Table name, as stored in the audit log.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Project => "project",
+            Self::Technology => "technology",
+            Self::Experience => "experience",
+            Self::Education => "education",
+            Self::Certificate => "certificate",
+            Self::Skill => "skill",
+            Self::Summary => "summary",
+            Self::OpenSourceContribution => "open_source_contribution",
+        }
+    }
+
+    /// Path segment of the admin screens, e.g. `/admin/projects`.
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::Project => "projects",
+            Self::Technology => "technologies",
+            Self::Experience => "experience",
+            Self::Education => "education",
+            Self::Certificate => "certificates",
+            Self::Skill => "skills",
+            Self::Summary => "summaries",
+            Self::OpenSourceContribution => "open-source",
+        }
+    }
+
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.slug() == slug)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Project => "Project",
+            Self::Technology => "Technology",
+            Self::Experience => "Experience",
+            Self::Education => "Education",
+            Self::Certificate => "Certificate",
+            Self::Skill => "Skill",
+            Self::Summary => "Summary",
+            Self::OpenSourceContribution => "Open source contribution",
+        }
+    }
+
+    pub fn plural_label(self) -> &'static str {
+        match self {
+            Self::Project => "Projects",
+            Self::Technology => "Technologies",
+            Self::Experience => "Experience",
+            Self::Education => "Education",
+            Self::Certificate => "Certificates",
+            Self::Skill => "Skills",
+            Self::Summary => "Summaries",
+            Self::OpenSourceContribution => "Open source contributions",
+        }
+    }
+}
+
+impl FromStr for ContentKind {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == value)
+            .ok_or(())
+    }
+}
+
+/// Row of an admin list.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentRow {
+    pub id: i32,
+    pub title: String,
+    pub details: String,
+    pub updated_at: String,
+}
+
+/// Form of one of the content tables, with its values as the inputs hold
+/// them, e.g. dates as `YYYY-MM-DD` and empty strings for missing values.
+#[cfg(feature = "ssr")]
+pub(crate) trait ContentForm:
+    Serialize + Sized + From<<Self::Entity as sea_orm::EntityTrait>::Model>
+{
+    type Entity: sea_orm::EntityTrait;
+    type ActiveModel: sea_orm::ActiveModelTrait<Entity = Self::Entity>
+        + sea_orm::ActiveModelBehavior
+        + From<<Self::Entity as sea_orm::EntityTrait>::Model>
+        + Send;
+
+    const KIND: ContentKind;
+
+    /// Checks the values against the database, e.g. that the rows they
+    /// reference exist, before they are applied.
+    fn check(
+        &self,
+        _txn: &sea_orm::DatabaseTransaction,
+    ) -> impl Future<Output = Result<(), ApiError>> + Send {
+        async { Ok(()) }
+    }
+
+    /// Validates the values and sets them on `model`.
+    fn apply(self, model: &mut Self::ActiveModel) -> Result<(), ApiError>;
+
+    fn set_created_by(model: &mut Self::ActiveModel, user_id: i32);
+
+    fn id(model: &<Self::Entity as sea_orm::EntityTrait>::Model) -> i32;
+
+    fn row(model: &<Self::Entity as sea_orm::EntityTrait>::Model) -> ContentRow;
+
+    /// Column the admin list is sorted by, newest first.
+    fn order_column() -> <Self::Entity as sea_orm::EntityTrait>::Column;
+}
+
+#[cfg(feature = "ssr")]
+fn invalid(message: String) -> ApiError {
+    ApiError::bad_request(message)
+}
+
+/// Trims a required value, checking it fits `max_length` characters.
+#[cfg(feature = "ssr")]
+pub(crate) fn required(
+    label: &str,
+    value: String,
+    max_length: Option<usize>,
+) -> Result<String, ApiError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(invalid(format!("{label} is required.")));
+    }
+    match max_length {
+        Some(max_length) if value.chars().count() > max_length => Err(invalid(format!(
+            "{label} must have at most {max_length} characters."
+        ))),
+        _ => Ok(value.to_string()),
+    }
+}
+
+/// Trims an optional value, `None` when empty.
+#[cfg(feature = "ssr")]
+pub(crate) fn optional(
+    label: &str,
+    value: String,
+    max_length: Option<usize>,
+) -> Result<Option<String>, ApiError> {
+    if value.trim().is_empty() {
+        return Ok(None);
+    }
+    required(label, value, max_length).map(Some)
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn date(label: &str, value: &str) -> Result<chrono::NaiveDate, ApiError> {
+    crate::utils::dates::parse_date_input(value)
+        .ok_or_else(|| invalid(format!("{label} must be a valid date.")))
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn optional_date(
+    label: &str,
+    value: &str,
+) -> Result<Option<chrono::NaiveDate>, ApiError> {
+    if value.trim().is_empty() {
+        return Ok(None);
+    }
+    date(label, value).map(Some)
+}
+
+/// Checks a period does not end before it starts.
+#[cfg(feature = "ssr")]
+pub(crate) fn period(
+    start: chrono::NaiveDate,
+    end: Option<chrono::NaiveDate>,
+    end_label: &str,
+) -> Result<(), ApiError> {
+    match end {
+        Some(end) if end < start => Err(invalid(format!(
+            "{end_label} cannot be before the start date."
+        ))),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn optional_date_input(date: Option<chrono::NaiveDate>) -> String {
+    date.map(crate::utils::dates::date_input)
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "ssr")]
+fn content_not_found(kind: ContentKind) -> ApiError {
+    ApiError::not_found(format!("{} not found.", kind.label()))
+}
+
+/// Loads the form of row `id`.
+#[cfg(feature = "ssr")]
+pub(crate) async fn get_form<F>(id: i32) -> Result<F, ApiError>
+where
+    F: ContentForm,
+    i32: Into<
+        <<F::Entity as sea_orm::EntityTrait>::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType,
+    >,
+{
+    use sea_orm::EntityTrait;
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    require_staff().await?;
+    let db = use_database()?;
+
+    F::Entity::find_by_id(id)
+        .one(&db)
+        .await?
+        .map(F::from)
+        .ok_or_else(|| content_not_found(F::KIND))
+}
+
+/// Creates a row, owned by the site owner so it shows on the site, or updates
+/// row `id`, recording the change. Returns the row's id.
+#[cfg(feature = "ssr")]
+pub(crate) async fn save_form<F>(id: Option<i32>, form: F) -> Result<i32, ApiError>
+where
+    F: ContentForm,
+    <F::Entity as sea_orm::EntityTrait>::Model: sea_orm::IntoActiveModel<F::ActiveModel>,
+    i32: Into<
+        <<F::Entity as sea_orm::EntityTrait>::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType,
+    >,
+{
+    use entity::sea_orm_active_enums::AuditActionEnum;
+    use sea_orm::{ActiveModelTrait, EntityTrait, TransactionTrait};
+
+    use crate::server::{
+        config::use_config, database::use_database, guards::require_staff, site::find_owner,
+    };
+
+    let staff = require_staff().await?;
+    let db = use_database()?;
+    let txn = db.begin().await?;
+    form.check(&txn).await?;
+
+    let saved = match id {
+        Some(id) => {
+            let existing = F::Entity::find_by_id(id)
+                .one(&txn)
+                .await?
+                .ok_or_else(|| content_not_found(F::KIND))?;
+            let before = F::from(existing.clone());
+            let mut model = F::ActiveModel::from(existing);
+            form.apply(&mut model)?;
+            let saved = model.update(&txn).await?;
+            let after = F::from(saved.clone());
+            audit::record(
+                &txn,
+                staff.id,
+                F::KIND,
+                id,
+                AuditActionEnum::Update,
+                Some(&before),
+                Some(&after),
+            )
+            .await?;
+            saved
+        }
+        None => {
+            let config = use_config()?;
+            let owner = find_owner(&txn, &config.site).await?;
+            let mut model = F::ActiveModel::default();
+            F::set_created_by(&mut model, owner.id);
+            form.apply(&mut model)?;
+            let saved = model.insert(&txn).await?;
+            let after = F::from(saved.clone());
+            audit::record(
+                &txn,
+                staff.id,
+                F::KIND,
+                F::id(&saved),
+                AuditActionEnum::Create,
+                None,
+                Some(&after),
+            )
+            .await?;
+            saved
+        }
+    };
+    txn.commit().await?;
+
+    log::info!(
+        "staff {} saved {} {}",
+        staff.id,
+        F::KIND.as_str(),
+        F::id(&saved)
+    );
+    Ok(F::id(&saved))
+}
+
+#[cfg(feature = "ssr")]
+async fn delete_form<F>(id: i32, staff_id: i32) -> Result<(), ApiError>
+where
+    F: ContentForm,
+    i32: Into<
+        <<F::Entity as sea_orm::EntityTrait>::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType,
+    >,
+{
+    use entity::sea_orm_active_enums::AuditActionEnum;
+    use sea_orm::{EntityTrait, TransactionTrait};
+
+    use crate::server::database::use_database;
+
+    let db = use_database()?;
+    let txn = db.begin().await?;
+
+    let existing = F::Entity::find_by_id(id)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| content_not_found(F::KIND))?;
+    F::Entity::delete_by_id(id).exec(&txn).await?;
+    audit::record(
+        &txn,
+        staff_id,
+        F::KIND,
+        id,
+        AuditActionEnum::Delete,
+        Some(&F::from(existing)),
+        None,
+    )
+    .await?;
+    txn.commit().await?;
+
+    log::info!("staff {staff_id} deleted {} {id}", F::KIND.as_str());
+    Ok(())
+}
+
+#[cfg(feature = "ssr")]
+async fn list_rows<F>(db: &sea_orm::DatabaseConnection) -> Result<Vec<ContentRow>, ApiError>
+where
+    F: ContentForm,
+{
+    use sea_orm::{EntityTrait, QueryOrder};
+
+    Ok(F::Entity::find()
+        .order_by_desc(F::order_column())
+        .all(db)
+        .await?
+        .iter()
+        .map(F::row)
+        .collect())
+}
+
+#[server]
+pub async fn list_content(kind: ContentKind) -> Result<Vec<ContentRow>, ApiError> {
+    use std::collections::HashMap;
+
+    use entity::prelude::Technology;
+    use sea_orm::EntityTrait;
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    require_staff().await?;
+    let db = use_database()?;
+
+    match kind {
+        ContentKind::Project => list_rows::<portfolio::ProjectForm>(&db).await,
+        ContentKind::Technology => list_rows::<portfolio::TechnologyForm>(&db).await,
+        ContentKind::Experience => list_rows::<cv::ExperienceForm>(&db).await,
+        ContentKind::Education => list_rows::<cv::EducationForm>(&db).await,
+        ContentKind::Certificate => list_rows::<cv::CertificateForm>(&db).await,
+        ContentKind::Skill => {
+            // skills are named after their technology
+            let names = Technology::find()
+                .all(&db)
+                .await?
+                .into_iter()
+                .map(|technology| (technology.id.to_string(), technology.name))
+                .collect::<HashMap<_, _>>();
+            let mut rows = list_rows::<cv::SkillForm>(&db).await?;
+            for row in &mut rows {
+                if let Some(name) = names.get(&row.title) {
+                    row.title = name.clone();
+                }
+            }
+            Ok(rows)
+        }
+        ContentKind::Summary => list_rows::<cv::SummaryForm>(&db).await,
+        ContentKind::OpenSourceContribution => {
+            list_rows::<cv::OpenSourceContributionForm>(&db).await
+        }
+    }
+}
+
+#[server]
+pub async fn delete_content(kind: ContentKind, id: i32) -> Result<(), ApiError> {
+    use crate::server::guards::require_staff;
+
+    let staff = require_staff().await?;
+
+    match kind {
+        ContentKind::Project => delete_form::<portfolio::ProjectForm>(id, staff.id).await,
+        ContentKind::Technology => delete_form::<portfolio::TechnologyForm>(id, staff.id).await,
+        ContentKind::Experience => delete_form::<cv::ExperienceForm>(id, staff.id).await,
+        ContentKind::Education => delete_form::<cv::EducationForm>(id, staff.id).await,
+        ContentKind::Certificate => delete_form::<cv::CertificateForm>(id, staff.id).await,
+        ContentKind::Skill => delete_form::<cv::SkillForm>(id, staff.id).await,
+        ContentKind::Summary => delete_form::<cv::SummaryForm>(id, staff.id).await,
+        ContentKind::OpenSourceContribution => {
+            delete_form::<cv::OpenSourceContributionForm>(id, staff.id).await
+        }
+    }
+}
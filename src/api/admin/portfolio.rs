@@ -0,0 +1,209 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use super::{
+    ContentForm, ContentKind, ContentRow, NAME_LENGTH, SUBTITLE_LENGTH, date, get_form, optional,
+    optional_date, optional_date_input, period, required, save_form,
+};
+use crate::api::{ApiError, portfolio::ProjectStatus, technologies::TechnologyType};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectForm {
+    pub title: String,
+    pub subtitle: String,
+    pub description: String,
+    pub status: ProjectStatus,
+    pub start_date: String,
+    pub end_date: String,
+    pub drop_date: String,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::project::Model> for ProjectForm {
+    fn from(model: entity::project::Model) -> Self {
+        use crate::utils::dates::date_input;
+
+        Self {
+            title: model.title,
+            subtitle: model.subtitle.unwrap_or_default(),
+            description: model.description,
+            status: model.status.into(),
+            start_date: date_input(model.start_date),
+            end_date: optional_date_input(model.end_date),
+            drop_date: optional_date_input(model.drop_date),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl ContentForm for ProjectForm {
+    type Entity = entity::project::Entity;
+    type ActiveModel = entity::project::ActiveModel;
+
+    const KIND: ContentKind = ContentKind::Project;
+
+    fn apply(self, model: &mut Self::ActiveModel) -> Result<(), ApiError> {
+        use sea_orm::ActiveValue::Set;
+
+        let start_date = date("Start date", &self.start_date)?;
+        let end_date = optional_date("End date", &self.end_date)?;
+        let drop_date = optional_date("Drop date", &self.drop_date)?;
+        period(start_date, end_date, "End date")?;
+        period(start_date, drop_date, "Drop date")?;
+
+        model.title = Set(required("Title", self.title, Some(NAME_LENGTH))?);
+        model.subtitle = Set(optional("Subtitle", self.subtitle, Some(SUBTITLE_LENGTH))?);
+        model.description = Set(required("Description", self.description, None)?);
+        model.status = Set(self.status.into());
+        model.start_date = Set(start_date);
+        model.end_date = Set(end_date);
+        model.drop_date = Set(drop_date);
+        Ok(())
+    }
+
+    fn set_created_by(model: &mut Self::ActiveModel, user_id: i32) {
+        model.created_by_id = sea_orm::ActiveValue::Set(user_id);
+    }
+
+    fn id(model: &entity::project::Model) -> i32 {
+        model.id
+    }
+
+    fn row(model: &entity::project::Model) -> ContentRow {
+        use crate::utils::dates::{date_range, day_month_year};
+
+        ContentRow {
+            id: model.id,
+            title: model.title.clone(),
+            details: format!(
+                "{} · {}",
+                ProjectStatus::from(model.status.clone()).label(),
+                date_range(model.start_date, model.end_date)
+            ),
+            updated_at: day_month_year(model.updated_at),
+        }
+    }
+
+    fn order_column() -> entity::project::Column {
+        entity::project::Column::StartDate
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TechnologyForm {
+    pub name: String,
+    /// URL of the icon.
+    pub icon: String,
+    pub tech_type: TechnologyType,
+    pub description: String,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::technology::Model> for TechnologyForm {
+    fn from(model: entity::technology::Model) -> Self {
+        Self {
+            name: model.name,
+            icon: model.icon,
+            tech_type: model.tech_type.into(),
+            description: model.description.unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl ContentForm for TechnologyForm {
+    type Entity = entity::technology::Entity;
+    type ActiveModel = entity::technology::ActiveModel;
+
+    const KIND: ContentKind = ContentKind::Technology;
+
+    fn apply(self, model: &mut Self::ActiveModel) -> Result<(), ApiError> {
+        use sea_orm::ActiveValue::Set;
+
+        model.name = Set(required("Name", self.name, Some(NAME_LENGTH))?);
+        model.icon = Set(required("Icon", self.icon, None)?);
+        model.tech_type = Set(self.tech_type.into());
+        model.description = Set(optional("Description", self.description, None)?);
+        Ok(())
+    }
+
+    fn set_created_by(model: &mut Self::ActiveModel, user_id: i32) {
+        model.created_by_id = sea_orm::ActiveValue::Set(user_id);
+    }
+
+    fn id(model: &entity::technology::Model) -> i32 {
+        model.id
+    }
+
+    fn row(model: &entity::technology::Model) -> ContentRow {
+        use crate::utils::dates::day_month_year;
+
+        ContentRow {
+            id: model.id,
+            title: model.name.clone(),
+            details: TechnologyType::from(model.tech_type.clone())
+                .label()
+                .to_string(),
+            updated_at: day_month_year(model.updated_at),
+        }
+    }
+
+    fn order_column() -> entity::technology::Column {
+        entity::technology::Column::UpdatedAt
+    }
+}
+
+#[server]
+pub async fn get_project_form(id: i32) -> Result<ProjectForm, ApiError> {
+    get_form(id).await
+}
+
+/// Creates a project, or updates project `id`, returning its id.
+#[server]
+pub async fn save_project(
+    #[server(default)] id: Option<i32>,
+    project: ProjectForm,
+) -> Result<i32, ApiError> {
+    save_form(id, project).await
+}
+
+#[server]
+pub async fn get_technology_form(id: i32) -> Result<TechnologyForm, ApiError> {
+    get_form(id).await
+}
+
+/// Creates a technology, or updates technology `id`, returning its id.
+#[server]
+pub async fn save_technology(
+    #[server(default)] id: Option<i32>,
+    technology: TechnologyForm,
+) -> Result<i32, ApiError> {
+    save_form(id, technology).await
+}
+
+/// Technologies to pick from, e.g. for a skill, sorted by name.
+#[server]
+pub async fn list_technology_options() -> Result<Vec<(i32, String)>, ApiError> {
+    use entity::{prelude::Technology, technology};
+    use sea_orm::{EntityTrait, QueryOrder};
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    require_staff().await?;
+    let db = use_database()?;
+
+    Ok(Technology::find()
+        .order_by_asc(technology::Column::Name)
+        .all(&db)
+        .await?
+        .into_iter()
+        .map(|technology| (technology.id, technology.name))
+        .collect())
+}
@@ -0,0 +1,421 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Import of a [JSON Resume](https://jsonresume.org/schema) document, the
+//! format `/cv.json` exports, into the site owner's CV.
+//!
+//! Entries are matched to existing rows by what identifies them, e.g. the
+//! company, position and start date of a job, then created, updated or
+//! skipped when unchanged or invalid. Changes are recorded in the audit log
+//! like the ones made in the editors.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ContentKind;
+#[cfg(feature = "ssr")]
+use super::{ContentForm, audit};
+use crate::api::ApiError;
+
+/// Largest accepted document.
+pub const RESUME_MAX_SIZE: usize = 1024 * 1024;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImportOutcome {
+    Created,
+    Updated,
+    /// Unchanged or invalid, with the reason.
+    Skipped(String),
+}
+
+impl ImportOutcome {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Created => "Created",
+            Self::Updated => "Updated",
+            Self::Skipped(_) => "Skipped",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportEntry {
+    pub kind: ContentKind,
+    pub title: String,
+    pub outcome: ImportOutcome,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub entries: Vec<ImportEntry>,
+}
+
+impl ImportReport {
+    /// Number of entries with the outcome `label`, e.g. "Created".
+    pub fn count(&self, label: &str) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.outcome.label() == label)
+            .count()
+    }
+}
+
+/// An entry of the document, identified by `key`, or the reason it cannot be
+/// imported.
+#[cfg(feature = "ssr")]
+struct ImportRow<F> {
+    title: String,
+    key: String,
+    form: Result<F, String>,
+}
+
+/// Matching key of a text, ignoring case and surrounding whitespace.
+#[cfg(feature = "ssr")]
+fn key(value: &str) -> String {
+    value.trim().to_lowercase()
+}
+
+/// Creates a row owned by `owner_id`, or updates `existing`, unless `form` is
+/// what it already holds.
+#[cfg(feature = "ssr")]
+async fn upsert<F, C>(
+    db: &C,
+    staff_id: i32,
+    owner_id: i32,
+    existing: Option<<F::Entity as sea_orm::EntityTrait>::Model>,
+    form: F,
+) -> Result<ImportOutcome, ApiError>
+where
+    F: ContentForm + PartialEq,
+    C: sea_orm::ConnectionTrait,
+    <F::Entity as sea_orm::EntityTrait>::Model: sea_orm::IntoActiveModel<F::ActiveModel>,
+{
+    use entity::sea_orm_active_enums::AuditActionEnum;
+    use sea_orm::ActiveModelTrait;
+
+    // invalid entries are skipped, other errors abort the import
+    let skipped = |err: ApiError| match err {
+        ApiError::BadRequest(message) => Ok(ImportOutcome::Skipped(message)),
+        err => Err(err),
+    };
+
+    match existing {
+        Some(existing) => {
+            let before = F::from(existing.clone());
+            if before == form {
+                return Ok(ImportOutcome::Skipped("Unchanged.".to_string()));
+            }
+            let mut model = F::ActiveModel::from(existing);
+            if let Err(err) = form.apply(&mut model) {
+                return skipped(err);
+            }
+            let saved = model.update(db).await?;
+            audit::record(
+                db,
+                staff_id,
+                F::KIND,
+                F::id(&saved),
+                AuditActionEnum::Update,
+                Some(&before),
+                Some(&F::from(saved.clone())),
+            )
+            .await?;
+            Ok(ImportOutcome::Updated)
+        }
+        None => {
+            let mut model = F::ActiveModel::default();
+            F::set_created_by(&mut model, owner_id);
+            if let Err(err) = form.apply(&mut model) {
+                return skipped(err);
+            }
+            let saved = model.insert(db).await?;
+            audit::record(
+                db,
+                staff_id,
+                F::KIND,
+                F::id(&saved),
+                AuditActionEnum::Create,
+                None,
+                Some(&F::from(saved.clone())),
+            )
+            .await?;
+            Ok(ImportOutcome::Created)
+        }
+    }
+}
+
+/// Imports `rows`, matching them to the `existing` rows by `row_key`.
+#[cfg(feature = "ssr")]
+async fn import_rows<F, C>(
+    db: &C,
+    staff_id: i32,
+    owner_id: i32,
+    report: &mut ImportReport,
+    existing: &[<F::Entity as sea_orm::EntityTrait>::Model],
+    row_key: impl Fn(&<F::Entity as sea_orm::EntityTrait>::Model) -> String,
+    rows: Vec<ImportRow<F>>,
+) -> Result<(), ApiError>
+where
+    F: ContentForm + PartialEq,
+    C: sea_orm::ConnectionTrait,
+    <F::Entity as sea_orm::EntityTrait>::Model: sea_orm::IntoActiveModel<F::ActiveModel>,
+{
+    let mut seen = std::collections::HashSet::new();
+    for row in rows {
+        let outcome = match row.form {
+            Err(reason) => ImportOutcome::Skipped(reason),
+            Ok(_) if !seen.insert(row.key.clone()) => {
+                ImportOutcome::Skipped("Duplicate of an earlier entry.".to_string())
+            }
+            Ok(form) => {
+                let current = existing
+                    .iter()
+                    .find(|model| row_key(model) == row.key)
+                    .cloned();
+                upsert(db, staff_id, owner_id, current, form).await?
+            }
+        };
+        report.entries.push(ImportEntry {
+            kind: F::KIND,
+            title: row.title,
+            outcome,
+        });
+    }
+    Ok(())
+}
+
+#[server]
+pub async fn import_resume(resume: String) -> Result<ImportReport, ApiError> {
+    use std::collections::HashMap;
+
+    use entity::prelude::Technology;
+    use sea_orm::{EntityTrait, TransactionTrait};
+
+    use super::cv::{
+        CertificateForm, EducationForm, ExperienceForm, OpenSourceContributionForm, SkillForm,
+        SummaryForm, preview,
+    };
+    use crate::{
+        server::{
+            config::use_config, cv_export::CvData, database::use_database, guards::require_staff,
+            json_resume::Resume, site::find_owner,
+        },
+        utils::dates::date_input,
+    };
+
+    let staff = require_staff().await?;
+    if resume.len() > RESUME_MAX_SIZE {
+        return Err(ApiError::bad_request(format!(
+            "The document must be at most {} KB.",
+            RESUME_MAX_SIZE / 1024
+        )));
+    }
+    let resume = serde_json::from_str::<Resume>(&resume)
+        .map_err(|err| ApiError::bad_request(format!("Invalid JSON Resume: {err}.")))?;
+
+    let config = use_config()?;
+    let db = use_database()?;
+    let txn = db.begin().await?;
+    let owner = find_owner(&txn, &config.site).await?;
+    let owner_id = owner.id;
+    let cv = CvData::load(&txn, owner).await?;
+    let mut report = ImportReport::default();
+
+    if let Some(summary) = resume
+        .basics
+        .summary
+        .as_deref()
+        .map(str::trim)
+        .filter(|summary| !summary.is_empty())
+    {
+        // the site shows a single summary, the most recent one is replaced
+        let existing = cv
+            .summaries
+            .iter()
+            .find(|existing| existing.summary == summary)
+            .or(cv.summaries.first())
+            .cloned();
+        let outcome = upsert(
+            &txn,
+            staff.id,
+            owner_id,
+            existing,
+            SummaryForm {
+                summary: summary.to_string(),
+            },
+        )
+        .await?;
+        report.entries.push(ImportEntry {
+            kind: ContentKind::Summary,
+            title: preview(summary),
+            outcome,
+        });
+    }
+
+    let rows = resume
+        .work
+        .iter()
+        .map(|work| {
+            let form = work.to_form();
+            ImportRow {
+                title: format!("{} at {}", form.title, form.company),
+                key: format!(
+                    "{}|{}|{}",
+                    key(&form.company),
+                    key(&form.title),
+                    form.start_date
+                ),
+                form: Ok(form),
+            }
+        })
+        .collect::<Vec<ImportRow<ExperienceForm>>>();
+    import_rows(
+        &txn,
+        staff.id,
+        owner_id,
+        &mut report,
+        &cv.experiences,
+        |model| {
+            format!(
+                "{}|{}|{}",
+                key(&model.company),
+                key(&model.title),
+                date_input(model.start_date)
+            )
+        },
+        rows,
+    )
+    .await?;
+
+    let rows = resume
+        .education
+        .iter()
+        .map(|education| {
+            let form = education.to_form();
+            ImportRow {
+                title: format!("{}, {}", form.degree, form.institution),
+                key: format!(
+                    "{}|{}|{}",
+                    key(&form.institution),
+                    key(&form.degree),
+                    form.start_date
+                ),
+                form: Ok(form),
+            }
+        })
+        .collect::<Vec<ImportRow<EducationForm>>>();
+    import_rows(
+        &txn,
+        staff.id,
+        owner_id,
+        &mut report,
+        &cv.educations,
+        |model| {
+            format!(
+                "{}|{}|{}",
+                key(&model.institution),
+                key(&model.degree),
+                date_input(model.start_date)
+            )
+        },
+        rows,
+    )
+    .await?;
+
+    let rows = resume
+        .certificates
+        .iter()
+        .map(|certificate| {
+            let form = certificate.to_form();
+            ImportRow {
+                title: form.title.clone(),
+                key: format!("{}|{}", key(&form.title), key(&form.issuer)),
+                form: Ok(form),
+            }
+        })
+        .collect::<Vec<ImportRow<CertificateForm>>>();
+    import_rows(
+        &txn,
+        staff.id,
+        owner_id,
+        &mut report,
+        &cv.certificates,
+        |model| format!("{}|{}", key(&model.title), key(&model.issuer)),
+        rows,
+    )
+    .await?;
+
+    // skills are named after a technology, which has to exist already
+    let technologies = Technology::find()
+        .all(&txn)
+        .await?
+        .into_iter()
+        .map(|technology| (key(&technology.name), technology.id))
+        .collect::<HashMap<_, _>>();
+    let rows = resume
+        .skills
+        .iter()
+        .map(|skill| {
+            let technology_id = technologies.get(&key(&skill.name)).copied();
+            ImportRow {
+                title: skill.name.trim().to_string(),
+                key: technology_id.map(|id| id.to_string()).unwrap_or_default(),
+                form: technology_id
+                    .map(|id| skill.to_form(id))
+                    .ok_or_else(|| format!("No technology is named \"{}\".", skill.name.trim())),
+            }
+        })
+        .collect::<Vec<ImportRow<SkillForm>>>();
+    let skills = cv
+        .skills
+        .iter()
+        .map(|(skill, _)| skill.clone())
+        .collect::<Vec<_>>();
+    import_rows(
+        &txn,
+        staff.id,
+        owner_id,
+        &mut report,
+        &skills,
+        |model| model.technology_id.to_string(),
+        rows,
+    )
+    .await?;
+
+    let rows = resume
+        .projects
+        .iter()
+        .map(|project| {
+            let form = project.to_form();
+            ImportRow {
+                title: form.name.clone(),
+                key: form.url.clone(),
+                form: Ok(form),
+            }
+        })
+        .collect::<Vec<ImportRow<OpenSourceContributionForm>>>();
+    import_rows(
+        &txn,
+        staff.id,
+        owner_id,
+        &mut report,
+        &cv.contributions,
+        |model| model.url.clone(),
+        rows,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    log::info!(
+        "staff {} imported a JSON Resume: {} created, {} updated, {} skipped",
+        staff.id,
+        report.count("Created"),
+        report.count("Updated"),
+        report.count("Skipped")
+    );
+    Ok(report)
+}
@@ -0,0 +1,280 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Ordering of the posts in each blog series. A post's position is unique in
+//! its series, readers go through them from the first.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::blog::PostStatus;
+use crate::api::ApiError;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdminSeriesPost {
+    pub id: i32,
+    pub title: String,
+    pub status: PostStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdminSeries {
+    pub id: i32,
+    pub title: String,
+    /// Posts in reading order, drafts included.
+    pub posts: Vec<AdminSeriesPost>,
+}
+
+/// Ids of the posts of series `series_id`, in reading order.
+#[cfg(feature = "ssr")]
+async fn series_post_ids<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    series_id: i32,
+) -> Result<Vec<i32>, sea_orm::DbErr> {
+    use entity::{blog_series_post, prelude::BlogSeriesPost};
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+
+    BlogSeriesPost::find()
+        .select_only()
+        .column(blog_series_post::Column::BlogPostId)
+        .filter(blog_series_post::Column::BlogSeriesId.eq(series_id))
+        .order_by_asc(blog_series_post::Column::Position)
+        .into_tuple::<i32>()
+        .all(db)
+        .await
+}
+
+/// Numbers the posts of series `series_id` from 1 in the order of `post_ids`.
+/// Their positions are negated first, so no two posts hold the same one
+/// while they are renumbered.
+#[cfg(feature = "ssr")]
+async fn renumber<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    series_id: i32,
+    post_ids: &[i32],
+) -> Result<(), sea_orm::DbErr> {
+    use entity::{blog_series_post, prelude::BlogSeriesPost};
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, sea_query::Expr};
+
+    BlogSeriesPost::update_many()
+        .col_expr(
+            blog_series_post::Column::Position,
+            Expr::col(blog_series_post::Column::Position).mul(-1),
+        )
+        .filter(blog_series_post::Column::BlogSeriesId.eq(series_id))
+        .exec(db)
+        .await?;
+    for (index, post_id) in post_ids.iter().enumerate() {
+        BlogSeriesPost::update_many()
+            .col_expr(
+                blog_series_post::Column::Position,
+                Expr::value(index as i32 + 1),
+            )
+            .filter(blog_series_post::Column::BlogSeriesId.eq(series_id))
+            .filter(blog_series_post::Column::BlogPostId.eq(*post_id))
+            .exec(db)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Finds series `id`, locking it until the transaction ends so changes to
+/// its posts are made one at a time, each reading the positions the last
+/// one left.
+#[cfg(feature = "ssr")]
+async fn lock_series(
+    txn: &sea_orm::DatabaseTransaction,
+    id: i32,
+) -> Result<entity::blog_series::Model, ApiError> {
+    use entity::prelude::BlogSeries;
+    use sea_orm::{EntityTrait, QuerySelect};
+
+    BlogSeries::find_by_id(id)
+        .lock_exclusive()
+        .one(txn)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Series not found."))
+}
+
+/// Every series with its posts in reading order, by title.
+#[server]
+pub async fn list_admin_series() -> Result<Vec<AdminSeries>, ApiError> {
+    use entity::{
+        blog_post, blog_series, blog_series_post,
+        prelude::{BlogPost, BlogSeries},
+    };
+    use sea_orm::{
+        ColumnTrait, EntityTrait, JoinType, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+    };
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    require_staff().await?;
+    let db = use_database()?;
+    let series = BlogSeries::find()
+        .order_by_asc(blog_series::Column::Title)
+        .all(&db)
+        .await?;
+
+    let mut rows = Vec::with_capacity(series.len());
+    for series in series {
+        let posts = BlogPost::find()
+            .join(
+                JoinType::InnerJoin,
+                blog_post::Relation::BlogSeriesPost.def(),
+            )
+            .filter(blog_series_post::Column::BlogSeriesId.eq(series.id))
+            .order_by_asc(blog_series_post::Column::Position)
+            .all(&db)
+            .await?;
+        rows.push(AdminSeries {
+            id: series.id,
+            title: series.title,
+            posts: posts
+                .into_iter()
+                .map(|post| AdminSeriesPost {
+                    id: post.id,
+                    status: PostStatus::from(&post),
+                    title: post.title,
+                })
+                .collect(),
+        });
+    }
+    Ok(rows)
+}
+
+/// Adds post `post_id` at the end of series `series_id`.
+#[server]
+pub async fn add_series_post(series_id: i32, post_id: i32) -> Result<(), ApiError> {
+    use entity::{
+        blog_series_post,
+        prelude::{BlogPost, BlogSeriesPost},
+    };
+    use sea_orm::{
+        ActiveModelTrait, ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter, QuerySelect,
+        TransactionTrait, sea_query::Expr,
+    };
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    let staff = require_staff().await?;
+    let db = use_database()?;
+    let txn = db.begin().await?;
+    let series = lock_series(&txn, series_id).await?;
+    BlogPost::find_by_id(post_id)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Post not found."))?;
+
+    if BlogSeriesPost::find_by_id((series.id, post_id))
+        .one(&txn)
+        .await?
+        .is_some()
+    {
+        return Err(ApiError::bad_request("The post is already in the series."));
+    }
+    // positions may have gaps left by deleted posts, the new one goes last
+    let last = BlogSeriesPost::find()
+        .select_only()
+        .column_as(
+            Expr::col(blog_series_post::Column::Position).max(),
+            "position",
+        )
+        .filter(blog_series_post::Column::BlogSeriesId.eq(series.id))
+        .into_tuple::<Option<i32>>()
+        .one(&txn)
+        .await?
+        .flatten();
+    blog_series_post::ActiveModel {
+        blog_series_id: Set(series.id),
+        blog_post_id: Set(post_id),
+        position: Set(last.map_or(1, |position| position + 1)),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+    txn.commit().await?;
+
+    log::info!(
+        "staff {} added post {post_id} to series {}",
+        staff.id,
+        series.id
+    );
+    Ok(())
+}
+
+/// Moves post `post_id` one place earlier in series `series_id`, or later
+/// when `later` is set. Posts already first or last stay where they are.
+#[server]
+pub async fn move_series_post(series_id: i32, post_id: i32, later: bool) -> Result<(), ApiError> {
+    use sea_orm::TransactionTrait;
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    let staff = require_staff().await?;
+    let db = use_database()?;
+    let txn = db.begin().await?;
+    let series = lock_series(&txn, series_id).await?;
+    let mut post_ids = series_post_ids(&txn, series.id).await?;
+    let index = post_ids
+        .iter()
+        .position(|id| *id == post_id)
+        .ok_or_else(|| ApiError::not_found("The post is not in the series."))?;
+
+    let other = if later {
+        index + 1
+    } else {
+        match index.checked_sub(1) {
+            Some(other) => other,
+            None => return Ok(()),
+        }
+    };
+    if other >= post_ids.len() {
+        return Ok(());
+    }
+    post_ids.swap(index, other);
+    renumber(&txn, series.id, &post_ids).await?;
+    txn.commit().await?;
+
+    log::info!(
+        "staff {} moved post {post_id} to position {} of series {}",
+        staff.id,
+        other + 1,
+        series.id
+    );
+    Ok(())
+}
+
+/// Takes post `post_id` out of series `series_id`, the posts after it move
+/// up a place.
+#[server]
+pub async fn remove_series_post(series_id: i32, post_id: i32) -> Result<(), ApiError> {
+    use entity::prelude::BlogSeriesPost;
+    use sea_orm::{EntityTrait, TransactionTrait};
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    let staff = require_staff().await?;
+    let db = use_database()?;
+    let txn = db.begin().await?;
+    let series = lock_series(&txn, series_id).await?;
+    let deleted = BlogSeriesPost::delete_by_id((series.id, post_id))
+        .exec(&txn)
+        .await?;
+    if deleted.rows_affected == 0 {
+        return Err(ApiError::not_found("The post is not in the series."));
+    }
+    let post_ids = series_post_ids(&txn, series.id).await?;
+    renumber(&txn, series.id, &post_ids).await?;
+    txn.commit().await?;
+
+    log::info!(
+        "staff {} removed post {post_id} from series {}",
+        staff.id,
+        series.id
+    );
+    Ok(())
+}
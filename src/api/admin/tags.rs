@@ -0,0 +1,141 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Upkeep of the common tags posts are linked to, e.g. merging duplicates
+//! normalization cannot tell apart, like "js" and "javascript".
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::api::ApiError;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdminTagRow {
+    pub id: i32,
+    pub name: String,
+    /// Posts with the tag, drafts included.
+    pub post_count: i64,
+}
+
+/// Every common tag with how many posts use it, by name.
+#[server]
+pub async fn list_admin_tags() -> Result<Vec<AdminTagRow>, ApiError> {
+    use entity::{blog_post_tag, common_tag, prelude::CommonTag};
+    use sea_orm::{EntityTrait, JoinType, QueryOrder, QuerySelect, RelationTrait, sea_query::Expr};
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    require_staff().await?;
+    let db = use_database()?;
+    let tags = CommonTag::find()
+        .select_only()
+        .column(common_tag::Column::Id)
+        .column(common_tag::Column::Name)
+        .column_as(
+            Expr::col((blog_post_tag::Entity, blog_post_tag::Column::Id)).count(),
+            "post_count",
+        )
+        .join(JoinType::LeftJoin, common_tag::Relation::BlogPostTag.def())
+        .group_by(common_tag::Column::Id)
+        .order_by_asc(common_tag::Column::Name)
+        .into_tuple::<(i32, String, i64)>()
+        .all(&db)
+        .await?;
+
+    Ok(tags
+        .into_iter()
+        .map(|(id, name, post_count)| AdminTagRow {
+            id,
+            name,
+            post_count,
+        })
+        .collect())
+}
+
+/// Retags the posts tagged `source_id` as `target_id`, then replaces the
+/// source tag with an alias of the target, so posts saved with it later are
+/// tagged with the target too. Posts that already had both keep a single one.
+#[server]
+pub async fn merge_tags(source_id: i32, target_id: i32) -> Result<(), ApiError> {
+    use std::collections::HashSet;
+
+    use entity::{
+        blog_post_tag, common_tag_alias,
+        prelude::{BlogPostTag, CommonTag, CommonTagAlias},
+    };
+    use sea_orm::{
+        ActiveModelTrait, ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter,
+        TransactionTrait, sea_query::Expr,
+    };
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    let staff = require_staff().await?;
+    if source_id == target_id {
+        return Err(ApiError::bad_request("Pick two different tags to merge."));
+    }
+
+    let db = use_database()?;
+    let txn = db.begin().await?;
+    let not_found = || ApiError::not_found("Tag not found.");
+    let source = CommonTag::find_by_id(source_id)
+        .one(&txn)
+        .await?
+        .ok_or_else(not_found)?;
+    let target = CommonTag::find_by_id(target_id)
+        .one(&txn)
+        .await?
+        .ok_or_else(not_found)?;
+
+    let mut tagged = BlogPostTag::find()
+        .filter(blog_post_tag::Column::CommonTagId.eq(target.id))
+        .all(&txn)
+        .await?
+        .into_iter()
+        .map(|row| row.blog_post_id)
+        .collect::<HashSet<_>>();
+    let rows = BlogPostTag::find()
+        .filter(blog_post_tag::Column::CommonTagId.eq(source.id))
+        .all(&txn)
+        .await?;
+    for row in rows {
+        if !tagged.insert(row.blog_post_id) {
+            BlogPostTag::delete_by_id(row.id).exec(&txn).await?;
+            continue;
+        }
+        let mut model: blog_post_tag::ActiveModel = row.into();
+        model.tag = Set(target.name.clone());
+        model.common_tag_id = Set(Some(target.id));
+        model.update(&txn).await?;
+    }
+
+    // names merged into the source before now resolve to the target
+    CommonTagAlias::update_many()
+        .col_expr(
+            common_tag_alias::Column::CommonTagId,
+            Expr::value(target.id),
+        )
+        .filter(common_tag_alias::Column::CommonTagId.eq(source.id))
+        .exec(&txn)
+        .await?;
+    CommonTag::delete_by_id(source.id).exec(&txn).await?;
+    common_tag_alias::ActiveModel {
+        name: Set(source.name.clone()),
+        common_tag_id: Set(target.id),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+    txn.commit().await?;
+
+    log::info!(
+        "staff {} merged tag {} into tag {}",
+        staff.id,
+        source.name,
+        target.name
+    );
+    Ok(())
+}
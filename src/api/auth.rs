@@ -0,0 +1,149 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::str::FromStr;
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ApiError;
+
+/// Identity providers users can sign in with, stored by `as_str` in the
+/// `auth_provider` table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthProvider {
+    Github,
+    Google,
+}
+
+impl OAuthProvider {
+    pub const ALL: [Self; 2] = [Self::Github, Self::Google];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Github => "github",
+            Self::Google => "google",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Github => "GitHub",
+            Self::Google => "Google",
+        }
+    }
+
+    /// Server route starting the sign-in flow with this provider.
+    pub fn sign_in_url(self) -> String {
+        format!("/auth/{}", self.as_str())
+    }
+}
+
+impl FromStr for OAuthProvider {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|provider| provider.as_str() == value)
+            .ok_or(())
+    }
+}
+
+/// Keeps only local paths as a post sign-in destination so the flow cannot be
+/// used as an open redirect.
+pub fn safe_return_to(value: Option<&str>) -> String {
+    match value {
+        Some(path) if path.starts_with('/') && !path.starts_with("//") && !path.contains('\\') => {
+            path.to_string()
+        }
+        _ => "/".to_string(),
+    }
+}
+
+/// The signed in user, as exposed to the client.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionUser {
+    pub id: i32,
+    pub name: String,
+    pub is_staff: bool,
+    pub is_admin: bool,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::user::Model> for SessionUser {
+    fn from(user: entity::user::Model) -> Self {
+        Self {
+            id: user.id,
+            name: user.name,
+            is_staff: user.is_staff,
+            is_admin: user.is_admin,
+        }
+    }
+}
+
+#[server]
+pub async fn list_sign_in_providers() -> Result<Vec<OAuthProvider>, ApiError> {
+    use crate::server::config::use_config;
+
+    Ok(use_config()?.auth.enabled_providers())
+}
+
+#[server]
+pub async fn get_session_user() -> Result<Option<SessionUser>, ApiError> {
+    use crate::server::session::use_current_user;
+
+    Ok(use_current_user().map(SessionUser::from))
+}
+
+#[server]
+pub async fn sign_out() -> Result<(), ApiError> {
+    use actix_web::http::header::{HeaderValue, SET_COOKIE};
+
+    use crate::server::session::{clear_session_cookie, use_current_session, use_session_store};
+
+    if let Some(session) = use_current_session() {
+        use_session_store()?.destroy(&session.id).await?;
+    }
+    if let (Some(response), Ok(cookie)) = (
+        use_context::<leptos_actix::ResponseOptions>(),
+        HeaderValue::from_str(&clear_session_cookie().to_string()),
+    ) {
+        response.append_header(SET_COOKIE, cookie);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_return_to_keeps_local_paths() {
+        assert_eq!(
+            safe_return_to(Some("/blog/42-hello?page=2")),
+            "/blog/42-hello?page=2"
+        );
+        assert_eq!(safe_return_to(Some("/")), "/");
+        assert_eq!(safe_return_to(None), "/");
+    }
+
+    #[test]
+    fn safe_return_to_rejects_other_origins() {
+        for value in [
+            "//evil.example.com",
+            "https://evil.example.com",
+            "/\\evil.example.com",
+            "\\evil.example.com",
+            "javascript:alert(1)",
+            "evil",
+            "",
+        ] {
+            assert_eq!(safe_return_to(Some(value)), "/", "{value}");
+        }
+    }
+}
@@ -0,0 +1,565 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{ApiError, technologies::TechnologyBadge};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PostSummary {
+    pub id: i32,
+    pub slug: String,
+    pub title: String,
+    pub excerpt: String,
+    pub cover_image_url: Option<String>,
+    pub published_at: String,
+    pub is_featured: bool,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::blog_post::Model> for PostSummary {
+    fn from(model: entity::blog_post::Model) -> Self {
+        use crate::utils::{dates::day_month_year, slug::id_slug};
+
+        Self {
+            id: model.id,
+            slug: id_slug(model.id, &model.title),
+            excerpt: excerpt(&model.content),
+            // republished posts keep their original date
+            published_at: model
+                .first_published_at
+                .or(model.published_at)
+                .map(day_month_year)
+                .unwrap_or_default(),
+            title: model.title,
+            cover_image_url: model.cover_image_url,
+            is_featured: model.is_featured,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PostPage {
+    pub posts: Vec<PostSummary>,
+    /// Cursor to pass to [`list_posts`] to load the next, older, page.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeriesSummary {
+    pub id: i32,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub post_count: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeriesDetail {
+    pub id: i32,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub cover_image_url: Option<String>,
+    pub technologies: Vec<TechnologyBadge>,
+    /// Published posts, in reading order.
+    pub posts: Vec<PostSummary>,
+}
+
+/// Post next to another in a series.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeriesLink {
+    pub slug: String,
+    pub title: String,
+}
+
+/// Where a post is in one of its series.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeriesNavigation {
+    pub series_id: i32,
+    pub series_title: String,
+    /// Place of the post among the series' published posts, from 1.
+    pub position: usize,
+    pub post_count: usize,
+    pub previous: Option<SeriesLink>,
+    pub next: Option<SeriesLink>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TocEntry {
+    pub level: u8,
+    pub id: String,
+    pub title: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PostDetail {
+    pub id: i32,
+    pub slug: String,
+    pub title: String,
+    pub cover_image_url: Option<String>,
+    pub published_at: String,
+    /// Sanitized HTML of the post's content.
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+    /// Normalized tags, each with its page at `/blog/tags/{tag}`.
+    pub tags: Vec<String>,
+    pub series: Vec<SeriesNavigation>,
+}
+
+/// Number of posts per page of the blog index.
+#[cfg(feature = "ssr")]
+const POSTS_PER_PAGE: u64 = 10;
+
+/// Maximum number of characters of a post excerpt.
+#[cfg(feature = "ssr")]
+const EXCERPT_LENGTH: usize = 200;
+
+/// Builds a short plain text excerpt from the first paragraph of a post.
+#[cfg(feature = "ssr")]
+fn excerpt(content: &str) -> String {
+    let text = crate::server::markdown::first_paragraph(content)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if text.chars().count() <= EXCERPT_LENGTH {
+        return text;
+    }
+    let mut excerpt = text.chars().take(EXCERPT_LENGTH).collect::<String>();
+    if let Some(index) = excerpt.rfind(' ') {
+        excerpt.truncate(index);
+    }
+    excerpt.push('…');
+    excerpt
+}
+
+/// Encodes the position of a post in the index as an opaque cursor.
+#[cfg(feature = "ssr")]
+fn encode_cursor(published_at: chrono::NaiveDateTime, id: i32) -> String {
+    format!("{}_{id}", published_at.and_utc().timestamp_micros())
+}
+
+#[cfg(feature = "ssr")]
+fn decode_cursor(cursor: &str) -> Option<(chrono::NaiveDateTime, i32)> {
+    let (timestamp, id) = cursor.split_once('_')?;
+    let published_at = chrono::DateTime::from_timestamp_micros(timestamp.parse().ok()?)?;
+    Some((published_at.naive_utc(), id.parse().ok()?))
+}
+
+/// Date posts are listed and paged by, the one they show: the first
+/// publication, so republishing a post does not move it.
+#[cfg(feature = "ssr")]
+pub(crate) fn listed_at() -> sea_orm::sea_query::SimpleExpr {
+    use entity::{blog_post, prelude::BlogPost};
+    use sea_orm::sea_query::{Expr, Func};
+
+    Func::coalesce([
+        Expr::col((BlogPost, blog_post::Column::FirstPublishedAt)).into(),
+        Expr::col((BlogPost, blog_post::Column::PublishedAt)).into(),
+    ])
+    .into()
+}
+
+/// Condition matching posts visible to readers right now.
+#[cfg(feature = "ssr")]
+pub(crate) fn published() -> sea_orm::Condition {
+    use entity::blog_post;
+    use sea_orm::{ColumnTrait, Condition};
+
+    Condition::all()
+        .add(blog_post::Column::IsPublished.eq(true))
+        .add(blog_post::Column::PublishedAt.lte(chrono::Utc::now().naive_utc()))
+}
+
+/// Loads a post readers can see, i.e. a published one.
+#[cfg(feature = "ssr")]
+pub(crate) async fn find_published_post(
+    db: &sea_orm::DatabaseConnection,
+    post_id: i32,
+) -> Result<entity::blog_post::Model, ApiError> {
+    use entity::prelude::BlogPost;
+    use sea_orm::{EntityTrait, QueryFilter};
+
+    BlogPost::find_by_id(post_id)
+        .filter(published())
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Post not found."))
+}
+
+/// Lists the featured posts, newest first.
+#[server]
+pub async fn list_featured_posts() -> Result<Vec<PostSummary>, ApiError> {
+    use entity::{blog_post, prelude::BlogPost};
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+    use crate::server::database::use_database;
+
+    let db = use_database()?;
+    let posts = BlogPost::find()
+        .filter(published())
+        .filter(blog_post::Column::IsFeatured.eq(true))
+        .order_by_desc(listed_at())
+        .order_by_desc(blog_post::Column::Id)
+        .all(&db)
+        .await?;
+
+    Ok(posts.into_iter().map(PostSummary::from).collect())
+}
+
+/// Lists a page of published, non-featured, posts, newest first. Pages are
+/// addressed by the cursor returned with the previous page rather than by
+/// offset, so posts published in between don't shift the pages.
+#[server]
+pub async fn list_posts(cursor: Option<String>) -> Result<PostPage, ApiError> {
+    use entity::{blog_post, prelude::BlogPost};
+    use sea_orm::{
+        ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, QuerySelect, sea_query::Expr,
+    };
+
+    use crate::server::database::use_database;
+
+    let db = use_database()?;
+    let mut query = BlogPost::find()
+        .filter(published())
+        .filter(blog_post::Column::IsFeatured.eq(false));
+    if let Some((published_at, id)) = cursor.as_deref().and_then(decode_cursor) {
+        query = query.filter(
+            Condition::any()
+                .add(Expr::expr(listed_at()).lt(published_at))
+                .add(
+                    Condition::all()
+                        .add(Expr::expr(listed_at()).eq(published_at))
+                        .add(blog_post::Column::Id.lt(id)),
+                ),
+        );
+    }
+
+    let mut posts = query
+        .order_by_desc(listed_at())
+        .order_by_desc(blog_post::Column::Id)
+        .limit(POSTS_PER_PAGE + 1)
+        .all(&db)
+        .await?;
+
+    let next_cursor = if posts.len() as u64 > POSTS_PER_PAGE {
+        posts.truncate(POSTS_PER_PAGE as usize);
+        posts.last().and_then(|post| {
+            let published_at = post.first_published_at.or(post.published_at)?;
+            Some(encode_cursor(published_at, post.id))
+        })
+    } else {
+        None
+    };
+
+    Ok(PostPage {
+        posts: posts.into_iter().map(PostSummary::from).collect(),
+        next_cursor,
+    })
+}
+
+/// Lists every series with the number of published posts in it.
+#[server]
+pub async fn list_series() -> Result<Vec<SeriesSummary>, ApiError> {
+    use entity::{blog_post, blog_series, blog_series_post, prelude::BlogSeries};
+    use sea_orm::{
+        EntityTrait, JoinType, QueryOrder, QuerySelect, RelationTrait,
+        sea_query::{Expr, IntoCondition},
+    };
+
+    use crate::server::database::use_database;
+
+    let db = use_database()?;
+    let series = BlogSeries::find()
+        .select_only()
+        .column(blog_series::Column::Id)
+        .column(blog_series::Column::Title)
+        .column(blog_series::Column::Subtitle)
+        .column_as(
+            Expr::col((blog_post::Entity, blog_post::Column::Id)).count(),
+            "post_count",
+        )
+        .join(
+            JoinType::LeftJoin,
+            blog_series::Relation::BlogSeriesPost.def(),
+        )
+        .join(
+            JoinType::LeftJoin,
+            blog_series_post::Relation::BlogPost
+                .def()
+                .on_condition(|_, post| published_on(post).into_condition()),
+        )
+        .group_by(blog_series::Column::Id)
+        .order_by_asc(blog_series::Column::Title)
+        .into_tuple::<(i32, String, Option<String>, i64)>()
+        .all(&db)
+        .await?;
+
+    Ok(series
+        .into_iter()
+        .map(|(id, title, subtitle, post_count)| SeriesSummary {
+            id,
+            title,
+            subtitle,
+            post_count,
+        })
+        .collect())
+}
+
+/// Same as [`published`], for a joined `blog_post` table aliased as `post`.
+#[cfg(feature = "ssr")]
+fn published_on(post: sea_orm::sea_query::DynIden) -> sea_orm::Condition {
+    use entity::blog_post;
+    use sea_orm::{Condition, sea_query::Expr};
+
+    Condition::all()
+        .add(Expr::col((post.clone(), blog_post::Column::IsPublished)).eq(true))
+        .add(Expr::col((post, blog_post::Column::PublishedAt)).lte(chrono::Utc::now().naive_utc()))
+}
+
+/// Published posts of series `series_id`, in reading order.
+#[cfg(feature = "ssr")]
+async fn series_posts<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    series_id: i32,
+) -> Result<Vec<entity::blog_post::Model>, sea_orm::DbErr> {
+    use entity::{blog_post, blog_series_post, prelude::BlogPost};
+    use sea_orm::{
+        ColumnTrait, EntityTrait, JoinType, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+    };
+
+    BlogPost::find()
+        .join(
+            JoinType::InnerJoin,
+            blog_post::Relation::BlogSeriesPost.def(),
+        )
+        .filter(blog_series_post::Column::BlogSeriesId.eq(series_id))
+        .filter(published())
+        .order_by_asc(blog_series_post::Column::Position)
+        .order_by_asc(listed_at())
+        .order_by_asc(blog_post::Column::Id)
+        .all(db)
+        .await
+}
+
+/// Where post `post_id` is in each series it belongs to.
+#[cfg(feature = "ssr")]
+async fn series_navigation<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    post_id: i32,
+) -> Result<Vec<SeriesNavigation>, sea_orm::DbErr> {
+    use entity::{blog_series, blog_series_post, prelude::BlogSeries};
+    use sea_orm::{
+        ColumnTrait, EntityTrait, JoinType, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+    };
+
+    use crate::utils::slug::id_slug;
+
+    let series = BlogSeries::find()
+        .join(
+            JoinType::InnerJoin,
+            blog_series::Relation::BlogSeriesPost.def(),
+        )
+        .filter(blog_series_post::Column::BlogPostId.eq(post_id))
+        .order_by_asc(blog_series::Column::Title)
+        .all(db)
+        .await?;
+
+    let link = |post: &entity::blog_post::Model| SeriesLink {
+        slug: id_slug(post.id, &post.title),
+        title: post.title.clone(),
+    };
+    let mut navigation = Vec::with_capacity(series.len());
+    for series in series {
+        let posts = series_posts(db, series.id).await?;
+        let Some(index) = posts.iter().position(|post| post.id == post_id) else {
+            continue;
+        };
+        navigation.push(SeriesNavigation {
+            series_id: series.id,
+            series_title: series.title,
+            position: index + 1,
+            post_count: posts.len(),
+            previous: index.checked_sub(1).map(|index| link(&posts[index])),
+            next: posts.get(index + 1).map(link),
+        });
+    }
+    Ok(navigation)
+}
+
+/// Loads a series with its technologies and published posts.
+#[server]
+pub async fn get_series(id: i32) -> Result<SeriesDetail, ApiError> {
+    use entity::{
+        prelude::{BlogSeries, Technology},
+        technology,
+    };
+    use sea_orm::{EntityTrait, ModelTrait, QueryOrder};
+
+    use crate::server::database::use_database;
+
+    let db = use_database()?;
+    let series = BlogSeries::find_by_id(id)
+        .one(&db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Series not found."))?;
+    let technologies = series
+        .find_related(Technology)
+        .order_by_asc(technology::Column::Name)
+        .all(&db)
+        .await?;
+    let posts = series_posts(&db, series.id).await?;
+
+    Ok(SeriesDetail {
+        id: series.id,
+        title: series.title,
+        subtitle: series.subtitle,
+        cover_image_url: series.cover_image_url,
+        technologies: technologies
+            .into_iter()
+            .map(TechnologyBadge::from)
+            .collect(),
+        posts: posts.into_iter().map(PostSummary::from).collect(),
+    })
+}
+
+/// Renders a post's markdown, reusing the cached HTML while the post hasn't
+/// been updated.
+#[cfg(feature = "ssr")]
+pub(crate) async fn render_post<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    post: &entity::blog_post::Model,
+) -> Result<crate::server::markdown::Rendered, sea_orm::DbErr> {
+    use leptos::prelude::use_context;
+
+    use crate::server::markdown::RenderCache;
+
+    let cache = use_context::<RenderCache>();
+    if let Some(rendered) = cache
+        .as_ref()
+        .and_then(|cache| cache.get(post.id, post.updated_at))
+    {
+        return Ok(rendered);
+    }
+
+    let rendered = render_content(db, post.id, &post.content).await?;
+    if let Some(cache) = cache {
+        cache.insert(post.id, post.updated_at, rendered.clone());
+    }
+    Ok(rendered)
+}
+
+/// Renders `content` as the body of post `post_id`, e.g. an unsaved edit.
+#[cfg(feature = "ssr")]
+pub(crate) async fn render_content<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    post_id: i32,
+    content: &str,
+) -> Result<crate::server::markdown::Rendered, sea_orm::DbErr> {
+    use std::collections::{HashMap, HashSet};
+
+    use entity::{
+        blog_post_image,
+        prelude::{BlogPostImage, Technology},
+        technology,
+    };
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect};
+
+    use crate::server::markdown::{RenderContext, render};
+
+    let languages = Technology::find()
+        .select_only()
+        .column(technology::Column::Name)
+        .into_tuple::<String>()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|name| name.to_lowercase())
+        .collect::<HashSet<_>>();
+    let images = BlogPostImage::find()
+        .filter(blog_post_image::Column::BlogPostId.eq(post_id))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|image| (image.id, image.image_url))
+        .collect::<HashMap<_, _>>();
+
+    Ok(render(
+        content,
+        &RenderContext {
+            languages: &languages,
+            images: &images,
+        },
+    ))
+}
+
+/// Loads a published post by its slug, rendered to HTML.
+#[server]
+pub async fn get_post(slug: String) -> Result<PostDetail, ApiError> {
+    use entity::prelude::BlogPost;
+    use sea_orm::{EntityTrait, QueryFilter};
+
+    use crate::{
+        api::tags::post_tags,
+        server::database::use_database,
+        utils::{
+            dates::day_month_year,
+            slug::{id_slug, parse_id_slug},
+        },
+    };
+
+    let not_found = || ApiError::not_found("Post not found.");
+    let id = parse_id_slug(&slug).ok_or_else(not_found)?;
+
+    let db = use_database()?;
+    let post = BlogPost::find_by_id(id)
+        .filter(published())
+        .one(&db)
+        .await?
+        .ok_or_else(not_found)?;
+    let rendered = render_post(&db, &post).await?;
+    let tags = post_tags(&db, post.id).await?;
+    let series = series_navigation(&db, post.id).await?;
+
+    Ok(PostDetail {
+        id: post.id,
+        slug: id_slug(post.id, &post.title),
+        published_at: post
+            .first_published_at
+            .or(post.published_at)
+            .map(day_month_year)
+            .unwrap_or_default(),
+        title: post.title,
+        cover_image_url: post.cover_image_url,
+        html: rendered.html,
+        toc: rendered.toc,
+        tags,
+        series,
+    })
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trips() {
+        let published_at = chrono::NaiveDate::from_ymd_opt(2025, 9, 11)
+            .and_then(|date| date.and_hms_micro_opt(20, 55, 0, 123_456))
+            .unwrap();
+
+        let cursor = encode_cursor(published_at, 42);
+        assert_eq!(decode_cursor(&cursor), Some((published_at, 42)));
+    }
+
+    #[test]
+    fn invalid_cursors_are_ignored() {
+        assert_eq!(decode_cursor(""), None);
+        assert_eq!(decode_cursor("42"), None);
+        assert_eq!(decode_cursor("abc_42"), None);
+        assert_eq!(decode_cursor("1757624100000000_abc"), None);
+    }
+}
@@ -0,0 +1,362 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use super::blog::find_published_post;
+use super::{ApiError, reactions::ReactionSummary};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentItem {
+    pub id: i32,
+    pub author: String,
+    /// Empty for hidden comments, unless the reader is staff.
+    pub comment: String,
+    pub image_url: Option<String>,
+    pub created_at: String,
+    pub is_hidden: bool,
+    /// Whether the reader wrote the comment and can still edit it.
+    pub can_edit: bool,
+    pub reactions: ReactionSummary,
+    pub replies: Vec<CommentItem>,
+}
+
+/// Comments of a post, as a tree of replies, with the limits the forms apply.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentThread {
+    pub comments: Vec<CommentItem>,
+    /// Number of comments the reader can see.
+    pub count: usize,
+    /// Deepest reply level, top-level comments being at depth 0.
+    pub max_depth: usize,
+    pub max_length: usize,
+    /// Whether the reader is staff and can hide comments.
+    pub can_moderate: bool,
+}
+
+/// What the reader of a thread is allowed to see and do.
+#[cfg(feature = "ssr")]
+struct Reader {
+    user_id: Option<i32>,
+    is_staff: bool,
+    edit_window: chrono::Duration,
+}
+
+#[cfg(feature = "ssr")]
+impl Reader {
+    fn can_edit(&self, comment: &entity::blog_post_comment::Model) -> bool {
+        self.user_id == Some(comment.created_by_id)
+            && !comment.is_hidden
+            && comment.created_at + self.edit_window > chrono::Utc::now().naive_utc()
+    }
+
+    fn item(&self, comment: entity::blog_post_comment::Model, author: String) -> CommentItem {
+        use crate::utils::dates::day_month_year;
+
+        let can_edit = self.can_edit(&comment);
+        let visible = !comment.is_hidden || self.is_staff;
+        CommentItem {
+            id: comment.id,
+            author,
+            comment: if visible {
+                comment.comment
+            } else {
+                String::new()
+            },
+            image_url: comment.image_url.filter(|_| visible),
+            created_at: day_month_year(comment.created_at),
+            is_hidden: comment.is_hidden,
+            can_edit,
+            reactions: ReactionSummary::default(),
+            replies: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+fn reader(user: Option<&entity::user::Model>, config: &crate::server::config::Config) -> Reader {
+    Reader {
+        user_id: user.map(|user| user.id),
+        is_staff: user.is_some_and(|user| user.is_staff || user.is_admin),
+        edit_window: chrono::Duration::from_std(config.comments.edit_window)
+            .unwrap_or(chrono::Duration::MAX),
+    }
+}
+
+/// Whether `user` can still edit `comment`, e.g. to attach an image to it.
+#[cfg(feature = "ssr")]
+pub(crate) fn is_editable_by(
+    comment: &entity::blog_post_comment::Model,
+    user: &entity::user::Model,
+    config: &crate::server::config::Config,
+) -> bool {
+    reader(Some(user), config).can_edit(comment)
+}
+
+/// Nests `comments`, ordered oldest first and paired with their parent's id,
+/// under their parents. Replies to a deleted comment are promoted to the top
+/// level.
+#[cfg(feature = "ssr")]
+fn build_tree(comments: Vec<(Option<i32>, CommentItem)>) -> Vec<CommentItem> {
+    use std::collections::{HashMap, HashSet};
+
+    fn attach(item: &mut CommentItem, children: &mut HashMap<i32, Vec<CommentItem>>) {
+        item.replies = children.remove(&item.id).unwrap_or_default();
+        for reply in &mut item.replies {
+            attach(reply, children);
+        }
+    }
+
+    let ids = comments
+        .iter()
+        .map(|(_, comment)| comment.id)
+        .collect::<HashSet<_>>();
+    let mut roots = Vec::new();
+    let mut children = HashMap::<i32, Vec<CommentItem>>::new();
+    for (parent_id, comment) in comments {
+        match parent_id.filter(|id| ids.contains(id)) {
+            Some(parent_id) => children.entry(parent_id).or_default().push(comment),
+            None => roots.push(comment),
+        }
+    }
+    for root in &mut roots {
+        attach(root, &mut children);
+    }
+    roots
+}
+
+/// Depth a reply to `parent` would have.
+#[cfg(feature = "ssr")]
+async fn reply_depth(
+    db: &sea_orm::DatabaseConnection,
+    parent: &entity::blog_post_comment::Model,
+    max_depth: usize,
+) -> Result<usize, ApiError> {
+    use entity::prelude::BlogPostComment;
+    use sea_orm::EntityTrait;
+
+    let mut depth = 1;
+    let mut ancestor_id = parent.parent_comment_id;
+    // stops past the limit, the exact depth no longer matters
+    while let Some(id) = ancestor_id.filter(|_| depth <= max_depth) {
+        ancestor_id = BlogPostComment::find_by_id(id)
+            .one(db)
+            .await?
+            .and_then(|ancestor| ancestor.parent_comment_id);
+        depth += 1;
+    }
+    Ok(depth)
+}
+
+/// Trims `comment` and checks its length.
+#[cfg(feature = "ssr")]
+fn validate_comment(comment: &str, max_length: usize) -> Result<String, ApiError> {
+    let comment = comment.trim();
+    if comment.is_empty() || comment.chars().count() > max_length {
+        return Err(ApiError::bad_request(format!(
+            "Comments must have between 1 and {max_length} characters."
+        )));
+    }
+    Ok(comment.to_string())
+}
+
+#[server]
+pub async fn list_comments(post_id: i32) -> Result<CommentThread, ApiError> {
+    use std::collections::HashMap;
+
+    use entity::{
+        blog_post_comment,
+        prelude::{BlogPostComment, User},
+        user,
+    };
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+    use super::reactions::comment_reactions;
+    use crate::server::{config::use_config, database::use_database, session::use_current_user};
+
+    let db = use_database()?;
+    let config = use_config()?;
+    find_published_post(&db, post_id).await?;
+
+    let current_user = use_current_user();
+    let reader = reader(current_user.as_ref(), &config);
+
+    let comments = BlogPostComment::find()
+        .filter(blog_post_comment::Column::BlogPostId.eq(post_id))
+        .order_by_asc(blog_post_comment::Column::CreatedAt)
+        .order_by_asc(blog_post_comment::Column::Id)
+        .all(&db)
+        .await?;
+    let mut author_ids = comments
+        .iter()
+        .map(|comment| comment.created_by_id)
+        .collect::<Vec<_>>();
+    author_ids.sort_unstable();
+    author_ids.dedup();
+    let authors = User::find()
+        .filter(user::Column::Id.is_in(author_ids))
+        .all(&db)
+        .await?
+        .into_iter()
+        .map(|user| (user.id, user.name))
+        .collect::<HashMap<_, _>>();
+
+    let count = comments
+        .iter()
+        .filter(|comment| !comment.is_hidden || reader.is_staff)
+        .count();
+    let mut reactions = comment_reactions(
+        &db,
+        comments.iter().map(|comment| comment.id).collect(),
+        reader.user_id,
+    )
+    .await?;
+    let comments = comments
+        .into_iter()
+        .map(|comment| {
+            let author = authors
+                .get(&comment.created_by_id)
+                .cloned()
+                .unwrap_or_default();
+            let parent_id = comment.parent_comment_id;
+            let mut item = reader.item(comment, author);
+            item.reactions = reactions.remove(&item.id).unwrap_or_default();
+            (parent_id, item)
+        })
+        .collect();
+
+    Ok(CommentThread {
+        comments: build_tree(comments),
+        count,
+        max_depth: config.comments.max_depth,
+        max_length: config.comments.max_length,
+        can_moderate: reader.is_staff,
+    })
+}
+
+/// Comments on `post_id`, or replies to `parent_id` when given.
+#[server]
+pub async fn post_comment(
+    post_id: i32,
+    #[server(default)] parent_id: Option<i32>,
+    comment: String,
+) -> Result<CommentItem, ApiError> {
+    use chrono::Utc;
+    use entity::{blog_post_comment, prelude::BlogPostComment};
+    use sea_orm::{
+        ActiveModelTrait, ActiveValue::Set, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter,
+    };
+
+    use crate::server::{config::use_config, database::use_database, guards::require_active_user};
+
+    let user = require_active_user().await?;
+    let config = use_config()?;
+    let limits = &config.comments;
+    let comment = validate_comment(&comment, limits.max_length)?;
+
+    let db = use_database()?;
+    find_published_post(&db, post_id).await?;
+
+    if let Some(parent_id) = parent_id {
+        let parent = BlogPostComment::find_by_id(parent_id)
+            .filter(blog_post_comment::Column::BlogPostId.eq(post_id))
+            .one(&db)
+            .await?
+            .ok_or_else(|| ApiError::not_found("Comment not found."))?;
+        if reply_depth(&db, &parent, limits.max_depth).await? > limits.max_depth {
+            return Err(ApiError::bad_request(
+                "This thread is too deep to reply to.",
+            ));
+        }
+    }
+
+    let since =
+        Utc::now().naive_utc() - chrono::Duration::from_std(limits.rate_window).unwrap_or_default();
+    let recent = BlogPostComment::find()
+        .filter(blog_post_comment::Column::CreatedById.eq(user.id))
+        .filter(blog_post_comment::Column::CreatedAt.gt(since))
+        .count(&db)
+        .await?;
+    if recent >= limits.rate_limit {
+        return Err(ApiError::too_many_requests(
+            "You are commenting too fast. Try again in a few minutes.",
+        ));
+    }
+
+    let saved = blog_post_comment::ActiveModel {
+        blog_post_id: Set(post_id),
+        comment: Set(comment),
+        is_hidden: Set(false),
+        created_by_id: Set(user.id),
+        parent_comment_id: Set(parent_id),
+        ..Default::default()
+    }
+    .insert(&db)
+    .await?;
+
+    Ok(reader(Some(&user), &config).item(saved, user.name))
+}
+
+/// Edits a comment, only allowed to its author within the edit window.
+#[server]
+pub async fn edit_comment(id: i32, comment: String) -> Result<CommentItem, ApiError> {
+    use entity::{blog_post_comment, prelude::BlogPostComment};
+    use sea_orm::{ActiveModelTrait, ActiveValue::Set, EntityTrait};
+
+    use crate::server::{config::use_config, database::use_database, guards::require_active_user};
+
+    let user = require_active_user().await?;
+    let config = use_config()?;
+    let comment = validate_comment(&comment, config.comments.max_length)?;
+
+    let db = use_database()?;
+    let existing = BlogPostComment::find_by_id(id)
+        .one(&db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Comment not found."))?;
+    let reader = reader(Some(&user), &config);
+    if existing.created_by_id != user.id {
+        return Err(ApiError::forbidden("You can only edit your own comments."));
+    }
+    if !reader.can_edit(&existing) {
+        return Err(ApiError::forbidden("This comment can no longer be edited."));
+    }
+
+    let mut existing: blog_post_comment::ActiveModel = existing.into();
+    existing.comment = Set(comment);
+    let saved = existing.update(&db).await?;
+
+    Ok(reader.item(saved, user.name))
+}
+
+/// Hides or unhides a comment, its replies stay visible.
+#[server]
+pub async fn set_comment_hidden(id: i32, #[server(default)] hidden: bool) -> Result<(), ApiError> {
+    use entity::{blog_post_comment, prelude::BlogPostComment};
+    use sea_orm::{ActiveModelTrait, ActiveValue::Set, EntityTrait};
+
+    use crate::server::{database::use_database, guards::require_staff};
+
+    let staff = require_staff().await?;
+    let db = use_database()?;
+
+    let comment = BlogPostComment::find_by_id(id)
+        .one(&db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Comment not found."))?;
+    let mut comment: blog_post_comment::ActiveModel = comment.into();
+    comment.is_hidden = Set(hidden);
+    comment.update(&db).await?;
+
+    log::info!(
+        "staff {} {} comment {id}",
+        staff.id,
+        if hidden { "hid" } else { "unhid" }
+    );
+    Ok(())
+}
@@ -0,0 +1,327 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::str::FromStr;
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{ApiError, technologies::TechnologyType};
+
+/// Where an open source contribution is hosted or published.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CodeSource {
+    #[default]
+    Github,
+    Gitlab,
+    CratesIo,
+    Npm,
+    Pypi,
+    Other,
+}
+
+impl CodeSource {
+    pub const ALL: [Self; 6] = [
+        Self::Github,
+        Self::Gitlab,
+        Self::CratesIo,
+        Self::Npm,
+        Self::Pypi,
+        Self::Other,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Github => "github",
+            Self::Gitlab => "gitlab",
+            Self::CratesIo => "crates_io",
+            Self::Npm => "npm",
+            Self::Pypi => "pypi",
+            Self::Other => "other",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Github => "GitHub",
+            Self::Gitlab => "GitLab",
+            Self::CratesIo => "crates.io",
+            Self::Npm => "npm",
+            Self::Pypi => "PyPI",
+            Self::Other => "Other",
+        }
+    }
+}
+
+impl FromStr for CodeSource {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|source| source.as_str() == value)
+            .ok_or(())
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::sea_orm_active_enums::CodeSourceEnum> for CodeSource {
+    fn from(value: entity::sea_orm_active_enums::CodeSourceEnum) -> Self {
+        use entity::sea_orm_active_enums::CodeSourceEnum;
+
+        match value {
+            CodeSourceEnum::Github => Self::Github,
+            CodeSourceEnum::Gitlab => Self::Gitlab,
+            CodeSourceEnum::CratesIo => Self::CratesIo,
+            CodeSourceEnum::Npm => Self::Npm,
+            CodeSourceEnum::Pypi => Self::Pypi,
+            CodeSourceEnum::Other => Self::Other,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<CodeSource> for entity::sea_orm_active_enums::CodeSourceEnum {
+    fn from(value: CodeSource) -> Self {
+        match value {
+            CodeSource::Github => Self::Github,
+            CodeSource::Gitlab => Self::Gitlab,
+            CodeSource::CratesIo => Self::CratesIo,
+            CodeSource::Npm => Self::Npm,
+            CodeSource::Pypi => Self::Pypi,
+            CodeSource::Other => Self::Other,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExperienceItem {
+    pub id: i32,
+    pub title: String,
+    pub company: String,
+    pub location: String,
+    pub date: String,
+    pub points: Vec<String>,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::experience::Model> for ExperienceItem {
+    fn from(model: entity::experience::Model) -> Self {
+        use crate::utils::dates::date_range;
+
+        let end_date = if model.is_current {
+            None
+        } else {
+            model.end_date
+        };
+
+        Self {
+            id: model.id,
+            title: model.title,
+            company: model.company,
+            location: model.location,
+            date: date_range(model.start_date, end_date),
+            points: model
+                .achievements
+                .as_deref()
+                .map(split_points)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Splits a multi-line text column into bullet points, one per non-empty
+/// line, dropping any markdown list marker the author may have typed.
+#[cfg(feature = "ssr")]
+pub(crate) fn split_points(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| {
+            line.trim()
+                .trim_start_matches(['-', '*', '•'])
+                .trim_start()
+                .to_string()
+        })
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Lists the site owner's work experience, most recent first.
+#[server]
+pub async fn get_experiences() -> Result<Vec<ExperienceItem>, ApiError> {
+    use entity::{experience, prelude::Experience};
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+    use crate::server::{config::use_config, database::use_database, site::find_owner};
+
+    let db = use_database()?;
+    let config = use_config()?;
+    let owner = find_owner(&db, &config.site).await?;
+
+    let experiences = Experience::find()
+        .filter(experience::Column::CreatedById.eq(owner.id))
+        .order_by_desc(experience::Column::StartDate)
+        .all(&db)
+        .await?;
+
+    Ok(experiences.into_iter().map(ExperienceItem::from).collect())
+}
+
+/// Order of the skills within each type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillSort {
+    /// Most recently used first.
+    #[default]
+    Recency,
+    /// Longest used first.
+    Duration,
+}
+
+impl SkillSort {
+    pub const ALL: [Self; 2] = [Self::Recency, Self::Duration];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Recency => "Most recent",
+            Self::Duration => "Most experience",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillItem {
+    pub technology_id: i32,
+    pub name: String,
+    pub icon: String,
+    pub is_current: bool,
+    pub date: String,
+    /// Whole months between the first and last usage, today for current
+    /// skills.
+    pub months: i32,
+    /// Days from the common era to the last usage, to sort by recency.
+    pub last_used: i32,
+}
+
+impl SkillItem {
+    /// Experience as shown on the CV, e.g. "8 months" or "3+ years".
+    pub fn experience(&self) -> String {
+        match self.months {
+            ..=1 => "1 month".to_string(),
+            months @ 2..12 => format!("{months} months"),
+            12..24 => "1+ year".to_string(),
+            months => format!("{}+ years", months / 12),
+        }
+    }
+}
+
+/// Skills of one type of technology.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkillGroup {
+    pub tech_type: TechnologyType,
+    pub skills: Vec<SkillItem>,
+}
+
+impl SkillGroup {
+    pub fn sort(&mut self, sort: SkillSort) {
+        match sort {
+            SkillSort::Recency => self.skills.sort_by(|a, b| {
+                b.last_used
+                    .cmp(&a.last_used)
+                    .then(b.months.cmp(&a.months))
+                    .then_with(|| a.name.cmp(&b.name))
+            }),
+            SkillSort::Duration => self.skills.sort_by(|a, b| {
+                b.months
+                    .cmp(&a.months)
+                    .then(b.last_used.cmp(&a.last_used))
+                    .then_with(|| a.name.cmp(&b.name))
+            }),
+        }
+    }
+}
+
+/// Last usage of a skill, `None` while it is used. Past skills saved before
+/// their last usage was required count as used only when first used.
+#[cfg(feature = "ssr")]
+pub(crate) fn skill_last_usage(skill: &entity::skill::Model) -> Option<chrono::NaiveDate> {
+    (!skill.is_currently_used).then(|| skill.last_usage_date.unwrap_or(skill.initial_usage_date))
+}
+
+/// Whole months from `start` to `end`.
+#[cfg(feature = "ssr")]
+fn months_between(start: chrono::NaiveDate, end: chrono::NaiveDate) -> i32 {
+    use chrono::Datelike;
+
+    let months = (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+    let months = if end.day() < start.day() {
+        months - 1
+    } else {
+        months
+    };
+    months.max(0)
+}
+
+/// Lists the site owner's skills grouped by the type of their technology,
+/// most recently used first.
+#[server]
+pub async fn get_skills() -> Result<Vec<SkillGroup>, ApiError> {
+    use chrono::{Datelike, Utc};
+    use entity::{
+        prelude::{Skill, Technology},
+        skill,
+    };
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+    use crate::{
+        server::{config::use_config, database::use_database, site::find_owner},
+        utils::dates::date_range,
+    };
+
+    let db = use_database()?;
+    let config = use_config()?;
+    let owner = find_owner(&db, &config.site).await?;
+
+    let skills = Skill::find()
+        .find_also_related(Technology)
+        .filter(skill::Column::CreatedById.eq(owner.id))
+        .all(&db)
+        .await?;
+
+    let today = Utc::now().date_naive();
+    let mut groups = TechnologyType::ALL
+        .into_iter()
+        .map(|tech_type| SkillGroup {
+            tech_type,
+            skills: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+    for (skill, technology) in skills {
+        let Some(technology) = technology else {
+            continue;
+        };
+        let last_usage = skill_last_usage(&skill);
+        let end = last_usage.unwrap_or(today);
+        let tech_type = TechnologyType::from(technology.tech_type);
+        if let Some(group) = groups.iter_mut().find(|group| group.tech_type == tech_type) {
+            group.skills.push(SkillItem {
+                technology_id: technology.id,
+                name: technology.name,
+                icon: technology.icon,
+                is_current: skill.is_currently_used,
+                date: date_range(skill.initial_usage_date, last_usage),
+                months: months_between(skill.initial_usage_date, end),
+                last_used: end.num_days_from_ce(),
+            });
+        }
+    }
+
+    groups.retain(|group| !group.skills.is_empty());
+    for group in &mut groups {
+        group.sort(SkillSort::default());
+    }
+    Ok(groups)
+}
@@ -0,0 +1,158 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::fmt;
+
+#[cfg(feature = "ssr")]
+use leptos::server_fn::{Bytes, codec::Encodes};
+use leptos::server_fn::{
+    codec::JsonEncoding,
+    error::{FromServerFnError, ServerFnErrorErr},
+};
+use serde::{Deserialize, Serialize};
+
+/// Error returned by every server function of the app.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApiError {
+    /// Invalid input, e.g. a form field failing validation.
+    BadRequest(String),
+    /// No signed in user.
+    Unauthorized(String),
+    /// The signed in user is not allowed to do this.
+    Forbidden(String),
+    NotFound(String),
+    /// The submitted `version` is stale, someone else saved in the meantime.
+    Conflict(String),
+    /// A rate limit was hit.
+    TooManyRequests(String),
+    Internal(String),
+    ServerFn(ServerFnErrorErr),
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadRequest(message)
+            | Self::Unauthorized(message)
+            | Self::Forbidden(message)
+            | Self::NotFound(message)
+            | Self::Conflict(message)
+            | Self::TooManyRequests(message)
+            | Self::Internal(message) => write!(f, "{message}"),
+            Self::ServerFn(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<ServerFnErrorErr> for ApiError {
+    fn from(value: ServerFnErrorErr) -> Self {
+        Self::ServerFn(value)
+    }
+}
+
+impl FromServerFnError for ApiError {
+    type Encoder = JsonEncoding;
+
+    fn from_server_fn_error(value: ServerFnErrorErr) -> Self {
+        Self::ServerFn(value)
+    }
+
+    /// Serializes the error a server function returned, setting the HTTP
+    /// status matching it on the response.
+    #[cfg(feature = "ssr")]
+    fn ser(&self) -> Bytes {
+        self.set_response_status();
+        JsonEncoding::encode(self).unwrap_or_else(|err| {
+            JsonEncoding::encode(&Self::ServerFn(ServerFnErrorErr::Serialization(
+                err.to_string(),
+            )))
+            .unwrap_or_default()
+        })
+    }
+}
+
+impl ApiError {
+    /// Sets the HTTP status matching this error on the current response.
+    /// Pages call it when showing an error their resources loaded, as during
+    /// server-side rendering those call the server functions directly.
+    pub fn set_response_status(&self) {
+        #[cfg(feature = "ssr")]
+        if let Some(response) = leptos::prelude::use_context::<leptos_actix::ResponseOptions>() {
+            response.set_status(self.status_code());
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl ApiError {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        use actix_web::http::StatusCode;
+
+        match self {
+            Self::BadRequest(_) => StatusCode::BAD_REQUEST,
+            Self::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+            Self::Internal(_) | Self::ServerFn(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::NotFound(message.into())
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::BadRequest(message.into())
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::Conflict(message.into())
+    }
+
+    pub fn too_many_requests(message: impl Into<String>) -> Self {
+        Self::TooManyRequests(message.into())
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::Unauthorized(message.into())
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::Forbidden(message.into())
+    }
+}
+
+/// Lets Actix routes return the same errors as server functions.
+#[cfg(feature = "ssr")]
+impl actix_web::ResponseError for ApiError {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        ApiError::status_code(self)
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        actix_web::HttpResponse::build(ApiError::status_code(self)).body(self.to_string())
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<sea_orm::DbErr> for ApiError {
+    fn from(err: sea_orm::DbErr) -> Self {
+        log::error!("database error: {err}");
+        Self::Internal("Something went wrong, please try again later.".to_string())
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<crate::server::session::SessionError> for ApiError {
+    fn from(err: crate::server::session::SessionError) -> Self {
+        log::error!("{err}");
+        Self::Internal("Something went wrong, please try again later.".to_string())
+    }
+}
@@ -0,0 +1,22 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub mod admin;
+pub mod auth;
+pub mod blog;
+pub mod comments;
+pub mod cv;
+pub mod error;
+pub mod moderation;
+pub mod portfolio;
+pub mod profile;
+pub mod reactions;
+pub mod search;
+pub mod tags;
+pub mod technologies;
+pub mod uploads;
+
+pub use error::ApiError;
@@ -0,0 +1,308 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ApiError;
+
+#[cfg(feature = "ssr")]
+const REASON_MAX_LENGTH: usize = 1000;
+
+/// Suspension as shown to the affected user.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuspensionNotice {
+    pub reason: String,
+    /// End of a temporary suspension, `None` for bans.
+    pub until: Option<String>,
+    pub is_banned: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuspensionItem {
+    pub id: i32,
+    /// `datetime-local` value of `suspended_until`, in UTC.
+    pub suspended_until: String,
+    pub reason: String,
+    pub is_banned: bool,
+    pub is_active: bool,
+    /// Active and either a ban or not expired yet.
+    pub in_effect: bool,
+    pub version: i32,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::user_suspension::Model> for SuspensionItem {
+    fn from(suspension: entity::user_suspension::Model) -> Self {
+        use crate::utils::dates::datetime_input;
+
+        let in_effect = suspension.is_active
+            && (suspension.is_banned
+                || suspension.suspended_until > chrono::Utc::now().naive_utc());
+        Self {
+            id: suspension.id,
+            suspended_until: datetime_input(suspension.suspended_until),
+            reason: suspension.suspended_reason,
+            is_banned: suspension.is_banned,
+            is_active: suspension.is_active,
+            in_effect,
+            version: suspension.version,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModeratedUser {
+    pub id: i32,
+    pub name: String,
+    pub email: String,
+    pub is_active: bool,
+    pub is_staff: bool,
+    pub is_admin: bool,
+    pub version: i32,
+    pub joined_at: String,
+    pub suspension: Option<SuspensionItem>,
+}
+
+#[cfg(feature = "ssr")]
+impl From<(entity::user::Model, Option<entity::user_suspension::Model>)> for ModeratedUser {
+    fn from(
+        (user, suspension): (entity::user::Model, Option<entity::user_suspension::Model>),
+    ) -> Self {
+        use crate::utils::dates::day_month_year;
+
+        Self {
+            id: user.id,
+            name: user.name,
+            email: user.email,
+            is_active: user.is_active,
+            is_staff: user.is_staff,
+            is_admin: user.is_admin,
+            version: user.version,
+            joined_at: day_month_year(user.created_at),
+            suspension: suspension.map(SuspensionItem::from),
+        }
+    }
+}
+
+/// Loads the suspension of `user_id`, failing when the user does not exist.
+#[cfg(feature = "ssr")]
+async fn find_user_suspension(
+    db: &sea_orm::DatabaseConnection,
+    user_id: i32,
+) -> Result<(entity::user::Model, Option<entity::user_suspension::Model>), ApiError> {
+    use entity::prelude::{User, UserSuspension};
+    use sea_orm::EntityTrait;
+
+    User::find_by_id(user_id)
+        .find_also_related(UserSuspension)
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("User not found."))
+}
+
+/// Suspension in effect for the signed in user or, right after a refused
+/// sign-in, for the banned user.
+#[server]
+pub async fn get_my_suspension() -> Result<Option<SuspensionNotice>, ApiError> {
+    use crate::server::{
+        auth::suspension_notice_user, config::use_config, database::use_database,
+        guards::active_suspension, session::use_current_user,
+    };
+    use crate::utils::dates::day_month_year;
+
+    let db = use_database()?;
+    let config = use_config()?;
+    let user_id = use_current_user().map(|user| user.id).or_else(|| {
+        use_context::<leptos_actix::Request>()
+            .and_then(|req| suspension_notice_user(&req, &config.auth.cookie_key))
+    });
+    let Some(user_id) = user_id else {
+        return Err(ApiError::unauthorized("You need to sign in first."));
+    };
+
+    Ok(active_suspension(&db, user_id)
+        .await?
+        .map(|suspension| SuspensionNotice {
+            reason: suspension.suspended_reason,
+            until: (!suspension.is_banned).then(|| day_month_year(suspension.suspended_until)),
+            is_banned: suspension.is_banned,
+        }))
+}
+
+#[server]
+pub async fn list_users() -> Result<Vec<ModeratedUser>, ApiError> {
+    use entity::{
+        prelude::{User, UserSuspension},
+        user,
+    };
+    use sea_orm::{EntityTrait, QueryOrder};
+
+    use crate::server::{database::use_database, guards::require_admin};
+
+    require_admin().await?;
+    let db = use_database()?;
+
+    let users = User::find()
+        .find_also_related(UserSuspension)
+        .order_by_asc(user::Column::Id)
+        .all(&db)
+        .await?;
+    Ok(users.into_iter().map(ModeratedUser::from).collect())
+}
+
+#[server]
+pub async fn get_moderated_user(id: i32) -> Result<ModeratedUser, ApiError> {
+    use crate::server::{database::use_database, guards::require_admin};
+
+    require_admin().await?;
+    let db = use_database()?;
+
+    Ok(find_user_suspension(&db, id).await?.into())
+}
+
+/// Creates or updates (e.g. extends) the suspension of `user_id`. `version` is
+/// the one the admin last saw, `None` when there was no suspension yet.
+#[server]
+pub async fn save_suspension(
+    user_id: i32,
+    suspended_until: String,
+    reason: String,
+    #[server(default)] is_banned: bool,
+    #[server(default)] version: Option<i32>,
+) -> Result<SuspensionItem, ApiError> {
+    use chrono::Utc;
+    use entity::user_suspension;
+    use sea_orm::ActiveValue::Set;
+
+    use crate::server::{
+        database::use_database,
+        guards::require_admin,
+        versioning::{insert_unique, update_versioned},
+    };
+    use crate::utils::dates::parse_datetime_input;
+
+    let admin = require_admin().await?;
+    if admin.id == user_id {
+        return Err(ApiError::bad_request("You cannot suspend yourself."));
+    }
+
+    let reason = reason.trim().to_string();
+    if reason.is_empty() || reason.chars().count() > REASON_MAX_LENGTH {
+        return Err(ApiError::bad_request(format!(
+            "The reason must have between 1 and {REASON_MAX_LENGTH} characters."
+        )));
+    }
+    let now = Utc::now().naive_utc();
+    let suspended_until = match parse_datetime_input(&suspended_until) {
+        Some(until) if until > now || is_banned => until,
+        // bans do not expire, so the date is optional
+        None if is_banned && suspended_until.trim().is_empty() => now,
+        _ => {
+            return Err(ApiError::bad_request(
+                "The suspension must end in the future.",
+            ));
+        }
+    };
+
+    let db = use_database()?;
+    let (_, existing) = find_user_suspension(&db, user_id).await?;
+
+    let saved = match (existing, version) {
+        (Some(suspension), Some(version)) => {
+            let mut suspension: user_suspension::ActiveModel = suspension.into();
+            suspension.suspended_until = Set(suspended_until);
+            suspension.suspended_reason = Set(reason);
+            suspension.is_banned = Set(is_banned);
+            suspension.is_active = Set(true);
+            update_versioned(&db, suspension, version).await?
+        }
+        (None, None) => {
+            let suspension = user_suspension::ActiveModel {
+                user_id: Set(user_id),
+                suspended_until: Set(suspended_until),
+                suspended_reason: Set(reason),
+                is_banned: Set(is_banned),
+                is_active: Set(true),
+                version: Set(1),
+                ..Default::default()
+            };
+            insert_unique(&db, suspension).await?
+        }
+        // created or removed since the admin loaded the form
+        _ => {
+            return Err(ApiError::conflict(
+                "Someone else changed this suspension in the meantime. Review it and try again.",
+            ));
+        }
+    };
+
+    log::info!(
+        "admin {} {} user {user_id}",
+        admin.id,
+        if is_banned { "banned" } else { "suspended" }
+    );
+    Ok(saved.into())
+}
+
+/// Lifts the suspension of `user_id`, keeping the row as history.
+#[server]
+pub async fn lift_suspension(user_id: i32, version: i32) -> Result<SuspensionItem, ApiError> {
+    use entity::user_suspension;
+    use sea_orm::ActiveValue::Set;
+
+    use crate::server::{
+        database::use_database, guards::require_admin, versioning::update_versioned,
+    };
+
+    let admin = require_admin().await?;
+    let db = use_database()?;
+
+    let Some(suspension) = find_user_suspension(&db, user_id).await?.1 else {
+        return Err(ApiError::not_found("This user is not suspended."));
+    };
+    let mut suspension: user_suspension::ActiveModel = suspension.into();
+    suspension.is_active = Set(false);
+    let lifted = update_versioned(&db, suspension, version).await?;
+
+    log::info!("admin {} lifted the suspension of user {user_id}", admin.id);
+    Ok(lifted.into())
+}
+
+/// Updates the flags of `id`, `version` being the one the admin last saw.
+#[server]
+pub async fn update_user_roles(
+    id: i32,
+    version: i32,
+    #[server(default)] is_active: bool,
+    #[server(default)] is_staff: bool,
+    #[server(default)] is_admin: bool,
+) -> Result<ModeratedUser, ApiError> {
+    use entity::user;
+    use sea_orm::ActiveValue::Set;
+
+    use crate::server::{
+        database::use_database, guards::require_admin, versioning::update_versioned,
+    };
+
+    let admin = require_admin().await?;
+    if admin.id == id && (!is_active || !is_admin) {
+        return Err(ApiError::bad_request(
+            "You cannot deactivate or demote yourself.",
+        ));
+    }
+    let db = use_database()?;
+
+    let (user, suspension) = find_user_suspension(&db, id).await?;
+    let mut user: user::ActiveModel = user.into();
+    user.is_active = Set(is_active);
+    user.is_staff = Set(is_staff);
+    user.is_admin = Set(is_admin);
+    let user = update_versioned(&db, user, version).await?;
+
+    log::info!("admin {} updated the roles of user {id}", admin.id);
+    Ok((user, suspension).into())
+}
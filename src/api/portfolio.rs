@@ -0,0 +1,477 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::str::FromStr;
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{ApiError, technologies::TechnologyBadge};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectStatus {
+    #[default]
+    Ongoing,
+    Completed,
+    Maintenance,
+    Archived,
+    OnHold,
+    Dropped,
+}
+
+impl ProjectStatus {
+    pub const ALL: [Self; 6] = [
+        Self::Ongoing,
+        Self::Completed,
+        Self::Maintenance,
+        Self::Archived,
+        Self::OnHold,
+        Self::Dropped,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ongoing => "ongoing",
+            Self::Completed => "completed",
+            Self::Maintenance => "maintenance",
+            Self::Archived => "archived",
+            Self::OnHold => "on_hold",
+            Self::Dropped => "dropped",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Ongoing => "Ongoing",
+            Self::Completed => "Completed",
+            Self::Maintenance => "Maintenance",
+            Self::Archived => "Archived",
+            Self::OnHold => "On Hold",
+            Self::Dropped => "Dropped",
+        }
+    }
+}
+
+impl FromStr for ProjectStatus {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str() == value)
+            .ok_or(())
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::sea_orm_active_enums::ProjectStatusEnum> for ProjectStatus {
+    fn from(value: entity::sea_orm_active_enums::ProjectStatusEnum) -> Self {
+        use entity::sea_orm_active_enums::ProjectStatusEnum;
+
+        match value {
+            ProjectStatusEnum::Ongoing => Self::Ongoing,
+            ProjectStatusEnum::Completed => Self::Completed,
+            ProjectStatusEnum::Maintenance => Self::Maintenance,
+            ProjectStatusEnum::Archived => Self::Archived,
+            ProjectStatusEnum::OnHold => Self::OnHold,
+            ProjectStatusEnum::Dropped => Self::Dropped,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<ProjectStatus> for entity::sea_orm_active_enums::ProjectStatusEnum {
+    fn from(value: ProjectStatus) -> Self {
+        match value {
+            ProjectStatus::Ongoing => Self::Ongoing,
+            ProjectStatus::Completed => Self::Completed,
+            ProjectStatus::Maintenance => Self::Maintenance,
+            ProjectStatus::Archived => Self::Archived,
+            ProjectStatus::OnHold => Self::OnHold,
+            ProjectStatus::Dropped => Self::Dropped,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkType {
+    Website,
+    Github,
+    Gitlab,
+    DevTo,
+    Linkedin,
+    Youtube,
+    Other,
+}
+
+impl LinkType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Website => "website",
+            Self::Github => "github",
+            Self::Gitlab => "gitlab",
+            Self::DevTo => "dev_to",
+            Self::Linkedin => "linkedin",
+            Self::Youtube => "youtube",
+            Self::Other => "other",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Website => "Website",
+            Self::Github => "GitHub",
+            Self::Gitlab => "GitLab",
+            Self::DevTo => "DEV",
+            Self::Linkedin => "LinkedIn",
+            Self::Youtube => "YouTube",
+            Self::Other => "Link",
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::sea_orm_active_enums::ProjectLinkTypeEnum> for LinkType {
+    fn from(value: entity::sea_orm_active_enums::ProjectLinkTypeEnum) -> Self {
+        use entity::sea_orm_active_enums::ProjectLinkTypeEnum;
+
+        match value {
+            ProjectLinkTypeEnum::Website => Self::Website,
+            ProjectLinkTypeEnum::Github => Self::Github,
+            ProjectLinkTypeEnum::Gitlab => Self::Gitlab,
+            ProjectLinkTypeEnum::DevTo => Self::DevTo,
+            ProjectLinkTypeEnum::Linkedin => Self::Linkedin,
+            ProjectLinkTypeEnum::Youtube => Self::Youtube,
+            ProjectLinkTypeEnum::Other => Self::Other,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectLinkItem {
+    pub url: String,
+    pub link_type: LinkType,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::project_link::Model> for ProjectLinkItem {
+    fn from(model: entity::project_link::Model) -> Self {
+        Self {
+            url: model.link_url,
+            link_type: model.link_type.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectSummary {
+    pub id: i32,
+    pub slug: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub status: ProjectStatus,
+    pub date: String,
+    pub cover_image: Option<String>,
+    pub technologies: Vec<TechnologyBadge>,
+    pub links: Vec<ProjectLinkItem>,
+}
+
+/// Image type marking the picture shown on a project's card.
+pub const COVER_IMAGE_TYPE: &str = "cover";
+
+/// Picks the cover image of a project, falling back to its first image.
+#[cfg(feature = "ssr")]
+fn cover_image(images: &[entity::project_image::Model]) -> Option<String> {
+    images
+        .iter()
+        .find(|image| image.image_type.eq_ignore_ascii_case(COVER_IMAGE_TYPE))
+        .or_else(|| images.first())
+        .map(|image| image.image_url.clone())
+}
+
+/// Lists the site owner's projects, most recent first, optionally filtered by
+/// a technology they use and by status.
+#[server]
+pub async fn list_projects(
+    technology: Option<i32>,
+    status: Option<ProjectStatus>,
+) -> Result<Vec<ProjectSummary>, ApiError> {
+    use entity::{
+        prelude::{Project, ProjectImage, ProjectLink, ProjectTechnology, Technology},
+        project, project_image, project_technology,
+    };
+    use sea_orm::{
+        ColumnTrait, EntityTrait, LoaderTrait, QueryFilter, QueryOrder, sea_query::Query,
+    };
+
+    use crate::{
+        server::{config::use_config, database::use_database, site::find_owner},
+        utils::{dates::date_range, slug::id_slug},
+    };
+
+    let db = use_database()?;
+    let config = use_config()?;
+    let owner = find_owner(&db, &config.site).await?;
+
+    let mut query = Project::find().filter(project::Column::CreatedById.eq(owner.id));
+    if let Some(status) = status {
+        query = query.filter(project::Column::Status.eq(
+            entity::sea_orm_active_enums::ProjectStatusEnum::from(status),
+        ));
+    }
+    if let Some(technology) = technology {
+        query = query.filter(
+            project::Column::Id.in_subquery(
+                Query::select()
+                    .column(project_technology::Column::ProjectId)
+                    .from(ProjectTechnology)
+                    .and_where(project_technology::Column::TechnologyId.eq(technology))
+                    .to_owned(),
+            ),
+        );
+    }
+    let projects = query
+        .order_by_desc(project::Column::StartDate)
+        .all(&db)
+        .await?;
+
+    let technologies = projects
+        .load_many_to_many(Technology, ProjectTechnology, &db)
+        .await?;
+    let links = projects.load_many(ProjectLink, &db).await?;
+    let images = projects
+        .load_many(
+            ProjectImage::find().order_by_asc(project_image::Column::Id),
+            &db,
+        )
+        .await?;
+
+    Ok(projects
+        .into_iter()
+        .zip(technologies)
+        .zip(links)
+        .zip(images)
+        .map(
+            |(((project, technologies), links), images)| ProjectSummary {
+                id: project.id,
+                slug: id_slug(project.id, &project.title),
+                date: date_range(project.start_date, project.end_date.or(project.drop_date)),
+                title: project.title,
+                subtitle: project.subtitle,
+                status: project.status.into(),
+                cover_image: cover_image(&images),
+                technologies: technologies
+                    .into_iter()
+                    .map(TechnologyBadge::from)
+                    .collect(),
+                links: links.into_iter().map(ProjectLinkItem::from).collect(),
+            },
+        )
+        .collect())
+}
+
+/// Lists the technologies used by the site owner's projects, for filtering.
+#[server]
+pub async fn list_project_technologies() -> Result<Vec<TechnologyBadge>, ApiError> {
+    use entity::{prelude::Technology, project, project_technology, technology};
+    use sea_orm::{
+        ColumnTrait, EntityTrait, JoinType, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+    };
+
+    use crate::server::{config::use_config, database::use_database, site::find_owner};
+
+    let db = use_database()?;
+    let config = use_config()?;
+    let owner = find_owner(&db, &config.site).await?;
+
+    let technologies = Technology::find()
+        .join(
+            JoinType::InnerJoin,
+            technology::Relation::ProjectTechnology.def(),
+        )
+        .join(
+            JoinType::InnerJoin,
+            project_technology::Relation::Project.def(),
+        )
+        .filter(project::Column::CreatedById.eq(owner.id))
+        .distinct()
+        .order_by_asc(technology::Column::Name)
+        .all(&db)
+        .await?;
+
+    Ok(technologies
+        .into_iter()
+        .map(TechnologyBadge::from)
+        .collect())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageGroup {
+    pub image_type: String,
+    pub images: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelatedProject {
+    pub slug: String,
+    pub title: String,
+    pub status: ProjectStatus,
+    pub shared_technologies: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectDetail {
+    pub id: i32,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub description: String,
+    pub status: ProjectStatus,
+    pub start_date: String,
+    pub end_date: Option<String>,
+    pub drop_date: Option<String>,
+    pub technologies: Vec<TechnologyBadge>,
+    pub links: Vec<ProjectLinkItem>,
+    pub gallery: Vec<ImageGroup>,
+    pub related: Vec<RelatedProject>,
+}
+
+/// Number of related projects shown on a project page.
+#[cfg(feature = "ssr")]
+const RELATED_PROJECTS_LIMIT: u64 = 4;
+
+/// Groups a project's images by their type, keeping the order in which each
+/// type first appears.
+#[cfg(feature = "ssr")]
+fn group_images(images: Vec<entity::project_image::Model>) -> Vec<ImageGroup> {
+    let mut groups: Vec<ImageGroup> = Vec::new();
+    for image in images {
+        match groups
+            .iter_mut()
+            .find(|group| group.image_type == image.image_type)
+        {
+            Some(group) => group.images.push(image.image_url),
+            None => groups.push(ImageGroup {
+                image_type: image.image_type,
+                images: vec![image.image_url],
+            }),
+        }
+    }
+    groups
+}
+
+/// Loads a single project of the site owner by its slug, along with the
+/// projects sharing the most technologies with it.
+#[server]
+pub async fn get_project(slug: String) -> Result<ProjectDetail, ApiError> {
+    use entity::{
+        prelude::{Project, ProjectImage, ProjectLink, ProjectTechnology, Technology},
+        project, project_image, project_link, project_technology, technology,
+    };
+    use sea_orm::{
+        ColumnTrait, EntityTrait, JoinType, ModelTrait, Order, QueryFilter, QueryOrder,
+        QuerySelect, RelationTrait, sea_query::Expr,
+    };
+
+    use crate::{
+        server::{config::use_config, database::use_database, site::find_owner},
+        utils::{
+            dates::month_year,
+            slug::{id_slug, parse_id_slug},
+        },
+    };
+
+    let not_found = || ApiError::not_found("Project not found.");
+    let id = parse_id_slug(&slug).ok_or_else(not_found)?;
+
+    let db = use_database()?;
+    let config = use_config()?;
+    let owner = find_owner(&db, &config.site).await?;
+
+    let project = Project::find_by_id(id)
+        .filter(project::Column::CreatedById.eq(owner.id))
+        .one(&db)
+        .await?
+        .ok_or_else(not_found)?;
+
+    let technologies = project
+        .find_related(Technology)
+        .order_by_asc(technology::Column::Name)
+        .all(&db)
+        .await?;
+    let links = project
+        .find_related(ProjectLink)
+        .order_by_asc(project_link::Column::Id)
+        .all(&db)
+        .await?;
+    let images = project
+        .find_related(ProjectImage)
+        .order_by_asc(project_image::Column::Id)
+        .all(&db)
+        .await?;
+
+    let technology_ids = technologies
+        .iter()
+        .map(|technology| technology.id)
+        .collect::<Vec<_>>();
+    let shared = Expr::col((ProjectTechnology, project_technology::Column::TechnologyId)).count();
+    let related = if technology_ids.is_empty() {
+        Vec::new()
+    } else {
+        ProjectTechnology::find()
+            .select_only()
+            .column(project::Column::Id)
+            .column(project::Column::Title)
+            .column(project::Column::Status)
+            .column_as(shared.clone(), "shared_technologies")
+            .join(
+                JoinType::InnerJoin,
+                project_technology::Relation::Project.def(),
+            )
+            .filter(project_technology::Column::TechnologyId.is_in(technology_ids))
+            .filter(project::Column::Id.ne(project.id))
+            .filter(project::Column::CreatedById.eq(owner.id))
+            .group_by(project::Column::Id)
+            .order_by(shared, Order::Desc)
+            .order_by_desc(project::Column::StartDate)
+            .limit(RELATED_PROJECTS_LIMIT)
+            .into_tuple::<(
+                i32,
+                String,
+                entity::sea_orm_active_enums::ProjectStatusEnum,
+                i64,
+            )>()
+            .all(&db)
+            .await?
+    };
+
+    Ok(ProjectDetail {
+        id: project.id,
+        start_date: month_year(project.start_date),
+        end_date: project.end_date.map(month_year),
+        drop_date: project.drop_date.map(month_year),
+        title: project.title,
+        subtitle: project.subtitle,
+        description: project.description,
+        status: project.status.into(),
+        technologies: technologies
+            .into_iter()
+            .map(TechnologyBadge::from)
+            .collect(),
+        links: links.into_iter().map(ProjectLinkItem::from).collect(),
+        gallery: group_images(images),
+        related: related
+            .into_iter()
+            .map(|(id, title, status, shared_technologies)| RelatedProject {
+                slug: id_slug(id, &title),
+                title,
+                status: status.into(),
+                shared_technologies,
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,94 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ApiError;
+
+/// Matches the `user.name` column length.
+#[cfg(feature = "ssr")]
+const NAME_MAX_LENGTH: usize = 200;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    pub email: String,
+    pub joined_at: String,
+    /// Labels of the providers the user signs in with.
+    pub providers: Vec<String>,
+    pub version: i32,
+}
+
+#[cfg(feature = "ssr")]
+async fn load_profile(
+    db: &sea_orm::DatabaseConnection,
+    user: entity::user::Model,
+) -> Result<Profile, ApiError> {
+    use entity::{auth_provider, prelude::AuthProvider};
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+    use super::auth::OAuthProvider;
+    use crate::utils::dates::day_month_year;
+
+    let providers = AuthProvider::find()
+        .filter(auth_provider::Column::UserId.eq(user.id))
+        .order_by_asc(auth_provider::Column::CreatedAt)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|provider| match provider.name.parse::<OAuthProvider>() {
+            Ok(known) => known.label().to_string(),
+            Err(()) => provider.name,
+        })
+        .collect();
+
+    Ok(Profile {
+        name: user.name,
+        email: user.email,
+        joined_at: day_month_year(user.created_at),
+        providers,
+        version: user.version,
+    })
+}
+
+#[server]
+pub async fn get_profile() -> Result<Profile, ApiError> {
+    use crate::server::{database::use_database, session::use_current_user};
+
+    let user =
+        use_current_user().ok_or_else(|| ApiError::unauthorized("You need to sign in first."))?;
+    let db = use_database()?;
+
+    load_profile(&db, user).await
+}
+
+/// Renames the signed in user, `version` being the one shown in the form.
+#[server]
+pub async fn update_profile(name: String, version: i32) -> Result<Profile, ApiError> {
+    use entity::user;
+    use sea_orm::ActiveValue::Set;
+
+    use crate::server::{
+        database::use_database, session::use_current_user, versioning::update_versioned,
+    };
+
+    let user =
+        use_current_user().ok_or_else(|| ApiError::unauthorized("You need to sign in first."))?;
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > NAME_MAX_LENGTH {
+        return Err(ApiError::bad_request(format!(
+            "Your name must have between 1 and {NAME_MAX_LENGTH} characters."
+        )));
+    }
+    let db = use_database()?;
+
+    let mut user: user::ActiveModel = user.into();
+    user.name = Set(name);
+    let user = update_versioned(&db, user, version).await?;
+
+    load_profile(&db, user).await
+}
@@ -0,0 +1,345 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ApiError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reaction {
+    Hate,
+    Dislike,
+    Ok,
+    Like,
+    Love,
+}
+
+impl Reaction {
+    /// From worst to best, the order of [`ReactionSummary::counts`].
+    pub const ALL: [Self; 5] = [Self::Hate, Self::Dislike, Self::Ok, Self::Like, Self::Love];
+
+    fn index(self) -> usize {
+        match self {
+            Self::Hate => 0,
+            Self::Dislike => 1,
+            Self::Ok => 2,
+            Self::Like => 3,
+            Self::Love => 4,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Hate => "Hate",
+            Self::Dislike => "Dislike",
+            Self::Ok => "Ok",
+            Self::Like => "Like",
+            Self::Love => "Love",
+        }
+    }
+
+    pub fn emoji(self) -> &'static str {
+        match self {
+            Self::Hate => "😠",
+            Self::Dislike => "👎",
+            Self::Ok => "😐",
+            Self::Like => "👍",
+            Self::Love => "❤️",
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::sea_orm_active_enums::BlogReactionsEnum> for Reaction {
+    fn from(value: entity::sea_orm_active_enums::BlogReactionsEnum) -> Self {
+        use entity::sea_orm_active_enums::BlogReactionsEnum;
+
+        match value {
+            BlogReactionsEnum::Hate => Self::Hate,
+            BlogReactionsEnum::Dislike => Self::Dislike,
+            BlogReactionsEnum::Ok => Self::Ok,
+            BlogReactionsEnum::Like => Self::Like,
+            BlogReactionsEnum::Love => Self::Love,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<Reaction> for entity::sea_orm_active_enums::BlogReactionsEnum {
+    fn from(value: Reaction) -> Self {
+        match value {
+            Reaction::Hate => Self::Hate,
+            Reaction::Dislike => Self::Dislike,
+            Reaction::Ok => Self::Ok,
+            Reaction::Like => Self::Like,
+            Reaction::Love => Self::Love,
+        }
+    }
+}
+
+/// What is being reacted to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReactionTarget {
+    Post,
+    Comment,
+}
+
+/// Reactions to a post or comment.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReactionSummary {
+    /// Number of each reaction, in [`Reaction::ALL`] order.
+    pub counts: [i64; 5],
+    /// The reader's own reaction.
+    pub mine: Option<Reaction>,
+}
+
+impl ReactionSummary {
+    pub fn count(&self, reaction: Reaction) -> i64 {
+        self.counts[reaction.index()]
+    }
+
+    /// Replaces the reader's reaction, adjusting the counts, i.e. what the
+    /// server will do, so the client can apply it before it answers.
+    pub fn react(&mut self, reaction: Option<Reaction>) {
+        if let Some(previous) = self.mine {
+            self.counts[previous.index()] = (self.counts[previous.index()] - 1).max(0);
+        }
+        if let Some(reaction) = reaction {
+            self.counts[reaction.index()] += 1;
+        }
+        self.mine = reaction;
+    }
+}
+
+/// Summarizes `(target id, reaction, count)` rows and the reader's
+/// `(target id, reaction)` rows per target.
+#[cfg(feature = "ssr")]
+fn summarize(
+    counts: Vec<(i32, entity::sea_orm_active_enums::BlogReactionsEnum, i64)>,
+    mine: Vec<(i32, entity::sea_orm_active_enums::BlogReactionsEnum)>,
+) -> std::collections::HashMap<i32, ReactionSummary> {
+    let mut summaries = std::collections::HashMap::<i32, ReactionSummary>::new();
+    for (id, reaction, count) in counts {
+        summaries.entry(id).or_default().counts[Reaction::from(reaction).index()] = count;
+    }
+    for (id, reaction) in mine {
+        summaries.entry(id).or_default().mine = Some(reaction.into());
+    }
+    summaries
+}
+
+/// Reactions to each of `post_ids`, posts without any are left out.
+#[cfg(feature = "ssr")]
+pub(crate) async fn post_reactions<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    post_ids: Vec<i32>,
+    user_id: Option<i32>,
+) -> Result<std::collections::HashMap<i32, ReactionSummary>, sea_orm::DbErr> {
+    use entity::{blog_post_reaction, prelude::BlogPostReaction};
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, sea_query::Expr};
+
+    let counts = BlogPostReaction::find()
+        .select_only()
+        .column(blog_post_reaction::Column::BlogPostId)
+        .column(blog_post_reaction::Column::Reaction)
+        .column_as(
+            Expr::col(blog_post_reaction::Column::CreatedById).count(),
+            "count",
+        )
+        .filter(blog_post_reaction::Column::BlogPostId.is_in(post_ids.clone()))
+        .group_by(blog_post_reaction::Column::BlogPostId)
+        .group_by(blog_post_reaction::Column::Reaction)
+        .into_tuple()
+        .all(db)
+        .await?;
+    let mine = match user_id {
+        Some(user_id) => {
+            BlogPostReaction::find()
+                .select_only()
+                .column(blog_post_reaction::Column::BlogPostId)
+                .column(blog_post_reaction::Column::Reaction)
+                .filter(blog_post_reaction::Column::BlogPostId.is_in(post_ids))
+                .filter(blog_post_reaction::Column::CreatedById.eq(user_id))
+                .into_tuple()
+                .all(db)
+                .await?
+        }
+        None => Vec::new(),
+    };
+    Ok(summarize(counts, mine))
+}
+
+/// Reactions to each of `comment_ids`, comments without any are left out.
+#[cfg(feature = "ssr")]
+pub(crate) async fn comment_reactions<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    comment_ids: Vec<i32>,
+    user_id: Option<i32>,
+) -> Result<std::collections::HashMap<i32, ReactionSummary>, sea_orm::DbErr> {
+    use entity::{blog_post_comment_reaction, prelude::BlogPostCommentReaction};
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, sea_query::Expr};
+
+    let counts = BlogPostCommentReaction::find()
+        .select_only()
+        .column(blog_post_comment_reaction::Column::BlogPostCommentId)
+        .column(blog_post_comment_reaction::Column::Reaction)
+        .column_as(
+            Expr::col(blog_post_comment_reaction::Column::CreatedById).count(),
+            "count",
+        )
+        .filter(blog_post_comment_reaction::Column::BlogPostCommentId.is_in(comment_ids.clone()))
+        .group_by(blog_post_comment_reaction::Column::BlogPostCommentId)
+        .group_by(blog_post_comment_reaction::Column::Reaction)
+        .into_tuple()
+        .all(db)
+        .await?;
+    let mine = match user_id {
+        Some(user_id) => {
+            BlogPostCommentReaction::find()
+                .select_only()
+                .column(blog_post_comment_reaction::Column::BlogPostCommentId)
+                .column(blog_post_comment_reaction::Column::Reaction)
+                .filter(blog_post_comment_reaction::Column::BlogPostCommentId.is_in(comment_ids))
+                .filter(blog_post_comment_reaction::Column::CreatedById.eq(user_id))
+                .into_tuple()
+                .all(db)
+                .await?
+        }
+        None => Vec::new(),
+    };
+    Ok(summarize(counts, mine))
+}
+
+/// Reactions to `id` as seen by `user_id`.
+#[cfg(feature = "ssr")]
+async fn reactions<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    target: ReactionTarget,
+    id: i32,
+    user_id: Option<i32>,
+) -> Result<ReactionSummary, sea_orm::DbErr> {
+    let mut summaries = match target {
+        ReactionTarget::Post => post_reactions(db, vec![id], user_id).await?,
+        ReactionTarget::Comment => comment_reactions(db, vec![id], user_id).await?,
+    };
+    Ok(summaries.remove(&id).unwrap_or_default())
+}
+
+/// Fails unless readers can see, and so react to, `id`.
+#[cfg(feature = "ssr")]
+async fn find_target(
+    db: &sea_orm::DatabaseConnection,
+    target: ReactionTarget,
+    id: i32,
+) -> Result<(), ApiError> {
+    use entity::prelude::BlogPostComment;
+    use sea_orm::EntityTrait;
+
+    use super::blog::find_published_post;
+
+    match target {
+        ReactionTarget::Post => {
+            find_published_post(db, id).await?;
+        }
+        ReactionTarget::Comment => {
+            let comment = BlogPostComment::find_by_id(id)
+                .one(db)
+                .await?
+                .filter(|comment| !comment.is_hidden)
+                .ok_or_else(|| ApiError::not_found("Comment not found."))?;
+            find_published_post(db, comment.blog_post_id).await?;
+        }
+    }
+    Ok(())
+}
+
+#[server]
+pub async fn get_reactions(target: ReactionTarget, id: i32) -> Result<ReactionSummary, ApiError> {
+    use crate::server::{database::use_database, session::use_current_user};
+
+    let db = use_database()?;
+    find_target(&db, target, id).await?;
+
+    let user_id = use_current_user().map(|user| user.id);
+    Ok(reactions(&db, target, id, user_id).await?)
+}
+
+/// Sets the signed in user's reaction to `id`, clearing it when `reaction` is
+/// `None`, and returns the updated reactions.
+#[server]
+pub async fn set_reaction(
+    target: ReactionTarget,
+    id: i32,
+    #[server(default)] reaction: Option<Reaction>,
+) -> Result<ReactionSummary, ApiError> {
+    use chrono::Utc;
+    use entity::{
+        blog_post_comment_reaction, blog_post_reaction,
+        prelude::{BlogPostCommentReaction, BlogPostReaction},
+    };
+    use sea_orm::{ActiveValue::Set, EntityTrait, sea_query::OnConflict};
+
+    use crate::server::{database::use_database, guards::require_active_user};
+
+    let user = require_active_user().await?;
+    let db = use_database()?;
+    find_target(&db, target, id).await?;
+
+    // one reaction per user, the composite primary key
+    match (target, reaction) {
+        (ReactionTarget::Post, Some(reaction)) => {
+            BlogPostReaction::insert(blog_post_reaction::ActiveModel {
+                blog_post_id: Set(id),
+                created_by_id: Set(user.id),
+                reaction: Set(reaction.into()),
+                created_at: Set(Utc::now().naive_utc()),
+            })
+            .on_conflict(
+                OnConflict::columns([
+                    blog_post_reaction::Column::BlogPostId,
+                    blog_post_reaction::Column::CreatedById,
+                ])
+                .update_column(blog_post_reaction::Column::Reaction)
+                .to_owned(),
+            )
+            .exec_without_returning(&db)
+            .await?;
+        }
+        (ReactionTarget::Post, None) => {
+            BlogPostReaction::delete_by_id((id, user.id))
+                .exec(&db)
+                .await?;
+        }
+        (ReactionTarget::Comment, Some(reaction)) => {
+            BlogPostCommentReaction::insert(blog_post_comment_reaction::ActiveModel {
+                blog_post_comment_id: Set(id),
+                created_by_id: Set(user.id),
+                reaction: Set(reaction.into()),
+                created_at: Set(Utc::now().naive_utc()),
+            })
+            .on_conflict(
+                OnConflict::columns([
+                    blog_post_comment_reaction::Column::BlogPostCommentId,
+                    blog_post_comment_reaction::Column::CreatedById,
+                ])
+                .update_column(blog_post_comment_reaction::Column::Reaction)
+                .to_owned(),
+            )
+            .exec_without_returning(&db)
+            .await?;
+        }
+        (ReactionTarget::Comment, None) => {
+            BlogPostCommentReaction::delete_by_id((id, user.id))
+                .exec(&db)
+                .await?;
+        }
+    }
+
+    Ok(reactions(&db, target, id, Some(user.id)).await?)
+}
@@ -0,0 +1,240 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Full-text search over blog posts, projects and technologies, backed by the
+//! PostgreSQL `search_vector` columns.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ApiError;
+
+/// Longest query searched, longer ones are cut.
+pub const QUERY_MAX_LENGTH: usize = 200;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchKind {
+    Post,
+    Project,
+    Technology,
+}
+
+impl SearchKind {
+    pub fn plural_label(self) -> &'static str {
+        match self {
+            Self::Post => "Blog posts",
+            Self::Project => "Projects",
+            Self::Technology => "Technologies",
+        }
+    }
+}
+
+/// Part of a snippet, highlighted when it matches the query.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnippetPart {
+    pub text: String,
+    pub highlighted: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub title: String,
+    pub url: String,
+    pub snippet: Vec<SnippetPart>,
+}
+
+/// Hits of one kind, best ranked first.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchGroup {
+    pub kind: SearchKind,
+    pub hits: Vec<SearchHit>,
+}
+
+/// Most hits of each kind.
+#[cfg(feature = "ssr")]
+const GROUP_LIMIT: i64 = 10;
+
+/// Marks around the matches in `ts_headline` snippets, private use characters
+/// which cannot clash with the searched text.
+#[cfg(feature = "ssr")]
+const START_MARK: char = '\u{e000}';
+#[cfg(feature = "ssr")]
+const STOP_MARK: char = '\u{e001}';
+
+#[cfg(feature = "ssr")]
+const HEADLINE_OPTIONS: &str = "StartSel=\u{e000}, StopSel=\u{e001}, MaxWords=30, MinWords=12, \
+     MaxFragments=2, FragmentDelimiter=\" … \"";
+
+/// `$1` is the query, `$2` the headline options, `$3` the current time and
+/// `$4` the limit.
+#[cfg(feature = "ssr")]
+const POSTS_QUERY: &str = r#"
+SELECT blog_post.id, blog_post.title,
+       ts_headline('english', blog_post.content, query, $2) AS snippet
+FROM blog_post, websearch_to_tsquery('english', $1) AS query
+WHERE blog_post.search_vector @@ query
+  AND blog_post.is_published
+  AND blog_post.published_at <= $3
+ORDER BY ts_rank(blog_post.search_vector, query) DESC, blog_post.id DESC
+LIMIT $4
+"#;
+
+/// `$1` is the query, `$2` the headline options, `$3` the site owner's id and
+/// `$4` the limit.
+#[cfg(feature = "ssr")]
+const PROJECTS_QUERY: &str = r#"
+SELECT project.id, project.title,
+       ts_headline('english', project.description, query, $2) AS snippet
+FROM project, websearch_to_tsquery('english', $1) AS query
+WHERE project.search_vector @@ query
+  AND project.created_by_id = $3
+ORDER BY ts_rank(project.search_vector, query) DESC, project.id DESC
+LIMIT $4
+"#;
+
+/// `$1` is the query, `$2` the headline options and `$3` the limit.
+#[cfg(feature = "ssr")]
+const TECHNOLOGIES_QUERY: &str = r#"
+SELECT technology.id, technology.name AS title,
+       ts_headline('english', coalesce(technology.description, technology.name), query, $2)
+           AS snippet
+FROM technology, websearch_to_tsquery('english', $1) AS query
+WHERE technology.search_vector @@ query
+ORDER BY ts_rank(technology.search_vector, query) DESC, technology.name
+LIMIT $3
+"#;
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, sea_orm::FromQueryResult)]
+struct SearchRow {
+    id: i32,
+    title: String,
+    snippet: String,
+}
+
+/// Splits a `ts_headline` snippet at its marks.
+#[cfg(feature = "ssr")]
+fn snippet_parts(snippet: &str) -> Vec<SnippetPart> {
+    let mut parts = Vec::new();
+    let mut push = |text: &str, highlighted: bool| {
+        if !text.is_empty() {
+            parts.push(SnippetPart {
+                text: text.to_string(),
+                highlighted,
+            });
+        }
+    };
+
+    let mut chunks = snippet.split(START_MARK);
+    push(chunks.next().unwrap_or_default(), false);
+    for chunk in chunks {
+        match chunk.split_once(STOP_MARK) {
+            Some((highlighted, rest)) => {
+                push(highlighted, true);
+                push(rest, false);
+            }
+            None => push(chunk, true),
+        }
+    }
+    parts
+}
+
+#[cfg(feature = "ssr")]
+async fn search_rows(
+    db: &sea_orm::DatabaseConnection,
+    sql: &str,
+    values: Vec<sea_orm::Value>,
+) -> Result<Vec<SearchRow>, ApiError> {
+    use sea_orm::{DbBackend, FromQueryResult, Statement};
+
+    Ok(SearchRow::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        sql,
+        values,
+    ))
+    .all(db)
+    .await?)
+}
+
+/// Searches published posts, the site owner's projects and technologies,
+/// grouping the hits by kind. Kinds without hits are left out.
+#[server]
+pub async fn search(query: String) -> Result<Vec<SearchGroup>, ApiError> {
+    use crate::{
+        server::{config::use_config, database::use_database, site::find_owner},
+        utils::slug::id_slug,
+    };
+
+    let query = query
+        .trim()
+        .chars()
+        .take(QUERY_MAX_LENGTH)
+        .collect::<String>();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let config = use_config()?;
+    let db = use_database()?;
+    let owner = find_owner(&db, &config.site).await?;
+
+    let posts = search_rows(
+        &db,
+        POSTS_QUERY,
+        vec![
+            query.clone().into(),
+            HEADLINE_OPTIONS.into(),
+            chrono::Utc::now().naive_utc().into(),
+            GROUP_LIMIT.into(),
+        ],
+    )
+    .await?;
+    let projects = search_rows(
+        &db,
+        PROJECTS_QUERY,
+        vec![
+            query.clone().into(),
+            HEADLINE_OPTIONS.into(),
+            owner.id.into(),
+            GROUP_LIMIT.into(),
+        ],
+    )
+    .await?;
+    let technologies = search_rows(
+        &db,
+        TECHNOLOGIES_QUERY,
+        vec![query.into(), HEADLINE_OPTIONS.into(), GROUP_LIMIT.into()],
+    )
+    .await?;
+
+    let group =
+        |kind: SearchKind, rows: Vec<SearchRow>, url: &dyn Fn(&SearchRow) -> String| SearchGroup {
+            kind,
+            hits: rows
+                .into_iter()
+                .map(|row| SearchHit {
+                    url: url(&row),
+                    snippet: snippet_parts(&row.snippet),
+                    title: row.title,
+                })
+                .collect(),
+        };
+    Ok([
+        group(SearchKind::Post, posts, &|row| {
+            format!("/blog/{}", id_slug(row.id, &row.title))
+        }),
+        group(SearchKind::Project, projects, &|row| {
+            format!("/portfolio/{}", id_slug(row.id, &row.title))
+        }),
+        group(SearchKind::Technology, technologies, &|row| {
+            format!("/technologies/{}", row.id)
+        }),
+    ]
+    .into_iter()
+    .filter(|group| !group.hits.is_empty())
+    .collect())
+}
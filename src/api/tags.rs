@@ -0,0 +1,368 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Tags of blog posts. Tags are normalized when a post is saved and linked to
+//! the `common_tag` of the same name, which the tag pages and cloud go by.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{ApiError, blog::PostSummary};
+
+/// Longest tag, the `string_len(100)` columns.
+pub const TAG_LENGTH: usize = 100;
+/// Most tags of a post.
+pub const MAX_POST_TAGS: usize = 10;
+/// Heaviest weight of a tag in the cloud, the lightest being 1.
+pub const MAX_TAG_WEIGHT: i64 = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagCount {
+    pub name: String,
+    /// Published posts with the tag.
+    pub post_count: i64,
+}
+
+impl TagCount {
+    /// Weight in the cloud, from 1 to [`MAX_TAG_WEIGHT`], relative to the
+    /// most used tag's `max_count`.
+    pub fn weight(&self, max_count: i64) -> i64 {
+        if max_count <= 1 {
+            return 1;
+        }
+        1 + (self.post_count - 1).max(0) * (MAX_TAG_WEIGHT - 1) / (max_count - 1)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagPosts {
+    pub name: String,
+    pub posts: Vec<PostSummary>,
+}
+
+/// Normalizes a free-text tag so spellings differing in case, whitespace or
+/// diacritics are the same tag, e.g. " Café  Racer" into "cafe-racer".
+#[cfg(feature = "ssr")]
+pub(crate) fn normalize_tag(tag: &str) -> String {
+    use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+    tag.nfkd()
+        .filter(|c| !is_combining_mark(*c))
+        .collect::<String>()
+        .to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Normalizes a comma separated list of tags, dropping empty and repeated
+/// ones.
+#[cfg(feature = "ssr")]
+pub(crate) fn parse_tags(tags: &str) -> Result<Vec<String>, ApiError> {
+    let mut parsed = Vec::new();
+    for tag in tags.split(',').map(normalize_tag) {
+        if tag.is_empty() || parsed.contains(&tag) {
+            continue;
+        }
+        if tag.chars().count() > TAG_LENGTH {
+            return Err(ApiError::bad_request(format!(
+                "Tags must have at most {TAG_LENGTH} characters."
+            )));
+        }
+        parsed.push(tag);
+    }
+    if parsed.len() > MAX_POST_TAGS {
+        return Err(ApiError::bad_request(format!(
+            "Posts can have at most {MAX_POST_TAGS} tags."
+        )));
+    }
+    Ok(parsed)
+}
+
+/// Replaces the names of merged tags with the tag they were merged into,
+/// dropping the repeats it makes.
+#[cfg(feature = "ssr")]
+pub(crate) async fn resolve_aliases<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    tags: &[String],
+) -> Result<Vec<String>, sea_orm::DbErr> {
+    use std::collections::HashMap;
+
+    use entity::{common_tag, common_tag_alias, prelude::CommonTagAlias};
+    use sea_orm::{ColumnTrait, EntityTrait, JoinType, QueryFilter, QuerySelect, RelationTrait};
+
+    let aliases = CommonTagAlias::find()
+        .select_only()
+        .column_as(common_tag_alias::Column::Name, "alias")
+        .column_as(common_tag::Column::Name, "name")
+        .join(
+            JoinType::InnerJoin,
+            common_tag_alias::Relation::CommonTag.def(),
+        )
+        .filter(common_tag_alias::Column::Name.is_in(tags.iter().cloned()))
+        .into_tuple::<(String, String)>()
+        .all(db)
+        .await?
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+    let mut resolved = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = aliases.get(tag).unwrap_or(tag);
+        if !resolved.contains(tag) {
+            resolved.push(tag.clone());
+        }
+    }
+    Ok(resolved)
+}
+
+/// Tags of post `post_id`, in the order they were added.
+#[cfg(feature = "ssr")]
+pub(crate) async fn post_tags<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    post_id: i32,
+) -> Result<Vec<String>, sea_orm::DbErr> {
+    use entity::{blog_post_tag, prelude::BlogPostTag};
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+
+    BlogPostTag::find()
+        .select_only()
+        .column(blog_post_tag::Column::Tag)
+        .filter(blog_post_tag::Column::BlogPostId.eq(post_id))
+        .order_by_asc(blog_post_tag::Column::Id)
+        .into_tuple::<String>()
+        .all(db)
+        .await
+}
+
+/// Replaces the tags of post `post_id` with the normalized `tags`, linking
+/// each to its `common_tag`, created when missing, or to the tag it was merged
+/// into. Kept tags keep their row, and who added them.
+#[cfg(feature = "ssr")]
+pub(crate) async fn set_post_tags<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    post_id: i32,
+    user_id: i32,
+    tags: &[String],
+) -> Result<(), sea_orm::DbErr> {
+    use std::collections::HashMap;
+
+    use entity::{
+        blog_post_tag, common_tag,
+        prelude::{BlogPostTag, CommonTag},
+    };
+    use sea_orm::{ActiveModelTrait, ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter};
+
+    let tags = &resolve_aliases(db, tags).await?;
+    let mut common_tags = CommonTag::find()
+        .filter(common_tag::Column::Name.is_in(tags.iter().cloned()))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|common_tag| (common_tag.name, common_tag.id))
+        .collect::<HashMap<_, _>>();
+    for tag in tags {
+        if !common_tags.contains_key(tag) {
+            let common_tag = common_tag::ActiveModel {
+                name: Set(tag.clone()),
+                ..Default::default()
+            }
+            .insert(db)
+            .await?;
+            common_tags.insert(common_tag.name, common_tag.id);
+        }
+    }
+
+    // rows saved before tags were normalized are normalized in place
+    let mut missing = tags.to_vec();
+    let rows = BlogPostTag::find()
+        .filter(blog_post_tag::Column::BlogPostId.eq(post_id))
+        .all(db)
+        .await?;
+    for row in rows {
+        let tag = normalize_tag(&row.tag);
+        let Some(index) = missing.iter().position(|missing| *missing == tag) else {
+            BlogPostTag::delete_by_id(row.id).exec(db).await?;
+            continue;
+        };
+        missing.remove(index);
+        let common_tag_id = common_tags.get(&tag).copied();
+        if row.tag != tag || row.common_tag_id != common_tag_id {
+            let mut model: blog_post_tag::ActiveModel = row.into();
+            model.tag = Set(tag);
+            model.common_tag_id = Set(common_tag_id);
+            model.update(db).await?;
+        }
+    }
+    for tag in missing {
+        blog_post_tag::ActiveModel {
+            blog_post_id: Set(post_id),
+            common_tag_id: Set(common_tags.get(&tag).copied()),
+            tag: Set(tag),
+            created_by_id: Set(user_id),
+            ..Default::default()
+        }
+        .insert(db)
+        .await?;
+    }
+    Ok(())
+}
+
+/// `$1` is the current time.
+#[cfg(feature = "ssr")]
+const TAG_COUNTS_QUERY: &str = r#"
+SELECT common_tag.name, count(DISTINCT blog_post.id) AS post_count
+FROM common_tag
+JOIN blog_post_tag ON blog_post_tag.common_tag_id = common_tag.id
+JOIN blog_post ON blog_post.id = blog_post_tag.blog_post_id
+WHERE blog_post.is_published
+  AND blog_post.published_at <= $1
+GROUP BY common_tag.id
+ORDER BY common_tag.name
+"#;
+
+/// Lists the tags of published posts with how many use them, by name.
+#[server]
+pub async fn list_tags() -> Result<Vec<TagCount>, ApiError> {
+    use sea_orm::{DbBackend, FromQueryResult, Statement};
+
+    use crate::server::database::use_database;
+
+    #[derive(FromQueryResult)]
+    struct TagCountRow {
+        name: String,
+        post_count: i64,
+    }
+
+    let db = use_database()?;
+    let rows = TagCountRow::find_by_statement(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        TAG_COUNTS_QUERY,
+        [chrono::Utc::now().naive_utc().into()],
+    ))
+    .all(&db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| TagCount {
+            name: row.name,
+            post_count: row.post_count,
+        })
+        .collect())
+}
+
+/// Lists the published posts tagged `name`, or the tag it was merged into,
+/// newest first.
+#[server]
+pub async fn list_tag_posts(name: String) -> Result<TagPosts, ApiError> {
+    use entity::{
+        blog_post, blog_post_tag, common_tag,
+        prelude::{BlogPost, BlogPostTag, CommonTag},
+    };
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+
+    use crate::{
+        api::blog::{listed_at, published},
+        server::database::use_database,
+    };
+
+    let db = use_database()?;
+    let name = resolve_aliases(&db, &[normalize_tag(&name)])
+        .await?
+        .pop()
+        .unwrap_or_default();
+    let tag = CommonTag::find()
+        .filter(common_tag::Column::Name.eq(name))
+        .one(&db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Tag not found."))?;
+    let post_ids = BlogPostTag::find()
+        .select_only()
+        .column(blog_post_tag::Column::BlogPostId)
+        .filter(blog_post_tag::Column::CommonTagId.eq(tag.id))
+        .into_tuple::<i32>()
+        .all(&db)
+        .await?;
+    let posts = BlogPost::find()
+        .filter(published())
+        .filter(blog_post::Column::Id.is_in(post_ids))
+        .order_by_desc(listed_at())
+        .order_by_desc(blog_post::Column::Id)
+        .all(&db)
+        .await?;
+
+    Ok(TagPosts {
+        name: tag.name,
+        posts: posts.into_iter().map(PostSummary::from).collect(),
+    })
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    fn tag_count(post_count: i64) -> TagCount {
+        TagCount {
+            name: "rust".to_string(),
+            post_count,
+        }
+    }
+
+    #[test]
+    fn normalize_tag_ignores_case_whitespace_and_diacritics() {
+        assert_eq!(normalize_tag(" Café  Racer"), "cafe-racer");
+        assert_eq!(normalize_tag("cafe-racer"), "cafe-racer");
+        assert_eq!(normalize_tag("CAFÉ\tRACER"), "cafe-racer");
+        assert_eq!(normalize_tag("-web -- dev-"), "web-dev");
+        assert_eq!(normalize_tag("   "), "");
+    }
+
+    #[test]
+    fn parse_tags_drops_empty_and_repeated_tags() {
+        assert_eq!(
+            parse_tags("Rust, rust ,, Café Racer, cafe-racer, ").unwrap(),
+            ["rust", "cafe-racer"]
+        );
+        assert!(parse_tags(" , ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_tags_limits_tags() {
+        let longest = "a".repeat(TAG_LENGTH);
+        assert_eq!(parse_tags(&longest).unwrap(), [longest.clone()]);
+        assert!(matches!(
+            parse_tags(&format!("{longest}a")),
+            Err(ApiError::BadRequest(_))
+        ));
+
+        let tags = (0..MAX_POST_TAGS)
+            .map(|tag| format!("tag-{tag}"))
+            .collect::<Vec<_>>();
+        assert_eq!(parse_tags(&tags.join(",")).unwrap(), tags);
+        assert!(matches!(
+            parse_tags(&format!("{},one-more", tags.join(","))),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn weight_goes_from_one_to_the_max() {
+        assert_eq!(tag_count(1).weight(9), 1);
+        assert_eq!(tag_count(5).weight(9), 3);
+        assert_eq!(tag_count(9).weight(9), MAX_TAG_WEIGHT);
+        for post_count in 0..=20 {
+            assert!((1..=MAX_TAG_WEIGHT).contains(&tag_count(post_count).weight(20)));
+        }
+    }
+
+    #[test]
+    fn weight_is_one_without_a_more_used_tag() {
+        assert_eq!(tag_count(1).weight(1), 1);
+        assert_eq!(tag_count(0).weight(0), 1);
+    }
+}
@@ -0,0 +1,384 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::str::FromStr;
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ApiError;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TechnologyType {
+    Language,
+    Framework,
+    Database,
+    Platform,
+    Tool,
+    #[default]
+    Other,
+}
+
+impl TechnologyType {
+    pub const ALL: [Self; 6] = [
+        Self::Language,
+        Self::Framework,
+        Self::Database,
+        Self::Platform,
+        Self::Tool,
+        Self::Other,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Language => "language",
+            Self::Framework => "framework",
+            Self::Database => "database",
+            Self::Platform => "platform",
+            Self::Tool => "tool",
+            Self::Other => "other",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Language => "Language",
+            Self::Framework => "Framework",
+            Self::Database => "Database",
+            Self::Platform => "Platform",
+            Self::Tool => "Tool",
+            Self::Other => "Other",
+        }
+    }
+}
+
+impl FromStr for TechnologyType {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|tech_type| tech_type.as_str() == value)
+            .ok_or(())
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::sea_orm_active_enums::TechnologyTypeEnum> for TechnologyType {
+    fn from(value: entity::sea_orm_active_enums::TechnologyTypeEnum) -> Self {
+        use entity::sea_orm_active_enums::TechnologyTypeEnum;
+
+        match value {
+            TechnologyTypeEnum::Language => Self::Language,
+            TechnologyTypeEnum::Framework => Self::Framework,
+            TechnologyTypeEnum::Database => Self::Database,
+            TechnologyTypeEnum::Platform => Self::Platform,
+            TechnologyTypeEnum::Tool => Self::Tool,
+            TechnologyTypeEnum::Other => Self::Other,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<TechnologyType> for entity::sea_orm_active_enums::TechnologyTypeEnum {
+    fn from(value: TechnologyType) -> Self {
+        match value {
+            TechnologyType::Language => Self::Language,
+            TechnologyType::Framework => Self::Framework,
+            TechnologyType::Database => Self::Database,
+            TechnologyType::Platform => Self::Platform,
+            TechnologyType::Tool => Self::Tool,
+            TechnologyType::Other => Self::Other,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TechnologyBadge {
+    pub id: i32,
+    pub name: String,
+    pub icon: String,
+}
+
+#[cfg(feature = "ssr")]
+impl From<entity::technology::Model> for TechnologyBadge {
+    fn from(model: entity::technology::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            icon: model.icon,
+        }
+    }
+}
+
+/// Project, post, series or skill using a technology.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TechnologyUse {
+    pub title: String,
+    pub url: String,
+    /// e.g. the period of a skill.
+    pub details: Option<String>,
+    /// Descendant technology it is used through, `None` when it uses the
+    /// technology itself.
+    pub via: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TechnologyDetail {
+    pub id: i32,
+    pub name: String,
+    pub icon: String,
+    pub tech_type: TechnologyType,
+    pub description: Option<String>,
+    pub parents: Vec<TechnologyBadge>,
+    pub children: Vec<TechnologyBadge>,
+    pub projects: Vec<TechnologyUse>,
+    pub posts: Vec<TechnologyUse>,
+    pub series: Vec<TechnologyUse>,
+    pub skills: Vec<TechnologyUse>,
+}
+
+/// `$1` is the technology. `UNION` drops rows already found, so the
+/// recursion stops at technologies it has been through, even when the graph
+/// has cycles.
+#[cfg(feature = "ssr")]
+const DESCENDANTS_QUERY: &str = r#"
+WITH RECURSIVE descendant(id) AS (
+    SELECT $1::integer
+    UNION
+    SELECT related_technology.child_technology_id
+    FROM related_technology
+    JOIN descendant ON related_technology.parent_technology_id = descendant.id
+)
+SELECT id FROM descendant
+"#;
+
+/// Ids of technology `id` and of every technology below it.
+#[cfg(feature = "ssr")]
+async fn descendant_ids<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    id: i32,
+) -> Result<Vec<i32>, sea_orm::DbErr> {
+    use sea_orm::{DbBackend, FromQueryResult, Statement};
+
+    #[derive(FromQueryResult)]
+    struct Descendant {
+        id: i32,
+    }
+
+    Ok(
+        Descendant::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            DESCENDANTS_QUERY,
+            [id.into()],
+        ))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|descendant| descendant.id)
+        .collect(),
+    )
+}
+
+/// Loads a technology with its parents and children, and what uses it or any
+/// technology below it: the site owner's projects and skills, published
+/// posts and series.
+#[server]
+pub async fn get_technology(id: i32) -> Result<TechnologyDetail, ApiError> {
+    use std::collections::HashMap;
+
+    use entity::{
+        blog_post, blog_post_technology, blog_series, blog_series_technology,
+        prelude::{
+            BlogPost, BlogPostTechnology, BlogSeries, BlogSeriesTechnology, Project,
+            ProjectTechnology, RelatedTechnology, Skill, Technology,
+        },
+        project, project_technology, related_technology, skill, technology,
+    };
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+    use crate::{
+        api::{
+            blog::{listed_at, published},
+            cv::skill_last_usage,
+        },
+        server::{config::use_config, database::use_database, site::find_owner},
+        utils::{dates::date_range, slug::id_slug},
+    };
+
+    let db = use_database()?;
+    let config = use_config()?;
+    let technology = Technology::find_by_id(id)
+        .one(&db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Technology not found."))?;
+    let owner = find_owner(&db, &config.site).await?;
+
+    let edges = RelatedTechnology::find()
+        .filter(
+            related_technology::Column::ParentTechnologyId
+                .eq(id)
+                .or(related_technology::Column::ChildTechnologyId.eq(id)),
+        )
+        .all(&db)
+        .await?;
+    let (parent_edges, child_edges): (Vec<_>, Vec<_>) = edges
+        .into_iter()
+        .partition(|edge| edge.child_technology_id == id);
+    let parents = Technology::find()
+        .filter(
+            technology::Column::Id.is_in(
+                parent_edges
+                    .into_iter()
+                    .map(|edge| edge.parent_technology_id),
+            ),
+        )
+        .order_by_asc(technology::Column::Name)
+        .all(&db)
+        .await?;
+    let children = Technology::find()
+        .filter(
+            technology::Column::Id
+                .is_in(child_edges.into_iter().map(|edge| edge.child_technology_id)),
+        )
+        .order_by_asc(technology::Column::Name)
+        .all(&db)
+        .await?;
+
+    let ids = descendant_ids(&db, id).await?;
+    let names = Technology::find()
+        .filter(technology::Column::Id.is_in(ids.clone()))
+        .all(&db)
+        .await?
+        .into_iter()
+        .map(|technology| (technology.id, technology.name))
+        .collect::<HashMap<_, _>>();
+    // the technology itself first, then the closest match by name
+    let via = |technology_ids: &[i32]| {
+        if technology_ids.contains(&id) {
+            return None;
+        }
+        technology_ids
+            .iter()
+            .filter_map(|technology_id| names.get(technology_id))
+            .min()
+            .cloned()
+    };
+    let by_row = |pairs: Vec<(i32, i32)>| {
+        let mut technologies = HashMap::<i32, Vec<i32>>::new();
+        for (row_id, technology_id) in pairs {
+            technologies.entry(row_id).or_default().push(technology_id);
+        }
+        technologies
+    };
+
+    let project_technologies = by_row(
+        ProjectTechnology::find()
+            .filter(project_technology::Column::TechnologyId.is_in(ids.clone()))
+            .all(&db)
+            .await?
+            .into_iter()
+            .map(|row| (row.project_id, row.technology_id))
+            .collect(),
+    );
+    let projects = Project::find()
+        .filter(project::Column::Id.is_in(project_technologies.keys().copied()))
+        .filter(project::Column::CreatedById.eq(owner.id))
+        .order_by_desc(project::Column::StartDate)
+        .all(&db)
+        .await?
+        .into_iter()
+        .map(|project| TechnologyUse {
+            url: format!("/portfolio/{}", id_slug(project.id, &project.title)),
+            details: Some(date_range(
+                project.start_date,
+                project.end_date.or(project.drop_date),
+            )),
+            via: via(&project_technologies[&project.id]),
+            title: project.title,
+        })
+        .collect();
+
+    let post_technologies = by_row(
+        BlogPostTechnology::find()
+            .filter(blog_post_technology::Column::TechnologyId.is_in(ids.clone()))
+            .all(&db)
+            .await?
+            .into_iter()
+            .map(|row| (row.blog_post_id, row.technology_id))
+            .collect(),
+    );
+    let posts = BlogPost::find()
+        .filter(blog_post::Column::Id.is_in(post_technologies.keys().copied()))
+        .filter(published())
+        .order_by_desc(listed_at())
+        .all(&db)
+        .await?
+        .into_iter()
+        .map(|post| TechnologyUse {
+            url: format!("/blog/{}", id_slug(post.id, &post.title)),
+            details: None,
+            via: via(&post_technologies[&post.id]),
+            title: post.title,
+        })
+        .collect();
+
+    let series_technologies = by_row(
+        BlogSeriesTechnology::find()
+            .filter(blog_series_technology::Column::TechnologyId.is_in(ids.clone()))
+            .all(&db)
+            .await?
+            .into_iter()
+            .map(|row| (row.blog_series_id, row.technology_id))
+            .collect(),
+    );
+    let series = BlogSeries::find()
+        .filter(blog_series::Column::Id.is_in(series_technologies.keys().copied()))
+        .order_by_asc(blog_series::Column::Title)
+        .all(&db)
+        .await?
+        .into_iter()
+        .map(|series| TechnologyUse {
+            url: format!("/blog/series/{}", series.id),
+            details: series.subtitle,
+            via: via(&series_technologies[&series.id]),
+            title: series.title,
+        })
+        .collect();
+
+    let skills = Skill::find()
+        .filter(skill::Column::TechnologyId.is_in(ids))
+        .filter(skill::Column::CreatedById.eq(owner.id))
+        .order_by_desc(skill::Column::InitialUsageDate)
+        .all(&db)
+        .await?
+        .into_iter()
+        .map(|skill| TechnologyUse {
+            title: names.get(&skill.technology_id).cloned().unwrap_or_default(),
+            url: "/cv".to_string(),
+            details: Some(date_range(
+                skill.initial_usage_date,
+                skill_last_usage(&skill),
+            )),
+            via: via(&[skill.technology_id]),
+        })
+        .collect();
+
+    Ok(TechnologyDetail {
+        id: technology.id,
+        name: technology.name,
+        icon: technology.icon,
+        tech_type: technology.tech_type.into(),
+        description: technology.description,
+        parents: parents.into_iter().map(TechnologyBadge::from).collect(),
+        children: children.into_iter().map(TechnologyBadge::from).collect(),
+        projects,
+        posts,
+        series,
+        skills,
+    })
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::portfolio::COVER_IMAGE_TYPE;
+
+/// Image types offered when uploading to a project's gallery.
+pub const PROJECT_IMAGE_TYPES: [&str; 3] = [COVER_IMAGE_TYPE, "screenshot", "diagram"];
+
+/// MIME types accepted by the upload route, as a file input's `accept`.
+pub const ACCEPTED_TYPES: &str = "image/jpeg,image/png,image/webp,image/gif";
+
+/// Rows an uploaded image can be attached to, the upload route being
+/// `/uploads/{target}/{id}`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadTarget {
+    /// A new `project_image` of project `id`.
+    ProjectImage,
+    /// A new `blog_post_image` of post `id`, referenced from its markdown.
+    PostImage,
+    /// The `cover_image_url` of post `id`.
+    PostCover,
+    /// The `cover_image_url` of series `id`.
+    SeriesCover,
+    /// The `image_url` of comment `id`.
+    CommentImage,
+}
+
+impl UploadTarget {
+    pub const ALL: [Self; 5] = [
+        Self::ProjectImage,
+        Self::PostImage,
+        Self::PostCover,
+        Self::SeriesCover,
+        Self::CommentImage,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ProjectImage => "project-image",
+            Self::PostImage => "post-image",
+            Self::PostCover => "post-cover",
+            Self::SeriesCover => "series-cover",
+            Self::CommentImage => "comment-image",
+        }
+    }
+
+    /// Route uploading an image for row `id`.
+    pub fn upload_url(self, id: i32) -> String {
+        format!("/uploads/{}/{id}", self.as_str())
+    }
+}
+
+impl FromStr for UploadTarget {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|target| target.as_str() == value)
+            .ok_or(())
+    }
+}
+
+/// Response of the upload route.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadedImage {
+    /// Id of the created `project_image` or `blog_post_image` row.
+    pub id: Option<i32>,
+    /// URL of the widest fallback variant, as stored in the row.
+    pub url: String,
+}
@@ -1,9 +1,15 @@
-use crate::components::{footer::Footer, navbar::Navbar};
-use crate::pages::{CvPage, HomePage, PrivacyPolicy, TermsAndConditions};
+use crate::components::{footer::Footer, global::auth::provide_auth_state, navbar::Navbar};
+use crate::pages::{
+    AdminAuditPage, AdminContentEditPage, AdminContentPage, AdminImportPage, AdminPage,
+    AdminPostEditPage, AdminPostsPage, AdminSeriesPage, AdminTagsPage, AdminUserPage,
+    AdminUsersPage, BlogPage, CvPage, HomePage, PortfolioPage, PostPage, PrivacyPolicy,
+    ProfilePage, ProjectPage, SearchPage, SeriesPage, SignInPage, SuspensionPage, TagPage,
+    TechnologyPage, TermsAndConditions,
+};
 use leptos::prelude::*;
 use leptos_meta::{Link, Stylesheet, Title, provide_meta_context};
 use leptos_router::{
-    StaticSegment, WildcardSegment,
+    ParamSegment, SsrMode, StaticSegment, WildcardSegment,
     components::{Route, Router, Routes},
 };
 
@@ -11,6 +17,8 @@ use leptos_router::{
 pub fn App() -> impl IntoView {
     // Provides context that manages stylesheets, titles, meta tags, etc.
     provide_meta_context();
+    // auth state of the session, shared with the client on hydration
+    provide_auth_state();
 
     view! {
         // injects a stylesheet into the document <head>
@@ -31,8 +39,80 @@ pub fn App() -> impl IntoView {
                 <Routes fallback=move || "Not found.">
                     <Route path=StaticSegment("") view=HomePage/>
                     <Route path=StaticSegment("portfolio") view=PortfolioPage/>
+                    // rendered in full before responding so a missing project returns a 404
+                    <Route
+                        path=(StaticSegment("portfolio"), ParamSegment("slug"))
+                        view=ProjectPage
+                        ssr=SsrMode::Async
+                    />
+                    <Route
+                        path=(StaticSegment("technologies"), ParamSegment("id"))
+                        view=TechnologyPage
+                        ssr=SsrMode::Async
+                    />
                     <Route path=StaticSegment("cv") view=CvPage/>
-                    <Route path=StaticSegment("blog") view=BlogPage/>
+                    <Route path=StaticSegment("search") view=SearchPage/>
+                    // rendered in full before responding so crawlers get every post
+                    <Route path=StaticSegment("blog") view=BlogPage ssr=SsrMode::Async/>
+                    <Route
+                        path=(StaticSegment("blog"), ParamSegment("slug"))
+                        view=PostPage
+                        ssr=SsrMode::Async
+                    />
+                    <Route
+                        path=(StaticSegment("blog"), StaticSegment("series"), ParamSegment("id"))
+                        view=SeriesPage
+                        ssr=SsrMode::Async
+                    />
+                    <Route
+                        path=(StaticSegment("blog"), StaticSegment("tags"), ParamSegment("name"))
+                        view=TagPage
+                        ssr=SsrMode::Async
+                    />
+                    <Route path=StaticSegment("sign-in") view=SignInPage/>
+                    <Route path=StaticSegment("me") view=ProfilePage/>
+                    <Route path=StaticSegment("suspension") view=SuspensionPage/>
+                    <Route
+                        path=(StaticSegment("admin"), StaticSegment("users"))
+                        view=AdminUsersPage
+                    />
+                    <Route
+                        path=(StaticSegment("admin"), StaticSegment("users"), ParamSegment("id"))
+                        view=AdminUserPage
+                    />
+                    <Route path=StaticSegment("admin") view=AdminPage/>
+                    <Route
+                        path=(StaticSegment("admin"), StaticSegment("audit"))
+                        view=AdminAuditPage
+                    />
+                    <Route
+                        path=(StaticSegment("admin"), StaticSegment("import"))
+                        view=AdminImportPage
+                    />
+                    <Route
+                        path=(StaticSegment("admin"), StaticSegment("tags"))
+                        view=AdminTagsPage
+                    />
+                    <Route
+                        path=(StaticSegment("admin"), StaticSegment("series"))
+                        view=AdminSeriesPage
+                    />
+                    <Route
+                        path=(StaticSegment("admin"), StaticSegment("blog"))
+                        view=AdminPostsPage
+                    />
+                    <Route
+                        path=(StaticSegment("admin"), StaticSegment("blog"), ParamSegment("id"))
+                        view=AdminPostEditPage
+                    />
+                    <Route
+                        path=(StaticSegment("admin"), ParamSegment("kind"))
+                        view=AdminContentPage
+                    />
+                    <Route
+                        path=(StaticSegment("admin"), ParamSegment("kind"), ParamSegment("id"))
+                        view=AdminContentEditPage
+                    />
                     <Route path=StaticSegment("privacy-policy") view=PrivacyPolicy/>
                     <Route path=StaticSegment("terms-and-conditions") view=TermsAndConditions/>
                     <Route path=WildcardSegment("any") view=NotFound/>
@@ -64,19 +144,3 @@ fn NotFound() -> impl IntoView {
         <h1>"Not Found"</h1>
     }
 }
-
-#[component]
-fn PortfolioPage() -> impl IntoView {
-    view! {
-        <h1>"Portfolio"</h1>
-        <p>"Welcome to my portfolio page!"</p>
-    }
-}
-
-#[component]
-fn BlogPage() -> impl IntoView {
-    view! {
-        <h1>"Blog"</h1>
-        <p>"Welcome to my blog!"</p>
-    }
-}
@@ -0,0 +1,287 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_location, location::Url};
+
+use super::reactions::ReactionBar;
+use crate::api::{
+    comments::{
+        CommentItem, CommentThread, EditComment, PostComment, SetCommentHidden, list_comments,
+    },
+    reactions::ReactionTarget,
+    uploads::UploadTarget,
+};
+use crate::components::{
+    common::{FormError, ImageUpload, ResponsiveImage},
+    global::state::AuthenticatedState,
+};
+
+/// Shared by every comment of a thread.
+#[derive(Clone, Copy)]
+struct ThreadContext {
+    post_id: i32,
+    max_depth: usize,
+    max_length: usize,
+    can_moderate: bool,
+    is_authenticated: bool,
+    /// Bumped after every successful change to reload the thread.
+    revision: RwSignal<usize>,
+}
+
+/// Reloads the thread once `action` succeeds.
+fn reload_on_success<A>(action: ServerAction<A>, revision: RwSignal<usize>)
+where
+    A: leptos::server_fn::ServerFn + Send + Sync + Clone + 'static,
+    A::Output: Send + Sync + Clone + 'static,
+    A::Error: Send + Sync + Clone + 'static,
+{
+    Effect::new(move |_| {
+        if matches!(action.value().get(), Some(Ok(_))) {
+            revision.update(|revision| *revision += 1);
+        }
+    });
+}
+
+#[component]
+fn CommentForm(
+    #[prop(optional)] parent_id: Option<i32>,
+    #[prop(optional)] on_cancel: Option<Callback<()>>,
+) -> impl IntoView {
+    let thread = expect_context::<ThreadContext>();
+    let action = ServerAction::<PostComment>::new();
+    let error = Signal::derive(move || action.value().get().and_then(Result::err));
+    reload_on_success(action, thread.revision);
+
+    view! {
+        <FormError error />
+        <ActionForm action attr:class="comment-form">
+            <input type="hidden" name="post_id" value=thread.post_id />
+            {parent_id.map(|id| view! { <input type="hidden" name="parent_id" value=id /> })}
+            <textarea
+                name="comment"
+                rows="3"
+                maxlength=thread.max_length
+                placeholder=if parent_id.is_some() { "Write a reply" } else { "Write a comment" }
+                required
+            ></textarea>
+            <div class="comment-buttons">
+                <button type="submit" disabled=move || action.pending().get()>
+                    {if parent_id.is_some() { "Reply" } else { "Comment" }}
+                </button>
+                {on_cancel.map(|on_cancel| view! {
+                    <button type="button" class="secondary" on:click=move |_| on_cancel.run(())>
+                        "Cancel"
+                    </button>
+                })}
+            </div>
+        </ActionForm>
+    }
+}
+
+#[component]
+fn EditForm(id: i32, comment: String, on_cancel: Callback<()>) -> impl IntoView {
+    let thread = expect_context::<ThreadContext>();
+    let action = ServerAction::<EditComment>::new();
+    let error = Signal::derive(move || action.value().get().and_then(Result::err));
+    reload_on_success(action, thread.revision);
+
+    view! {
+        <FormError error />
+        <ActionForm action attr:class="comment-form">
+            <input type="hidden" name="id" value=id />
+            <textarea name="comment" rows="3" maxlength=thread.max_length required>
+                {comment}
+            </textarea>
+            <div class="comment-buttons">
+                <button type="submit" disabled=move || action.pending().get()>"Save"</button>
+                <button type="button" class="secondary" on:click=move |_| on_cancel.run(())>
+                    "Cancel"
+                </button>
+            </div>
+        </ActionForm>
+    }
+}
+
+#[component]
+fn HideButton(id: i32, is_hidden: bool) -> impl IntoView {
+    let thread = expect_context::<ThreadContext>();
+    let action = ServerAction::<SetCommentHidden>::new();
+    let error = Signal::derive(move || action.value().get().and_then(Result::err));
+    reload_on_success(action, thread.revision);
+
+    view! {
+        <ActionForm action attr:class="comment-moderation">
+            <input type="hidden" name="id" value=id />
+            {(!is_hidden).then(|| view! { <input type="hidden" name="hidden" value="true" /> })}
+            <button type="submit" class="link" disabled=move || action.pending().get()>
+                {if is_hidden { "Unhide" } else { "Hide" }}
+            </button>
+        </ActionForm>
+        <FormError error />
+    }
+}
+
+#[component]
+fn Comment(comment: CommentItem, depth: usize) -> impl IntoView {
+    let thread = expect_context::<ThreadContext>();
+    let (is_replying, set_is_replying) = signal(false);
+    let (is_editing, set_is_editing) = signal(false);
+
+    let CommentItem {
+        id,
+        author,
+        comment: text,
+        image_url,
+        created_at,
+        is_hidden,
+        can_edit,
+        reactions,
+        replies,
+    } = comment;
+    let can_reply = thread.is_authenticated && depth < thread.max_depth;
+    // hidden comments keep their place so replies still make sense
+    let body = if is_hidden && !thread.can_moderate {
+        view! { <p class="comment-hidden">"This comment was hidden by a moderator."</p> }.into_any()
+    } else {
+        let edit_text = text.clone();
+        view! {
+            <Show
+                when=move || is_editing.get()
+                fallback=move || {
+                    let text = text.clone();
+                    let image_url = image_url.clone();
+                    view! {
+                        <p class="comment-text">{text}</p>
+                        {image_url.map(|url| {
+                            view! { <ResponsiveImage class="comment-image" src=url alt="" sizes="(min-width: 768px) 480px, 100vw" lazy=true /> }
+                        })}
+                    }
+                }
+            >
+                <EditForm
+                    id
+                    comment=edit_text.clone()
+                    on_cancel=Callback::new(move |_| set_is_editing.set(false))
+                />
+                <ImageUpload target=UploadTarget::CommentImage id label="Attach an image" />
+            </Show>
+        }
+        .into_any()
+    };
+
+    view! {
+        <li class=if is_hidden { "comment hidden" } else { "comment" }>
+            <p class="comment-meta">
+                <span class="comment-author">{author}</span>
+                " · "
+                <span>{created_at}</span>
+                {(is_hidden && thread.can_moderate).then_some(" · hidden")}
+            </p>
+            {body}
+            // hidden comments cannot be reacted to
+            {(!is_hidden).then(|| view! {
+                <ReactionBar target=ReactionTarget::Comment id summary=reactions />
+            })}
+            <div class="comment-actions">
+                {can_reply.then(|| view! {
+                    <button type="button" class="link" on:click=move |_| set_is_replying.update(|open| *open = !*open)>
+                        "Reply"
+                    </button>
+                })}
+                {can_edit.then(|| view! {
+                    <button type="button" class="link" on:click=move |_| set_is_editing.set(true)>
+                        "Edit"
+                    </button>
+                })}
+                {thread.can_moderate.then(|| view! { <HideButton id is_hidden /> })}
+            </div>
+            <Show when=move || is_replying.get()>
+                <CommentForm
+                    parent_id=id
+                    on_cancel=Callback::new(move |_| set_is_replying.set(false))
+                />
+            </Show>
+            {(!replies.is_empty()).then(|| view! { <CommentList comments=replies depth=depth + 1 /> })}
+        </li>
+    }
+}
+
+#[component]
+fn CommentList(comments: Vec<CommentItem>, depth: usize) -> AnyView {
+    view! {
+        <ul class="comment-list">
+            {comments
+                .into_iter()
+                .map(|comment| view! { <Comment comment depth /> })
+                .collect::<Vec<_>>()}
+        </ul>
+    }
+    .into_any()
+}
+
+#[component]
+fn Thread(thread: CommentThread, post_id: i32, revision: RwSignal<usize>) -> impl IntoView {
+    let auth_state = use_context::<ReadSignal<AuthenticatedState>>();
+    let location = use_location();
+    let is_authenticated = auth_state.is_some_and(|state| state.get_untracked().is_authenticated);
+    provide_context(ThreadContext {
+        post_id,
+        max_depth: thread.max_depth,
+        max_length: thread.max_length,
+        can_moderate: thread.can_moderate,
+        is_authenticated,
+        revision,
+    });
+    let sign_in_href = format!(
+        "/sign-in?return_to={}",
+        Url::escape(&location.pathname.get_untracked())
+    );
+
+    view! {
+        <h3>{format!("Comments ({})", thread.count)}</h3>
+        {if is_authenticated {
+            view! { <CommentForm /> }.into_any()
+        } else {
+            view! {
+                <p class="blog-message">
+                    <A href=sign_in_href>"Sign in"</A>
+                    " to join the conversation."
+                </p>
+            }
+            .into_any()
+        }}
+        {if thread.comments.is_empty() {
+            view! { <p class="blog-message">"No comments yet."</p> }.into_any()
+        } else {
+            view! { <CommentList comments=thread.comments depth=0 /> }.into_any()
+        }}
+    }
+}
+
+/// Comment thread of a post, signed in users can comment and reply.
+#[component]
+pub fn Comments(post_id: i32) -> impl IntoView {
+    let auth_state = use_context::<ReadSignal<AuthenticatedState>>();
+    let revision = RwSignal::new(0);
+    let thread = Resource::new(
+        move || {
+            (
+                revision.get(),
+                // signing in or out changes what the reader can do
+                auth_state.map(|state| state.get().is_authenticated),
+            )
+        },
+        move |_| list_comments(post_id),
+    );
+
+    view! {
+        <section class="comments">
+            <Transition fallback=|| view! { <p class="blog-message">"Loading comments..."</p> }>
+                {move || Suspend::new(async move {
+                    match thread.await {
+                        Ok(thread) => view! { <Thread thread post_id revision /> }.into_any(),
+                        Err(err) => view! { <p class="blog-message">{err.to_string()}</p> }.into_any(),
+                    }
+                })}
+            </Transition>
+        </section>
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod comments;
+mod post_card;
+mod reactions;
+mod series_sidebar;
+mod tag_cloud;
+
+pub use comments::Comments;
+pub use post_card::PostCard;
+pub use reactions::PostReactions;
+pub use series_sidebar::SeriesSidebar;
+pub use tag_cloud::TagCloud;
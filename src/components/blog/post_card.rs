@@ -0,0 +1,25 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::api::blog::PostSummary;
+use crate::components::common::ResponsiveImage;
+
+#[component]
+pub fn PostCard(post: PostSummary) -> impl IntoView {
+    let href = format!("/blog/{}", post.slug);
+
+    view! {
+        <article class=if post.is_featured { "post-card featured" } else { "post-card" }>
+            {post.cover_image_url.map(|url| {
+                view! {
+                    <ResponsiveImage class="post-cover" src=url alt=post.title.clone() sizes="(min-width: 768px) 50vw, 100vw" lazy=true />
+                }
+            })}
+            <div class="post-card-content">
+                <h3><A href=href>{post.title}</A></h3>
+                <p class="date">{post.published_at}</p>
+                <p>{post.excerpt}</p>
+            </div>
+        </article>
+    }
+}
@@ -0,0 +1,98 @@
+use leptos::prelude::*;
+
+use crate::api::reactions::{
+    Reaction, ReactionSummary, ReactionTarget, SetReaction, get_reactions,
+};
+use crate::components::{common::FormError, global::state::AuthenticatedState};
+
+/// Reaction counts of a post or comment, signed in users can pick one.
+///
+/// Clicks apply immediately and are then reconciled with the server's
+/// answer, or rolled back if the server refuses them.
+#[component]
+pub fn ReactionBar(target: ReactionTarget, id: i32, summary: ReactionSummary) -> impl IntoView {
+    let auth_state = use_context::<ReadSignal<AuthenticatedState>>();
+    let is_authenticated = move || auth_state.is_some_and(|state| state.get().is_authenticated);
+
+    let summary = RwSignal::new(summary);
+    // what the server last confirmed, restored when it refuses a change
+    let confirmed = StoredValue::new(summary.get_untracked());
+    let action = ServerAction::<SetReaction>::new();
+    let error = Signal::derive(move || action.value().get().and_then(Result::err));
+
+    // only answers to the latest click arrive here, earlier ones are dropped
+    Effect::new(move |_| match action.value().get() {
+        Some(Ok(latest)) => {
+            confirmed.set_value(latest.clone());
+            summary.set(latest);
+        }
+        Some(Err(_)) => summary.set(confirmed.get_value()),
+        None => {}
+    });
+
+    let react = move |reaction: Reaction| {
+        let reaction =
+            (summary.with_untracked(|summary| summary.mine) != Some(reaction)).then_some(reaction);
+        summary.update(|summary| summary.react(reaction));
+        action.dispatch(SetReaction {
+            target,
+            id,
+            reaction,
+        });
+    };
+
+    view! {
+        <div class="reaction-bar">
+            {Reaction::ALL
+                .into_iter()
+                .map(|reaction| {
+                    let is_mine = move || summary.with(|summary| summary.mine == Some(reaction));
+                    view! {
+                        <button
+                            type="button"
+                            class=move || if is_mine() { "reaction mine" } else { "reaction" }
+                            title=move || {
+                                if is_authenticated() {
+                                    reaction.label().to_string()
+                                } else {
+                                    format!("{} · sign in to react", reaction.label())
+                                }
+                            }
+                            aria-label=reaction.label()
+                            aria-pressed=move || is_mine().to_string()
+                            disabled=move || !is_authenticated()
+                            on:click=move |_| react(reaction)
+                        >
+                            <span>{reaction.emoji()}</span>
+                            <span class="reaction-count">
+                                {move || summary.with(|summary| summary.count(reaction))}
+                            </span>
+                        </button>
+                    }
+                })
+                .collect::<Vec<_>>()}
+        </div>
+        <FormError error />
+    }
+}
+
+/// Reaction bar under a post.
+#[component]
+pub fn PostReactions(post_id: i32) -> impl IntoView {
+    let auth_state = use_context::<ReadSignal<AuthenticatedState>>();
+    let reactions = Resource::new(
+        // the reader's own reaction depends on who is signed in
+        move || auth_state.map(|state| state.get().is_authenticated),
+        move |_| get_reactions(ReactionTarget::Post, post_id),
+    );
+
+    view! {
+        <Transition fallback=|| ()>
+            {move || Suspend::new(async move {
+                reactions.await.ok().map(|summary| {
+                    view! { <ReactionBar target=ReactionTarget::Post id=post_id summary /> }
+                })
+            })}
+        </Transition>
+    }
+}
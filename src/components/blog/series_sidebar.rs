@@ -0,0 +1,35 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::api::blog::list_series;
+
+#[component]
+pub fn SeriesSidebar() -> impl IntoView {
+    let series = Resource::new(|| (), |_| list_series());
+
+    view! {
+        <aside class="series-sidebar">
+            <h3>"Series"</h3>
+            <Suspense fallback=|| view! { <p class="blog-message">"Loading..."</p> }>
+                {move || Suspend::new(async move {
+                    let series = series.await.unwrap_or_default();
+                    if series.is_empty() {
+                        return view! { <p class="blog-message">"No series yet."</p> }.into_any();
+                    }
+                    view! {
+                        <ul>
+                            {series.into_iter().map(|series| {
+                                view! {
+                                    <li>
+                                        <A href=format!("/blog/series/{}", series.id)>{series.title}</A>
+                                        <span class="post-count">{series.post_count}</span>
+                                    </li>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </ul>
+                    }.into_any()
+                })}
+            </Suspense>
+        </aside>
+    }
+}
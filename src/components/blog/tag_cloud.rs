@@ -0,0 +1,45 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, location::Url};
+
+use crate::api::tags::list_tags;
+
+/// Tags of published posts, the more used the larger.
+#[component]
+pub fn TagCloud() -> impl IntoView {
+    let tags = Resource::new(|| (), |_| list_tags());
+
+    view! {
+        <aside class="tag-cloud">
+            <h3>"Tags"</h3>
+            <Suspense fallback=|| view! { <p class="blog-message">"Loading..."</p> }>
+                {move || Suspend::new(async move {
+                    let tags = tags.await.unwrap_or_default();
+                    if tags.is_empty() {
+                        return view! { <p class="blog-message">"No tags yet."</p> }.into_any();
+                    }
+                    let max_count = tags.iter().map(|tag| tag.post_count).max().unwrap_or(1);
+                    view! {
+                        <ul>
+                            {tags.into_iter().map(|tag| {
+                                let title = match tag.post_count {
+                                    1 => "1 post".to_string(),
+                                    count => format!("{count} posts"),
+                                };
+                                view! {
+                                    <li class=format!("tag-weight-{}", tag.weight(max_count))>
+                                        <A
+                                            href=format!("/blog/tags/{}", Url::escape(&tag.name))
+                                            attr:title=title
+                                        >
+                                            {tag.name}
+                                        </A>
+                                    </li>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </ul>
+                    }.into_any()
+                })}
+            </Suspense>
+        </aside>
+    }
+}
@@ -0,0 +1,18 @@
+use leptos::prelude::*;
+
+use crate::api::ApiError;
+
+/// Error of a form's last submission; on a version conflict the page has
+/// already reloaded the latest values, so the user only needs to resubmit.
+#[component]
+pub fn FormError(#[prop(into)] error: Signal<Option<ApiError>>) -> impl IntoView {
+    move || {
+        error.get().map(|err| {
+            let class = match err {
+                ApiError::Conflict(_) => "form-error conflict",
+                _ => "form-error",
+            };
+            view! { <p class=class>{err.to_string()}</p> }
+        })
+    }
+}
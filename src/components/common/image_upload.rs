@@ -0,0 +1,29 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_location;
+
+use crate::api::uploads::{ACCEPTED_TYPES, UploadTarget};
+
+/// Multipart form uploading an image for row `id` of `target`, which brings
+/// the user back to the current page once done. `children` can add fields,
+/// e.g. the type of a project image.
+#[component]
+pub fn ImageUpload(
+    target: UploadTarget,
+    id: i32,
+    label: &'static str,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let return_to = use_location().pathname.get_untracked();
+
+    view! {
+        <form class="image-upload" method="post" action=target.upload_url(id) enctype="multipart/form-data">
+            <input type="hidden" name="return_to" value=return_to />
+            {children.map(|children| children())}
+            <label>
+                {label}
+                <input type="file" name="image" accept=ACCEPTED_TYPES required />
+            </label>
+            <button type="submit">"Upload"</button>
+        </form>
+    }
+}
@@ -4,7 +4,13 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+mod form_error;
+mod image_upload;
+mod responsive_image;
 mod socials;
 mod title;
 
+pub use form_error::FormError;
+pub use image_upload::ImageUpload;
+pub use responsive_image::ResponsiveImage;
 pub use title::Title;
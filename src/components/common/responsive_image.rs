@@ -0,0 +1,29 @@
+use leptos::prelude::*;
+
+use crate::utils::images::srcsets;
+
+/// Image offering the WebP and fallback variants of an upload to the browser,
+/// external images being shown as they are.
+#[component]
+pub fn ResponsiveImage(
+    #[prop(into)] src: String,
+    #[prop(into)] alt: String,
+    #[prop(optional)] class: Option<&'static str>,
+    /// Width the image is displayed at, for the browser to pick a variant.
+    #[prop(default = "100vw")]
+    sizes: &'static str,
+    #[prop(optional)] lazy: bool,
+) -> impl IntoView {
+    let loading = lazy.then_some("lazy");
+
+    match srcsets(&src) {
+        Some(srcsets) => view! {
+            <picture>
+                <source type="image/webp" srcset=srcsets.webp sizes=sizes />
+                <img class=class src=src srcset=srcsets.fallback sizes=sizes alt=alt loading=loading />
+            </picture>
+        }
+        .into_any(),
+        None => view! { <img class=class src=src alt=alt loading=loading /> }.into_any(),
+    }
+}
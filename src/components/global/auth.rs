@@ -0,0 +1,59 @@
+use leptos::{ev, prelude::*, task::spawn_local};
+
+use crate::api::auth::{get_session_user, sign_out};
+
+use super::state::AuthenticatedState;
+
+/// Resolves the auth state from the session during server-side rendering, the
+/// client then reads the serialized value while hydrating.
+fn initial_state() -> AuthenticatedState {
+    #[cfg(feature = "ssr")]
+    {
+        use crate::api::auth::SessionUser;
+        use crate::server::session::use_current_user;
+
+        use_current_user().map(SessionUser::from).into()
+    }
+    #[cfg(not(feature = "ssr"))]
+    {
+        AuthenticatedState::new()
+    }
+}
+
+/// Provides `ReadSignal<AuthenticatedState>` and its `WriteSignal` to the app.
+pub fn provide_auth_state() {
+    let (state, set_state) = signal(SharedValue::new(initial_state).into_inner());
+    provide_context(state);
+    provide_context(set_state);
+
+    // picks up a sign-in or sign-out made in another tab
+    let handle = window_event_listener(ev::focus, move |_| load_state(set_state));
+    on_cleanup(move || handle.remove());
+}
+
+fn load_state(set_state: WriteSignal<AuthenticatedState>) {
+    spawn_local(async move {
+        if let Ok(user) = get_session_user().await {
+            set_state.set(user.into());
+        }
+    });
+}
+
+/// Reloads the auth state from the server session.
+pub fn refresh_auth_state() {
+    if let Some(set_state) = use_context::<WriteSignal<AuthenticatedState>>() {
+        load_state(set_state);
+    }
+}
+
+/// Ends the session and clears the auth state once the server confirms it.
+pub fn sign_out_user() {
+    let Some(set_state) = use_context::<WriteSignal<AuthenticatedState>>() else {
+        return;
+    };
+    spawn_local(async move {
+        if sign_out().await.is_ok() {
+            set_state.set(AuthenticatedState::new());
+        }
+    });
+}
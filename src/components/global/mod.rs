@@ -1 +1,3 @@
+pub mod auth;
+pub mod protected;
 pub mod state;
@@ -0,0 +1,60 @@
+use leptos::prelude::*;
+use leptos_router::{components::Redirect, hooks::use_location, location::Url};
+
+use super::state::{AuthenticatedState, UserState};
+
+/// Role a visitor needs to see a [`Protected`] page.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RequiredRole {
+    #[default]
+    User,
+    Staff,
+    Admin,
+}
+
+impl RequiredRole {
+    fn allows(self, user: &UserState) -> bool {
+        match self {
+            Self::User => true,
+            Self::Staff => user.is_staff || user.is_admin,
+            Self::Admin => user.is_admin,
+        }
+    }
+}
+
+/// Renders `children` only for signed in users with `role`, sending anonymous
+/// visitors to `/sign-in` and back here afterwards.
+///
+/// This only hides the page, the server functions it calls must still check
+/// the user with the guards in `server::guards`.
+#[component]
+pub fn Protected(#[prop(optional)] role: RequiredRole, children: ChildrenFn) -> impl IntoView {
+    let auth_state = use_context::<ReadSignal<AuthenticatedState>>();
+    let location = use_location();
+    let return_to = move || {
+        let search = location.search.get();
+        if search.is_empty() {
+            location.pathname.get()
+        } else {
+            format!("{}?{search}", location.pathname.get())
+        }
+    };
+
+    move || {
+        let user = auth_state.and_then(|state| state.get().user);
+        match user {
+            None => view! {
+                <Redirect path=format!("/sign-in?return_to={}", Url::escape(&return_to())) />
+            }
+            .into_any(),
+            Some(user) if !role.allows(&user) => view! {
+                <section class="forbidden">
+                    <h2>"FORBIDDEN"</h2>
+                    <p>"You don't have permission to see this page."</p>
+                </section>
+            }
+            .into_any(),
+            Some(_) => children().into_any(),
+        }
+    }
+}
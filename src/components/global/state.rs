@@ -1,16 +1,38 @@
-#[derive(Clone, Copy, Default)]
-pub struct UserState<'a> {
-    pub first_name: &'a str,
-    pub picture: &'a str,
+use serde::{Deserialize, Serialize};
+
+use crate::api::auth::SessionUser;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserState {
+    pub id: i32,
+    pub first_name: String,
+    pub is_staff: bool,
+    pub is_admin: bool,
 }
 
-#[derive(Clone, Copy, Default)]
-pub struct AuthenticatedState<'a> {
+impl From<SessionUser> for UserState {
+    fn from(user: SessionUser) -> Self {
+        Self {
+            id: user.id,
+            first_name: user
+                .name
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            is_staff: user.is_staff,
+            is_admin: user.is_admin,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthenticatedState {
     pub is_authenticated: bool,
-    pub user: Option<UserState<'a>>,
+    pub user: Option<UserState>,
 }
 
-impl<'a> AuthenticatedState<'a> {
+impl AuthenticatedState {
     pub fn new() -> Self {
         Self {
             is_authenticated: false,
@@ -18,8 +40,18 @@ impl<'a> AuthenticatedState<'a> {
         }
     }
 
-    pub fn set_user(&mut self, user: UserState<'a>) {
+    pub fn set_user(&mut self, user: UserState) {
         self.is_authenticated = true;
         self.user = Some(user);
     }
 }
+
+impl From<Option<SessionUser>> for AuthenticatedState {
+    fn from(user: Option<SessionUser>) -> Self {
+        let mut state = Self::new();
+        if let Some(user) = user {
+            state.set_user(user.into());
+        }
+        state
+    }
+}
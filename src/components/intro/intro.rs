@@ -1,5 +1,6 @@
 use super::description::Description;
 use super::experience::Experience;
+use super::skills::Skills;
 use crate::components::common::Title;
 
 use leptos::prelude::*;
@@ -9,6 +10,11 @@ pub fn Intro() -> impl IntoView {
     view! {
         <Title title="Senior Software Engineer" image_asset="me" />
         <Description />
+        <div class="cv-downloads">
+            <a href="/cv.pdf" rel="external" download="cv.pdf">"Download PDF"</a>
+            <a href="/cv.json" rel="external" download="cv.json">"JSON Resume"</a>
+        </div>
+        <Skills />
         <Experience />
     }
 }
@@ -1,6 +1,7 @@
 mod description;
 mod experience;
 mod intro;
+mod skills;
 mod timeline;
 
 pub use intro::Intro;
@@ -0,0 +1,77 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::api::cv::{SkillGroup, SkillItem, SkillSort, get_skills};
+
+#[component]
+fn SkillRow(skill: SkillItem) -> impl IntoView {
+    let (status, class) = if skill.is_current {
+        ("Current", "skill-status current")
+    } else {
+        ("Past", "skill-status past")
+    };
+    let experience = skill.experience();
+
+    view! {
+        <li class="skill">
+            <img src=skill.icon alt="" />
+            <A href=format!("/technologies/{}", skill.technology_id)>{skill.name}</A>
+            <span class="skill-experience" title=skill.date>{experience}</span>
+            <span class=class>{status}</span>
+        </li>
+    }
+}
+
+/// Skills grouped by the type of their technology, with the experience in
+/// each and whether they are still used.
+#[component]
+pub fn Skills() -> impl IntoView {
+    let groups = Resource::new(|| (), |_| get_skills());
+    let sort = RwSignal::new(SkillSort::default());
+
+    view! {
+        <section class="skills">
+            <h2>"Skills"</h2>
+            <div class="skills-sort" role="group" aria-label="Sort skills">
+                {SkillSort::ALL.into_iter().map(|option| view! {
+                    <button
+                        class:active=move || sort.get() == option
+                        aria-pressed=move || (sort.get() == option).to_string()
+                        on:click=move |_| sort.set(option)
+                    >
+                        {option.label()}
+                    </button>
+                }).collect::<Vec<_>>()}
+            </div>
+            <Suspense fallback=|| view! { <p class="skills-message">"Loading..."</p> }>
+                {move || Suspend::new(async move {
+                    match groups.await {
+                        Ok(groups) if groups.is_empty() => ().into_any(),
+                        Ok(groups) => {
+                            let sorted = move || {
+                                let sort = sort.get();
+                                groups.clone().into_iter().map(|mut group: SkillGroup| {
+                                    group.sort(sort);
+                                    view! {
+                                        <div class="skill-group">
+                                            <h3>{group.tech_type.label()}</h3>
+                                            <ul>
+                                                {group.skills.into_iter().map(|skill| view! {
+                                                    <SkillRow skill />
+                                                }).collect::<Vec<_>>()}
+                                            </ul>
+                                        </div>
+                                    }
+                                }).collect::<Vec<_>>()
+                            };
+                            view! { <div class="skill-groups">{sorted}</div> }.into_any()
+                        }
+                        Err(_) => view! {
+                            <p class="skills-message">"Unable to load skills right now."</p>
+                        }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </section>
+    }
+}
@@ -1,5 +1,7 @@
+pub mod blog;
 pub mod common;
 pub mod footer;
 pub mod global;
 pub mod intro;
 pub mod navbar;
+pub mod portfolio;
@@ -5,9 +5,9 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use leptos::prelude::*;
-use leptos_router::{components::A, hooks::use_location};
+use leptos_router::{components::A, hooks::use_location, location::Url};
 
-use crate::components::global::state::AuthenticatedState;
+use crate::components::global::{auth::sign_out_user, state::AuthenticatedState};
 
 #[component]
 pub fn NavbarButton(
@@ -48,30 +48,60 @@ fn CommonButtons(#[prop(into)] set_is_menu_open: WriteSignal<bool>) -> impl Into
 #[component]
 pub fn AuthButton(#[prop(into)] set_is_menu_open: WriteSignal<bool>) -> impl IntoView {
     let auth_state = use_context::<ReadSignal<AuthenticatedState>>();
-    let on_click = move |_| {
-        set_is_menu_open.set(false);
-    };
+    let location = use_location();
 
     let is_authenticated = move || {
         auth_state
             .map(|state| state.get().is_authenticated)
             .unwrap_or(false)
     };
+    let first_name = move || {
+        auth_state
+            .and_then(|state| state.get().user)
+            .map(|user| user.first_name)
+            .unwrap_or_default()
+    };
+    let is_staff = move || {
+        auth_state
+            .and_then(|state| state.get().user)
+            .is_some_and(|user| user.is_staff || user.is_admin)
+    };
+    // come back to the current page once signed in
+    let sign_in_href = move || {
+        let path = location.pathname.get();
+        if path == "/sign-in" {
+            "/sign-in".to_string()
+        } else {
+            format!("/sign-in?return_to={}", Url::escape(&path))
+        }
+    };
 
     view! {
         {move || {
             if is_authenticated() {
                 view! {
-                    <A href="/me" on:click=on_click>
-                        "User"
-                    </A>
+                    {is_staff().then(|| view! {
+                        <NavbarButton set_is_menu_open text="Admin" href="/admin" />
+                    })}
+                    <NavbarButton set_is_menu_open text=first_name() href="/me" />
+                    <button
+                        class="sign-out"
+                        on:click=move |_| {
+                            set_is_menu_open.set(false);
+                            sign_out_user();
+                        }
+                    >
+                        "Sign Out"
+                    </button>
                 }
+                    .into_any()
             } else {
                 view! {
-                    <A href="/sign-in" on:click=on_click>
+                    <A href=sign_in_href on:click=move |_| set_is_menu_open.set(false)>
                         "Sign In"
                     </A>
                 }
+                    .into_any()
             }
         }}
     }
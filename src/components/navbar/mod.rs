@@ -1,4 +1,5 @@
 mod buttons;
+mod search;
 pub mod navbar;
 
 pub use navbar::Navbar;
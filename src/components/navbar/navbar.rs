@@ -1,7 +1,7 @@
 use leptos::prelude::*;
 use leptos_router::components::A;
 
-use super::buttons::NavbarButtons;
+use super::{buttons::NavbarButtons, search::NavbarSearch};
 
 #[component]
 pub fn Navbar() -> impl IntoView {
@@ -22,6 +22,7 @@ pub fn Navbar() -> impl IntoView {
                     <span class=move || if is_menu_open.get() { "open" } else { "" }></span>
                 </button>
                 <div class=move || format!("navbar-buttons {}", if is_menu_open.get() { "open" } else { "" })>
+                    <NavbarSearch set_is_menu_open />
                     <NavbarButtons set_is_menu_open />
                 </div>
             </div>
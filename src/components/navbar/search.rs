@@ -0,0 +1,107 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::time::Duration;
+
+use leptos::prelude::*;
+use leptos_router::{
+    components::{A, Form},
+    location::Url,
+};
+
+use crate::api::search::{QUERY_MAX_LENGTH, search};
+
+/// Pause in typing after which the box searches.
+const SEARCH_DELAY: Duration = Duration::from_millis(300);
+/// Hits of each kind shown under the box, the rest being on `/search`.
+const QUICK_HITS: usize = 3;
+
+/// Search box showing the best hits as the user types, submitting to
+/// `/search` for all of them.
+#[component]
+pub fn NavbarSearch(#[prop(into)] set_is_menu_open: WriteSignal<bool>) -> impl IntoView {
+    let input = RwSignal::new(String::new());
+    let query = RwSignal::new(String::new());
+    let is_open = RwSignal::new(false);
+
+    // searches once typing pauses
+    let pending = StoredValue::new(None::<TimeoutHandle>);
+    Effect::new(move |_| {
+        input.track();
+        if let Some(handle) = pending.get_value() {
+            handle.clear();
+        }
+        let handle = set_timeout_with_handle(
+            move || {
+                if let Some(value) = input.try_get_untracked() {
+                    query.try_set(value.trim().to_string());
+                }
+            },
+            SEARCH_DELAY,
+        )
+        .ok();
+        pending.set_value(handle);
+    });
+    on_cleanup(move || {
+        if let Some(handle) = pending.try_get_value().flatten() {
+            handle.clear();
+        }
+    });
+
+    let groups = Resource::new(move || query.get(), search);
+    let close = move || {
+        is_open.set(false);
+        set_is_menu_open.set(false);
+    };
+
+    view! {
+        <div class="navbar-search" on:keydown=move |event| {
+            if event.key() == "Escape" {
+                is_open.set(false);
+            }
+        }>
+            <Form method="GET" action="/search" on:submit=move |_| close()>
+                <input
+                    type="search"
+                    name="q"
+                    aria-label="Search"
+                    placeholder="Search"
+                    autocomplete="off"
+                    maxlength=QUERY_MAX_LENGTH
+                    prop:value=move || input.get()
+                    on:input=move |event| {
+                        input.set(event_target_value(&event));
+                        is_open.set(true);
+                    }
+                    on:focus=move |_| is_open.set(true)
+                />
+            </Form>
+            <Show when=move || is_open.get() && !query.get().is_empty()>
+                <div class="navbar-search-results" on:click=move |_| close()>
+                    <Transition>
+                        {move || Suspend::new(async move {
+                            let groups = groups.await.unwrap_or_default();
+                            if groups.is_empty() {
+                                return view! { <p>"No results."</p> }.into_any();
+                            }
+                            view! {
+                                {groups.into_iter().map(|group| view! {
+                                    <p class="navbar-search-kind">{group.kind.plural_label()}</p>
+                                    {group.hits.into_iter().take(QUICK_HITS).map(|hit| view! {
+                                        <A href=hit.url>{hit.title}</A>
+                                    }).collect::<Vec<_>>()}
+                                }).collect::<Vec<_>>()}
+                                <A href=move || format!("/search?q={}", Url::escape(&query.get()))>
+                                    "All results"
+                                </A>
+                            }.into_any()
+                        })}
+                    </Transition>
+                </div>
+            </Show>
+        </div>
+    }
+}
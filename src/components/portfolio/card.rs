@@ -0,0 +1,29 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use super::{links::ProjectLinks, status::StatusBadge, technologies::TechnologyIcons};
+use crate::api::portfolio::ProjectSummary;
+use crate::components::common::ResponsiveImage;
+
+#[component]
+pub fn ProjectCard(project: ProjectSummary) -> impl IntoView {
+    view! {
+        <article class="project-card">
+            {project.cover_image.map(|url| {
+                view! {
+                    <ResponsiveImage class="project-cover" src=url alt=project.title.clone() sizes="(min-width: 768px) 50vw, 100vw" lazy=true />
+                }
+            })}
+            <div class="project-card-content">
+                <div class="project-card-header">
+                    <h3><A href=format!("/portfolio/{}", project.slug)>{project.title}</A></h3>
+                    <StatusBadge status=project.status />
+                </div>
+                {project.subtitle.map(|subtitle| view! { <h4>{subtitle}</h4> })}
+                <p class="date">{project.date}</p>
+                <TechnologyIcons technologies=project.technologies />
+                <ProjectLinks links=project.links />
+            </div>
+        </article>
+    }
+}
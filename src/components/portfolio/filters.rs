@@ -0,0 +1,49 @@
+use leptos::prelude::*;
+use leptos_router::components::{A, Form};
+
+use crate::api::{
+    portfolio::{ProjectStatus, list_project_technologies},
+    technologies::TechnologyBadge,
+};
+
+#[component]
+pub fn ProjectFilters(
+    #[prop(into)] technology: Signal<Option<i32>>,
+    #[prop(into)] status: Signal<Option<ProjectStatus>>,
+) -> impl IntoView {
+    let technologies = Resource::new(|| (), |_| list_project_technologies());
+
+    view! {
+        <Form method="GET" action="">
+            <div class="project-filters">
+                <select name="technology" aria-label="Technology">
+                    <option value="" selected=move || technology.get().is_none()>"All technologies"</option>
+                    <Suspense>
+                        {move || Suspend::new(async move {
+                            technologies.await.unwrap_or_default().into_iter().map(|badge: TechnologyBadge| {
+                                let id = badge.id;
+                                view! {
+                                    <option value=id.to_string() selected=move || technology.get() == Some(id)>
+                                        {badge.name}
+                                    </option>
+                                }
+                            }).collect::<Vec<_>>()
+                        })}
+                    </Suspense>
+                </select>
+                <select name="status" aria-label="Status">
+                    <option value="" selected=move || status.get().is_none()>"All statuses"</option>
+                    {ProjectStatus::ALL.into_iter().map(|value| {
+                        view! {
+                            <option value=value.as_str() selected=move || status.get() == Some(value)>
+                                {value.label()}
+                            </option>
+                        }
+                    }).collect::<Vec<_>>()}
+                </select>
+                <button type="submit">"Filter"</button>
+                <A href="/portfolio">"Clear"</A>
+            </div>
+        </Form>
+    }
+}
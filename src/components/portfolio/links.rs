@@ -0,0 +1,23 @@
+use leptos::prelude::*;
+
+use crate::api::portfolio::ProjectLinkItem;
+
+#[component]
+pub fn ProjectLinks(#[prop(into)] links: Vec<ProjectLinkItem>) -> impl IntoView {
+    view! {
+        <div class="project-links">
+            {links.into_iter().map(|link| {
+                view! {
+                    <a
+                        class=format!("project-link {}", link.link_type.as_str())
+                        href=link.url
+                        target="_blank"
+                        rel="noopener noreferrer"
+                    >
+                        {link.link_type.label()}
+                    </a>
+                }
+            }).collect::<Vec<_>>()}
+        </div>
+    }
+}
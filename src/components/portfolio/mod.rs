@@ -0,0 +1,17 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod card;
+mod filters;
+mod links;
+mod status;
+mod technologies;
+
+pub use card::ProjectCard;
+pub use filters::ProjectFilters;
+pub use links::ProjectLinks;
+pub use status::StatusBadge;
+pub use technologies::TechnologyIcons;
@@ -0,0 +1,10 @@
+use leptos::prelude::*;
+
+use crate::api::portfolio::ProjectStatus;
+
+#[component]
+pub fn StatusBadge(status: ProjectStatus) -> impl IntoView {
+    view! {
+        <span class=format!("status-badge {}", status.as_str())>{status.label()}</span>
+    }
+}
@@ -0,0 +1,19 @@
+use leptos::prelude::*;
+
+use crate::api::technologies::TechnologyBadge;
+
+#[component]
+pub fn TechnologyIcons(#[prop(into)] technologies: Vec<TechnologyBadge>) -> impl IntoView {
+    view! {
+        <ul class="technology-icons">
+            {technologies.into_iter().map(|technology| {
+                let title = technology.name.clone();
+                view! {
+                    <li title=title>
+                        <img src=technology.icon alt=technology.name />
+                    </li>
+                }
+            }).collect::<Vec<_>>()}
+        </ul>
+    }
+}
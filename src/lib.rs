@@ -1,6 +1,12 @@
+#![allow(clippy::module_inception)]
+
+pub mod api;
 pub mod app;
 pub mod components;
 pub mod pages;
+#[cfg(feature = "ssr")]
+pub mod server;
+pub mod utils;
 
 #[cfg(feature = "hydrate")]
 #[wasm_bindgen::prelude::wasm_bindgen]
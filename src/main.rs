@@ -8,9 +8,41 @@ async fn main() -> std::io::Result<()> {
     use leptos_actix::{LeptosRoutes, generate_route_list};
     use leptos_meta::MetaTags;
     use tugascript::app::*;
+    use tugascript::server::{
+        auth,
+        config::Config,
+        cv_export::{self, CvPdfCache},
+        database,
+        markdown::RenderCache,
+        migrations, publisher,
+        session::{self, SessionStore},
+        storage::Storage,
+        uploads,
+    };
+
+    env_logger::init_from_env(env_logger::Env::default().default_filter_or("info"));
 
     let conf = get_configuration(None).unwrap();
     let addr = conf.leptos_options.site_addr;
+    let config = Config::from_env().map_err(std::io::Error::other)?;
+    let db = database::connect(&config.database)
+        .await
+        .map_err(std::io::Error::other)?;
+    migrations::run(&db, config.database.migrations)
+        .await
+        .map_err(std::io::Error::other)?;
+    publisher::spawn(db.clone(), config.blog.publish_interval);
+    let render_cache = RenderCache::default();
+    let cv_pdf_cache = CvPdfCache::default();
+    let sessions = SessionStore::connect(&config.session)
+        .await
+        .map_err(std::io::Error::other)?;
+    // shared by the OAuth flows to reach the identity providers
+    let http_client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .map_err(std::io::Error::other)?;
+    let storage = Storage::new(&config.uploads, http_client.clone());
 
     HttpServer::new(move || {
         // Generate the list of routes in your Leptos App
@@ -20,6 +52,21 @@ async fn main() -> std::io::Result<()> {
 
         println!("listening on http://{}", &addr);
 
+        // Context shared by server-side rendering and server functions
+        let context = {
+            let db = db.clone();
+            let config = config.clone();
+            let render_cache = render_cache.clone();
+            let sessions = sessions.clone();
+            move || {
+                provide_context(db.clone());
+                provide_context(config.clone());
+                provide_context(render_cache.clone());
+                provide_context(sessions.clone());
+                session::provide_current_session();
+            }
+        };
+
         App::new()
             // serve JS/WASM/CSS from `pkg`
             .service(Files::new("/pkg", format!("{site_root}/pkg")))
@@ -27,7 +74,19 @@ async fn main() -> std::io::Result<()> {
             .service(Files::new("/assets", &site_root))
             // serve the favicon from /favicon.ico
             .service(favicon)
-            .leptos_routes(routes, {
+            // serve uploaded images, unless they are stored in a bucket
+            .configure(|cfg| {
+                if let Some(dir) = storage.local_dir() {
+                    cfg.service(Files::new("/media", dir));
+                }
+            })
+            // sign-in redirects, registered before the Leptos fallback route
+            .service(auth::sign_in)
+            .service(auth::callback)
+            .service(uploads::upload)
+            .service(cv_export::cv_pdf)
+            .service(cv_export::cv_json)
+            .leptos_routes_with_context(routes, context, {
                 let leptos_options = leptos_options.clone();
                 move || {
                     view! {
@@ -48,6 +107,13 @@ async fn main() -> std::io::Result<()> {
                 }
             })
             .app_data(web::Data::new(leptos_options.to_owned()))
+            .app_data(web::Data::new(db.clone()))
+            .app_data(web::Data::new(config.clone()))
+            .app_data(web::Data::new(http_client.clone()))
+            .app_data(web::Data::new(sessions.clone()))
+            .app_data(web::Data::new(storage.clone()))
+            .app_data(web::Data::new(cv_pdf_cache.clone()))
+            .wrap(middleware::from_fn(session::middleware))
         //.wrap(middleware::Compress::default())
     })
     .bind(&addr)?
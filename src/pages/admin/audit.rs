@@ -0,0 +1,116 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::api::admin::{
+    ContentKind,
+    audit::{AuditEntry, list_audit_log},
+};
+use crate::components::global::protected::{Protected, RequiredRole};
+
+fn change_value(value: Option<String>) -> String {
+    value.unwrap_or_else(|| "—".to_string())
+}
+
+#[component]
+fn Entry(entry: AuditEntry, #[prop(optional)] show_row: bool) -> impl IntoView {
+    let user = entry.user.unwrap_or_else(|| "Deleted user".to_string());
+    let row = show_row.then(|| match entry.kind {
+        Some(kind) => view! {
+            <A href=format!("/admin/{}/{}", kind.slug(), entry.entity_id)>
+                {format!("{} #{}", kind.label(), entry.entity_id)}
+            </A>
+        }
+        .into_any(),
+        None => format!("#{}", entry.entity_id).into_any(),
+    });
+
+    view! {
+        <li class="audit-entry">
+            <p class="admin-message">
+                {entry.created_at} " · " {user} " " {entry.action} " " {row}
+            </p>
+            <ul class="audit-changes">
+                {entry.changes.into_iter().map(|change| view! {
+                    <li>
+                        <strong>{change.field}</strong>
+                        ": " {change_value(change.from)} " → " {change_value(change.to)}
+                    </li>
+                }).collect::<Vec<_>>()}
+            </ul>
+        </li>
+    }
+}
+
+/// Changes of one row, latest first.
+#[component]
+pub fn AuditHistory(kind: ContentKind, id: i32) -> impl IntoView {
+    let entries = Resource::new(|| (), move |_| list_audit_log(Some(kind), Some(id)));
+
+    view! {
+        <Suspense fallback=|| view! { <p class="admin-message">"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match entries.await {
+                    Ok(entries) if entries.is_empty() => {
+                        view! { <p class="admin-message">"No changes recorded."</p> }.into_any()
+                    }
+                    Ok(entries) => view! {
+                        <ul class="audit-log">
+                            {entries.into_iter().map(|entry| view! { <Entry entry /> }).collect::<Vec<_>>()}
+                        </ul>
+                    }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+fn AuditLog() -> impl IntoView {
+    let kind = RwSignal::new(None::<ContentKind>);
+    let entries = Resource::new(move || kind.get(), |kind| list_audit_log(kind, None));
+
+    view! {
+        <label class="audit-filter">
+            "Show "
+            <select on:change=move |ev| kind.set(event_target_value(&ev).parse().ok())>
+                <option value="">"All content"</option>
+                {ContentKind::ALL
+                    .into_iter()
+                    .map(|kind| view! { <option value=kind.as_str()>{kind.plural_label()}</option> })
+                    .collect::<Vec<_>>()}
+            </select>
+        </label>
+        <Transition fallback=|| view! { <p class="admin-message">"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match entries.await {
+                    Ok(entries) if entries.is_empty() => {
+                        view! { <p class="admin-message">"No changes recorded."</p> }.into_any()
+                    }
+                    Ok(entries) => view! {
+                        <ul class="audit-log">
+                            {entries
+                                .into_iter()
+                                .map(|entry| view! { <Entry entry show_row=true /> })
+                                .collect::<Vec<_>>()}
+                        </ul>
+                    }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Transition>
+    }
+}
+
+#[component]
+pub fn AdminAuditPage() -> impl IntoView {
+    view! {
+        <section class="admin">
+            <A href="/admin">"← Admin"</A>
+            <h2>"AUDIT LOG"</h2>
+            <Protected role=RequiredRole::Staff>
+                <AuditLog />
+            </Protected>
+        </section>
+    }
+}
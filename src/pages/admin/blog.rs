@@ -0,0 +1,385 @@
+use std::time::Duration;
+
+use leptos::prelude::*;
+use leptos_router::{
+    components::A,
+    hooks::{use_navigate, use_params_map},
+};
+
+use super::fields::{CheckboxField, SelectField, TextField};
+use crate::api::{
+    admin::{
+        NAME_LENGTH, URL_LENGTH,
+        blog::{
+            AutosavePost, CreatePost, DeletePost, DiscardDraft, PostEditor, PostImage, PostStatus,
+            SavePost, get_post_editor, list_admin_posts, preview_post,
+        },
+    },
+    uploads::UploadTarget,
+};
+use crate::components::{
+    common::{FormError, ImageUpload},
+    global::protected::{Protected, RequiredRole},
+};
+
+/// How long the editor waits for typing to pause before autosaving and
+/// refreshing the preview.
+const AUTOSAVE_DELAY: Duration = Duration::from_millis(1500);
+
+#[component]
+fn NewPostForm() -> impl IntoView {
+    let create = ServerAction::<CreatePost>::new();
+    let error = Signal::derive(move || create.value().get().and_then(Result::err));
+    let navigate = use_navigate();
+    Effect::new(move |_| {
+        if let Some(Ok(id)) = create.value().get() {
+            navigate(&format!("/admin/blog/{id}"), Default::default());
+        }
+    });
+
+    view! {
+        <FormError error />
+        <ActionForm action=create attr:class="admin-form admin-inline-form">
+            <input type="text" name="title" placeholder="Title of a new post" maxlength=NAME_LENGTH required />
+            <button type="submit" disabled=move || create.pending().get()>"New post"</button>
+        </ActionForm>
+    }
+}
+
+#[component]
+fn DeletePostButton(id: i32, title: String, delete: ServerAction<DeletePost>) -> impl IntoView {
+    let on_click = move |_| {
+        let confirmed = window()
+            .confirm_with_message(&format!("Delete \"{title}\"?"))
+            .unwrap_or(false);
+        if confirmed {
+            delete.dispatch(DeletePost { id });
+        }
+    };
+
+    view! {
+        <button class="secondary" on:click=on_click disabled=move || delete.pending().get()>
+            "Delete"
+        </button>
+    }
+}
+
+#[component]
+fn PostsTable() -> impl IntoView {
+    let delete = ServerAction::<DeletePost>::new();
+    let error = Signal::derive(move || delete.value().get().and_then(Result::err));
+    let posts = Resource::new(move || delete.version().get(), |_| list_admin_posts());
+
+    view! {
+        <FormError error />
+        <Suspense fallback=|| view! { <p class="admin-message">"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match posts.await {
+                    Ok(posts) if posts.is_empty() => {
+                        view! { <p class="admin-message">"No posts yet."</p> }.into_any()
+                    }
+                    Ok(posts) => view! {
+                        <table class="admin-table">
+                            <thead>
+                                <tr>
+                                    <th>"Title"</th>
+                                    <th>"Status"</th>
+                                    <th>"Published"</th>
+                                    <th>"Updated"</th>
+                                    <th></th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {posts.into_iter().map(|post| {
+                                    let title = post.title.clone();
+                                    view! {
+                                        <tr>
+                                            <td>
+                                                <A href=format!("/admin/blog/{}", post.id)>{post.title}</A>
+                                            </td>
+                                            <td>{post.status.label()}</td>
+                                            <td>{post.published_at}</td>
+                                            <td>{post.updated_at}</td>
+                                            <td>
+                                                <DeletePostButton id=post.id title delete />
+                                            </td>
+                                        </tr>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </tbody>
+                        </table>
+                    }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn AdminPostsPage() -> impl IntoView {
+    view! {
+        <section class="admin">
+            <A href="/admin">"← Admin"</A>
+            <h2>"BLOG POSTS"</h2>
+            <Protected role=RequiredRole::Staff>
+                <NewPostForm />
+                <PostsTable />
+            </Protected>
+        </section>
+    }
+}
+
+/// Autosaves the title and content, and refreshes the preview, once typing
+/// pauses.
+fn autosave(
+    id: i32,
+    title: RwSignal<String>,
+    content: RwSignal<String>,
+    preview_source: RwSignal<String>,
+    action: ServerAction<AutosavePost>,
+) {
+    let pending = StoredValue::new(None::<TimeoutHandle>);
+    Effect::new(move |initialized: Option<()>| {
+        title.track();
+        content.track();
+        // nothing to save before the first edit
+        if initialized.is_none() {
+            return;
+        }
+        if let Some(handle) = pending.get_value() {
+            handle.clear();
+        }
+        let handle = set_timeout_with_handle(
+            move || {
+                let (Some(title), Some(content)) =
+                    (title.try_get_untracked(), content.try_get_untracked())
+                else {
+                    return;
+                };
+                if preview_source.try_get_untracked().as_ref() != Some(&content) {
+                    preview_source.try_set(content.clone());
+                }
+                action.dispatch(AutosavePost { id, title, content });
+            },
+            AUTOSAVE_DELAY,
+        )
+        .ok();
+        pending.set_value(handle);
+    });
+    on_cleanup(move || {
+        if let Some(handle) = pending.try_get_value().flatten() {
+            handle.clear();
+        }
+    });
+}
+
+#[component]
+fn Preview(id: i32, source: RwSignal<String>) -> impl IntoView {
+    let html = Resource::new(
+        move || source.get(),
+        move |content| preview_post(id, content),
+    );
+
+    view! {
+        <Transition fallback=|| view! { <p class="admin-message">"Rendering..."</p> }>
+            {move || Suspend::new(async move {
+                match html.await {
+                    Ok(html) => view! { <div class="post-content" inner_html=html></div> }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Transition>
+    }
+}
+
+/// Uploads of the cover and of images referenced from the markdown.
+#[component]
+fn Images(id: i32, images: Vec<PostImage>) -> impl IntoView {
+    view! {
+        <section class="post-images">
+            <h3>"Images"</h3>
+            <ImageUpload target=UploadTarget::PostCover id label="Cover image" />
+            <ImageUpload target=UploadTarget::PostImage id label="Image for the content" />
+            <ul>
+                {images.into_iter().map(|image| {
+                    view! {
+                        <li>
+                            <img src=image.url alt="" loading="lazy" />
+                            <code>{format!("![](image:{})", image.id)}</code>
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+        </section>
+    }
+}
+
+#[component]
+fn Editor(
+    editor: PostEditor,
+    save: ServerAction<SavePost>,
+    discard: ServerAction<DiscardDraft>,
+) -> impl IntoView {
+    let PostEditor {
+        id,
+        slug,
+        form,
+        first_published_at,
+        draft,
+        images,
+    } = editor;
+    let restored = draft.as_ref().map(|draft| draft.saved_at.clone());
+    let (initial_title, initial_content) = match draft {
+        Some(draft) => (draft.title, draft.content),
+        None => (form.title, form.content),
+    };
+    let title = RwSignal::new(initial_title);
+    let content = RwSignal::new(initial_content.clone());
+    let preview_source = RwSignal::new(initial_content);
+
+    let autosave_action = ServerAction::<AutosavePost>::new();
+    autosave(id, title, content, preview_source, autosave_action);
+    let autosave_error =
+        Signal::derive(move || autosave_action.value().get().and_then(Result::err));
+    let autosave_status = Memo::new(move |_| {
+        if autosave_action.pending().get() {
+            return Some("Saving draft...".to_string());
+        }
+        match autosave_action.value().get() {
+            Some(Ok(saved_at)) => Some(format!("Draft autosaved at {saved_at}.")),
+            _ => restored
+                .as_ref()
+                .map(|saved_at| format!("Restored the draft autosaved at {saved_at}.")),
+        }
+    });
+    let is_published = form.status == PostStatus::Published;
+
+    view! {
+        <p class="admin-message">
+            {first_published_at.map(|date| format!("First published on {date}. "))}
+            {is_published.then(|| view! { <A href=format!("/blog/{slug}")>"View post"</A> })}
+        </p>
+        <FormError error=autosave_error />
+        <p class="admin-message">
+            {autosave_status}
+            <Show when=move || autosave_status.get().is_some()>
+                " "
+                <button
+                    class="link-button"
+                    on:click=move |_| {
+                        discard.dispatch(DiscardDraft { id });
+                    }
+                    disabled=move || discard.pending().get()
+                >
+                    "Discard draft"
+                </button>
+            </Show>
+        </p>
+        <div class="post-editor">
+            <ActionForm action=save attr:class="admin-form">
+                <input type="hidden" name="id" value=id />
+                <label>
+                    "Title"
+                    <input type="text" name="post[title]" maxlength=NAME_LENGTH required bind:value=title />
+                </label>
+                <label>
+                    "Content (markdown)"
+                    <textarea name="post[content]" rows="24" bind:value=content></textarea>
+                </label>
+                <TextField
+                    label="Cover image URL"
+                    name="post[cover_image_url]"
+                    value=form.cover_image_url
+                    max_length=URL_LENGTH
+                    kind="url"
+                />
+                <TextField label="Tags, comma separated" name="post[tags]" value=form.tags />
+                <CheckboxField label="Featured" name="post[is_featured]" checked=form.is_featured />
+                <SelectField
+                    label="Status"
+                    name="post[status]"
+                    options=PostStatus::ALL
+                        .into_iter()
+                        .map(|status| (status.as_str().to_string(), status.label().to_string()))
+                        .collect()
+                    selected=form.status.as_str().to_string()
+                />
+                <label>
+                    "Publish at (UTC), for scheduled posts"
+                    <input type="datetime-local" name="post[publish_at]" value=form.publish_at />
+                </label>
+                <button type="submit" disabled=move || save.pending().get()>"Save"</button>
+            </ActionForm>
+            <div class="post-preview">
+                <h3>{move || title.get()}</h3>
+                <Preview id source=preview_source />
+            </div>
+        </div>
+        <Images id images />
+    }
+}
+
+#[component]
+fn PostEditing() -> impl IntoView {
+    let params = use_params_map();
+    let save = ServerAction::<SavePost>::new();
+    let discard = ServerAction::<DiscardDraft>::new();
+    // reloaded once saved or discarded, not after a failed save that would
+    // lose the unsaved changes
+    let revision = RwSignal::new(0_usize);
+    for value in [save.value(), discard.value()] {
+        Effect::new(move |_| {
+            if matches!(value.get(), Some(Ok(()))) {
+                revision.update(|revision| *revision += 1);
+            }
+        });
+    }
+    let error = Signal::derive(move || {
+        let save_error = save.value().get().and_then(Result::err);
+        let discard_error = discard.value().get().and_then(Result::err);
+        save_error.or(discard_error)
+    });
+    let saved = move || matches!(save.value().get(), Some(Ok(())));
+
+    let editor = Resource::new(
+        move || {
+            (
+                params
+                    .read()
+                    .get("id")
+                    .and_then(|id| id.parse::<i32>().ok()),
+                revision.get(),
+            )
+        },
+        |(id, _)| get_post_editor(id.unwrap_or_default()),
+    );
+
+    view! {
+        <FormError error />
+        <Show when=saved>
+            <p class="admin-message">"Saved."</p>
+        </Show>
+        <Transition fallback=|| view! { <p class="admin-message">"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match editor.await {
+                    Ok(editor) => view! { <Editor editor save discard /> }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Transition>
+    }
+}
+
+#[component]
+pub fn AdminPostEditPage() -> impl IntoView {
+    view! {
+        <section class="admin admin-wide">
+            <A href="/admin/blog">"← Blog posts"</A>
+            <h2>"EDIT POST"</h2>
+            <Protected role=RequiredRole::Staff>
+                <PostEditing />
+            </Protected>
+        </section>
+    }
+}
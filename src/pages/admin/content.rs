@@ -0,0 +1,226 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map};
+
+use super::{
+    audit::AuditHistory,
+    editors::{
+        CertificateEditor, EducationEditor, ExperienceEditor, OpenSourceContributionEditor,
+        ProjectEditor, SkillEditor, SummaryEditor, TechnologyEditor,
+    },
+};
+use crate::api::admin::{ContentKind, ContentRow, DeleteContent, list_content};
+use crate::components::{
+    common::FormError,
+    global::{
+        protected::{Protected, RequiredRole},
+        state::AuthenticatedState,
+    },
+};
+
+fn use_kind() -> Memo<Option<ContentKind>> {
+    let params = use_params_map();
+    Memo::new(move |_| {
+        params
+            .read()
+            .get("kind")
+            .and_then(|kind| ContentKind::from_slug(&kind))
+    })
+}
+
+#[component]
+pub fn AdminPage() -> impl IntoView {
+    let auth_state = use_context::<ReadSignal<AuthenticatedState>>();
+    let is_admin = move || {
+        auth_state
+            .and_then(|state| state.get().user)
+            .is_some_and(|user| user.is_admin)
+    };
+
+    view! {
+        <section class="admin">
+            <h2>"ADMIN"</h2>
+            <Protected role=RequiredRole::Staff>
+                <ul class="admin-links">
+                    {ContentKind::ALL
+                        .into_iter()
+                        .map(|kind| view! {
+                            <li>
+                                <A href=format!("/admin/{}", kind.slug())>{kind.plural_label()}</A>
+                            </li>
+                        })
+                        .collect::<Vec<_>>()}
+                    <li>
+                        <A href="/admin/import">"Import CV"</A>
+                    </li>
+                    <li>
+                        <A href="/admin/blog">"Blog posts"</A>
+                    </li>
+                    <li>
+                        <A href="/admin/series">"Series"</A>
+                    </li>
+                    <li>
+                        <A href="/admin/tags">"Tags"</A>
+                    </li>
+                    <li>
+                        <A href="/admin/audit">"Audit log"</A>
+                    </li>
+                    <Show when=is_admin>
+                        <li>
+                            <A href="/admin/users">"Users"</A>
+                        </li>
+                    </Show>
+                </ul>
+            </Protected>
+        </section>
+    }
+}
+
+#[component]
+fn DeleteButton(
+    kind: ContentKind,
+    id: i32,
+    title: String,
+    delete: ServerAction<DeleteContent>,
+) -> impl IntoView {
+    let on_click = move |_| {
+        let confirmed = window()
+            .confirm_with_message(&format!("Delete \"{title}\"?"))
+            .unwrap_or(false);
+        if confirmed {
+            delete.dispatch(DeleteContent { kind, id });
+        }
+    };
+
+    view! {
+        <button class="secondary" on:click=on_click disabled=move || delete.pending().get()>
+            "Delete"
+        </button>
+    }
+}
+
+#[component]
+fn ContentTable(kind: ContentKind) -> impl IntoView {
+    let delete = ServerAction::<DeleteContent>::new();
+    let error = Signal::derive(move || delete.value().get().and_then(Result::err));
+    let rows = Resource::new(move || delete.version().get(), move |_| list_content(kind));
+
+    view! {
+        <A href=format!("/admin/{}/new", kind.slug())>{format!("New {}", kind.label().to_lowercase())}</A>
+        <FormError error />
+        <Suspense fallback=|| view! { <p class="admin-message">"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match rows.await {
+                    Ok(rows) if rows.is_empty() => {
+                        view! { <p class="admin-message">"Nothing here yet."</p> }.into_any()
+                    }
+                    Ok(rows) => view! {
+                        <table class="admin-table">
+                            <thead>
+                                <tr>
+                                    <th>{kind.label()}</th>
+                                    <th>"Details"</th>
+                                    <th>"Updated"</th>
+                                    <th></th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {rows.into_iter().map(|row| {
+                                    let ContentRow { id, title, details, updated_at } = row;
+                                    let name = title.clone();
+                                    view! {
+                                    <tr>
+                                        <td>
+                                            <A href=format!("/admin/{}/{id}", kind.slug())>{name}</A>
+                                        </td>
+                                        <td>{details}</td>
+                                        <td>{updated_at}</td>
+                                        <td>
+                                            <DeleteButton kind id title delete />
+                                        </td>
+                                    </tr>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </tbody>
+                        </table>
+                    }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn AdminContentPage() -> impl IntoView {
+    let kind = use_kind();
+
+    view! {
+        <section class="admin">
+            <A href="/admin">"← Admin"</A>
+            <Protected role=RequiredRole::Staff>
+                {move || match kind.get() {
+                    Some(kind) => view! {
+                        <h2>{kind.plural_label().to_uppercase()}</h2>
+                        <ContentTable kind />
+                    }.into_any(),
+                    None => view! { <p class="admin-message">"Not found."</p> }.into_any(),
+                }}
+            </Protected>
+        </section>
+    }
+}
+
+fn editor(kind: ContentKind, id: Option<i32>) -> AnyView {
+    match kind {
+        ContentKind::Project => view! { <ProjectEditor id /> }.into_any(),
+        ContentKind::Technology => view! { <TechnologyEditor id /> }.into_any(),
+        ContentKind::Experience => view! { <ExperienceEditor id /> }.into_any(),
+        ContentKind::Education => view! { <EducationEditor id /> }.into_any(),
+        ContentKind::Certificate => view! { <CertificateEditor id /> }.into_any(),
+        ContentKind::Skill => view! { <SkillEditor id /> }.into_any(),
+        ContentKind::Summary => view! { <SummaryEditor id /> }.into_any(),
+        ContentKind::OpenSourceContribution => {
+            view! { <OpenSourceContributionEditor id /> }.into_any()
+        }
+    }
+}
+
+#[component]
+pub fn AdminContentEditPage() -> impl IntoView {
+    let params = use_params_map();
+    let kind = use_kind();
+    // "new" for a row still to be created
+    let id = Memo::new(move |_| {
+        params.read().get("id").map(|id| {
+            if id == "new" {
+                Ok(None)
+            } else {
+                id.parse().map(Some)
+            }
+        })
+    });
+
+    view! {
+        <section class="admin">
+            {move || kind.get().map(|kind| view! {
+                <A href=format!("/admin/{}", kind.slug())>{format!("← {}", kind.plural_label())}</A>
+            })}
+            <Protected role=RequiredRole::Staff>
+                {move || match (kind.get(), id.get()) {
+                    (Some(kind), Some(Ok(id))) => view! {
+                        <h2>
+                            {if id.is_some() { "EDIT " } else { "NEW " }}
+                            {kind.label().to_uppercase()}
+                        </h2>
+                        {editor(kind, id)}
+                        {id.map(|id| view! {
+                            <h3>"History"</h3>
+                            <AuditHistory kind id />
+                        })}
+                    }.into_any(),
+                    _ => view! { <p class="admin-message">"Not found."</p> }.into_any(),
+                }}
+            </Protected>
+        </section>
+    }
+}
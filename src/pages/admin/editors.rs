@@ -0,0 +1,374 @@
+use std::future::Future;
+
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+
+use super::fields::{CheckboxField, DateField, SelectField, TextAreaField, TextField};
+use crate::api::{
+    ApiError,
+    admin::{
+        ContentKind, GRADE_LENGTH, NAME_LENGTH, SUBTITLE_LENGTH, URL_LENGTH,
+        VERIFICATION_CODE_LENGTH,
+        cv::{
+            SaveCertificate, SaveEducation, SaveExperience, SaveOpenSourceContribution, SaveSkill,
+            SaveSummary, get_certificate_form, get_education_form, get_experience_form,
+            get_open_source_contribution_form, get_skill_form, get_summary_form,
+        },
+        portfolio::{
+            SaveProject, SaveTechnology, get_project_form, get_technology_form,
+            list_technology_options,
+        },
+    },
+    cv::CodeSource,
+    portfolio::{COVER_IMAGE_TYPE, ProjectStatus},
+    technologies::TechnologyType,
+    uploads::{PROJECT_IMAGE_TYPES, UploadTarget},
+};
+use crate::components::common::{FormError, ImageUpload};
+
+/// Loads the form of row `id`, or an empty one for a new row.
+async fn load<F, Fut>(id: Option<i32>, get: impl FnOnce(i32) -> Fut) -> Result<F, ApiError>
+where
+    F: Default,
+    Fut: Future<Output = Result<F, ApiError>>,
+{
+    match id {
+        Some(id) => get(id).await,
+        None => Ok(F::default()),
+    }
+}
+
+/// Shows the outcome of the last save, moving on to the edit screen of a
+/// newly created row.
+#[component]
+fn SaveStatus<A>(action: ServerAction<A>, kind: ContentKind, id: Option<i32>) -> impl IntoView
+where
+    A: leptos::server_fn::ServerFn<Output = i32, Error = ApiError> + Send + Sync + Clone + 'static,
+{
+    let navigate = use_navigate();
+    Effect::new(move |_| {
+        if let (None, Some(Ok(created))) = (id, action.value().get()) {
+            navigate(
+                &format!("/admin/{}/{created}", kind.slug()),
+                Default::default(),
+            );
+        }
+    });
+    let error = Signal::derive(move || action.value().get().and_then(Result::err));
+    let saved = move || id.is_some() && matches!(action.value().get(), Some(Ok(_)));
+
+    view! {
+        <FormError error />
+        <Show when=saved>
+            <p class="admin-message">"Saved."</p>
+        </Show>
+    }
+}
+
+fn loading() -> impl IntoView {
+    view! { <p class="admin-message">"Loading..."</p> }
+}
+
+fn options<T: Copy>(
+    values: impl IntoIterator<Item = T>,
+    value: impl Fn(T) -> &'static str,
+    label: impl Fn(T) -> &'static str,
+) -> Vec<(String, String)> {
+    values
+        .into_iter()
+        .map(|item| (value(item).to_string(), label(item).to_string()))
+        .collect()
+}
+
+fn hidden_id(id: Option<i32>) -> impl IntoView {
+    id.map(|id| view! { <input type="hidden" name="id" value=id /> })
+}
+
+#[component]
+pub fn ProjectEditor(id: Option<i32>) -> impl IntoView {
+    let action = ServerAction::<SaveProject>::new();
+    let form = Resource::new(
+        move || action.version().get(),
+        move |_| load(id, get_project_form),
+    );
+
+    view! {
+        <SaveStatus action kind=ContentKind::Project id />
+        <Suspense fallback=loading>
+            {move || Suspend::new(async move {
+                match form.await {
+                    Ok(project) => view! {
+                        <ActionForm action attr:class="admin-form">
+                            {hidden_id(id)}
+                            <TextField label="Title" name="project[title]" value=project.title max_length=NAME_LENGTH required=true />
+                            <TextField label="Subtitle" name="project[subtitle]" value=project.subtitle max_length=SUBTITLE_LENGTH />
+                            <TextAreaField label="Description (markdown)" name="project[description]" value=project.description required=true rows=10 />
+                            <SelectField
+                                label="Status"
+                                name="project[status]"
+                                options=options(ProjectStatus::ALL, ProjectStatus::as_str, ProjectStatus::label)
+                                selected=project.status.as_str().to_string()
+                            />
+                            <DateField label="Start date" name="project[start_date]" value=project.start_date required=true />
+                            <DateField label="End date" name="project[end_date]" value=project.end_date />
+                            <DateField label="Drop date" name="project[drop_date]" value=project.drop_date />
+                            <button type="submit" disabled=move || action.pending().get()>"Save"</button>
+                        </ActionForm>
+                    }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+        // images can only be added to saved projects
+        {id.map(|id| view! {
+            <ImageUpload target=UploadTarget::ProjectImage id label="Gallery image">
+                <SelectField
+                    label="Type"
+                    name="image_type"
+                    options=PROJECT_IMAGE_TYPES
+                        .into_iter()
+                        .map(|image_type| (image_type.to_string(), image_type.to_string()))
+                        .collect()
+                    selected=COVER_IMAGE_TYPE.to_string()
+                />
+            </ImageUpload>
+        })}
+    }
+}
+
+#[component]
+pub fn TechnologyEditor(id: Option<i32>) -> impl IntoView {
+    let action = ServerAction::<SaveTechnology>::new();
+    let form = Resource::new(
+        move || action.version().get(),
+        move |_| load(id, get_technology_form),
+    );
+
+    view! {
+        <SaveStatus action kind=ContentKind::Technology id />
+        <Suspense fallback=loading>
+            {move || Suspend::new(async move {
+                match form.await {
+                    Ok(technology) => view! {
+                        <ActionForm action attr:class="admin-form">
+                            {hidden_id(id)}
+                            <TextField label="Name" name="technology[name]" value=technology.name max_length=NAME_LENGTH required=true />
+                            <TextField label="Icon URL" name="technology[icon]" value=technology.icon required=true />
+                            <SelectField
+                                label="Type"
+                                name="technology[tech_type]"
+                                options=options(TechnologyType::ALL, TechnologyType::as_str, TechnologyType::label)
+                                selected=technology.tech_type.as_str().to_string()
+                            />
+                            <TextAreaField label="Description" name="technology[description]" value=technology.description />
+                            <button type="submit" disabled=move || action.pending().get()>"Save"</button>
+                        </ActionForm>
+                    }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn ExperienceEditor(id: Option<i32>) -> impl IntoView {
+    let action = ServerAction::<SaveExperience>::new();
+    let form = Resource::new(
+        move || action.version().get(),
+        move |_| load(id, get_experience_form),
+    );
+
+    view! {
+        <SaveStatus action kind=ContentKind::Experience id />
+        <Suspense fallback=loading>
+            {move || Suspend::new(async move {
+                match form.await {
+                    Ok(experience) => view! {
+                        <ActionForm action attr:class="admin-form">
+                            {hidden_id(id)}
+                            <TextField label="Title" name="experience[title]" value=experience.title max_length=NAME_LENGTH required=true />
+                            <TextField label="Company" name="experience[company]" value=experience.company max_length=NAME_LENGTH required=true />
+                            <TextField label="Location" name="experience[location]" value=experience.location max_length=NAME_LENGTH required=true />
+                            <DateField label="Start date" name="experience[start_date]" value=experience.start_date required=true />
+                            <DateField label="End date" name="experience[end_date]" value=experience.end_date />
+                            <CheckboxField label="Current position" name="experience[is_current]" checked=experience.is_current />
+                            <TextAreaField label="Achievements (one per line)" name="experience[achievements]" value=experience.achievements rows=8 />
+                            <button type="submit" disabled=move || action.pending().get()>"Save"</button>
+                        </ActionForm>
+                    }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn EducationEditor(id: Option<i32>) -> impl IntoView {
+    let action = ServerAction::<SaveEducation>::new();
+    let form = Resource::new(
+        move || action.version().get(),
+        move |_| load(id, get_education_form),
+    );
+
+    view! {
+        <SaveStatus action kind=ContentKind::Education id />
+        <Suspense fallback=loading>
+            {move || Suspend::new(async move {
+                match form.await {
+                    Ok(education) => view! {
+                        <ActionForm action attr:class="admin-form">
+                            {hidden_id(id)}
+                            <TextField label="Degree" name="education[degree]" value=education.degree max_length=NAME_LENGTH required=true />
+                            <TextField label="Institution" name="education[institution]" value=education.institution max_length=NAME_LENGTH required=true />
+                            <TextField label="Location" name="education[location]" value=education.location max_length=NAME_LENGTH required=true />
+                            <DateField label="Start date" name="education[start_date]" value=education.start_date required=true />
+                            <DateField label="End date" name="education[end_date]" value=education.end_date />
+                            <TextField label="Grade" name="education[grade]" value=education.grade max_length=GRADE_LENGTH />
+                            <TextAreaField label="Description" name="education[description]" value=education.description />
+                            <button type="submit" disabled=move || action.pending().get()>"Save"</button>
+                        </ActionForm>
+                    }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn CertificateEditor(id: Option<i32>) -> impl IntoView {
+    let action = ServerAction::<SaveCertificate>::new();
+    let form = Resource::new(
+        move || action.version().get(),
+        move |_| load(id, get_certificate_form),
+    );
+
+    view! {
+        <SaveStatus action kind=ContentKind::Certificate id />
+        <Suspense fallback=loading>
+            {move || Suspend::new(async move {
+                match form.await {
+                    Ok(certificate) => view! {
+                        <ActionForm action attr:class="admin-form">
+                            {hidden_id(id)}
+                            <TextField label="Title" name="certificate[title]" value=certificate.title max_length=NAME_LENGTH required=true />
+                            <TextField label="Issuer" name="certificate[issuer]" value=certificate.issuer max_length=NAME_LENGTH required=true />
+                            <DateField label="Issue date" name="certificate[issue_date]" value=certificate.issue_date required=true />
+                            <DateField label="Expiration date" name="certificate[expiration_date]" value=certificate.expiration_date />
+                            <TextField label="Verification code" name="certificate[verification_code]" value=certificate.verification_code max_length=VERIFICATION_CODE_LENGTH />
+                            <TextAreaField label="Description" name="certificate[description]" value=certificate.description />
+                            <button type="submit" disabled=move || action.pending().get()>"Save"</button>
+                        </ActionForm>
+                    }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn SkillEditor(id: Option<i32>) -> impl IntoView {
+    let action = ServerAction::<SaveSkill>::new();
+    let form = Resource::new(
+        move || action.version().get(),
+        move |_| load(id, get_skill_form),
+    );
+    let technologies = Resource::new(|| (), |_| list_technology_options());
+
+    view! {
+        <SaveStatus action kind=ContentKind::Skill id />
+        <Suspense fallback=loading>
+            {move || Suspend::new(async move {
+                match (form.await, technologies.await) {
+                    (Ok(skill), Ok(technologies)) => view! {
+                        <ActionForm action attr:class="admin-form">
+                            {hidden_id(id)}
+                            <SelectField
+                                label="Technology"
+                                name="skill[technology_id]"
+                                options=technologies
+                                    .into_iter()
+                                    .map(|(id, name)| (id.to_string(), name))
+                                    .collect()
+                                selected=skill.technology_id.to_string()
+                            />
+                            <DateField label="First used" name="skill[initial_usage_date]" value=skill.initial_usage_date required=true />
+                            <DateField label="Last used" name="skill[last_usage_date]" value=skill.last_usage_date />
+                            <CheckboxField label="Currently used" name="skill[is_currently_used]" checked=skill.is_currently_used />
+                            <TextAreaField label="Description" name="skill[description]" value=skill.description />
+                            <button type="submit" disabled=move || action.pending().get()>"Save"</button>
+                        </ActionForm>
+                    }.into_any(),
+                    (Err(err), _) | (_, Err(err)) => {
+                        view! { <p class="admin-message">{err.to_string()}</p> }.into_any()
+                    }
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn SummaryEditor(id: Option<i32>) -> impl IntoView {
+    let action = ServerAction::<SaveSummary>::new();
+    let form = Resource::new(
+        move || action.version().get(),
+        move |_| load(id, get_summary_form),
+    );
+
+    view! {
+        <SaveStatus action kind=ContentKind::Summary id />
+        <Suspense fallback=loading>
+            {move || Suspend::new(async move {
+                match form.await {
+                    Ok(summary) => view! {
+                        <ActionForm action attr:class="admin-form">
+                            {hidden_id(id)}
+                            <TextAreaField label="Summary" name="summary[summary]" value=summary.summary required=true rows=10 />
+                            <button type="submit" disabled=move || action.pending().get()>"Save"</button>
+                        </ActionForm>
+                    }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn OpenSourceContributionEditor(id: Option<i32>) -> impl IntoView {
+    let action = ServerAction::<SaveOpenSourceContribution>::new();
+    let form = Resource::new(
+        move || action.version().get(),
+        move |_| load(id, get_open_source_contribution_form),
+    );
+
+    view! {
+        <SaveStatus action kind=ContentKind::OpenSourceContribution id />
+        <Suspense fallback=loading>
+            {move || Suspend::new(async move {
+                match form.await {
+                    Ok(contribution) => view! {
+                        <ActionForm action attr:class="admin-form">
+                            {hidden_id(id)}
+                            <TextField label="Name" name="contribution[name]" value=contribution.name max_length=NAME_LENGTH required=true />
+                            <TextField label="URL" name="contribution[url]" value=contribution.url max_length=URL_LENGTH required=true kind="url" />
+                            <SelectField
+                                label="Source"
+                                name="contribution[source]"
+                                options=options(CodeSource::ALL, CodeSource::as_str, CodeSource::label)
+                                selected=contribution.source.as_str().to_string()
+                            />
+                            <TextAreaField label="Description" name="contribution[description]" value=contribution.description />
+                            <button type="submit" disabled=move || action.pending().get()>"Save"</button>
+                        </ActionForm>
+                    }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
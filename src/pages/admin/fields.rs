@@ -0,0 +1,84 @@
+use leptos::prelude::*;
+
+/// Single line input, `max_length` mirroring the column's length.
+#[component]
+pub fn TextField(
+    label: &'static str,
+    name: &'static str,
+    value: String,
+    #[prop(optional)] max_length: Option<usize>,
+    #[prop(optional)] required: bool,
+    #[prop(default = "text")] kind: &'static str,
+) -> impl IntoView {
+    view! {
+        <label>
+            {label}
+            <input type=kind name=name value=value maxlength=max_length required=required />
+        </label>
+    }
+}
+
+#[component]
+pub fn TextAreaField(
+    label: &'static str,
+    name: &'static str,
+    value: String,
+    #[prop(optional)] required: bool,
+    #[prop(default = 5)] rows: usize,
+) -> impl IntoView {
+    view! {
+        <label>
+            {label}
+            <textarea name=name rows=rows required=required>{value}</textarea>
+        </label>
+    }
+}
+
+#[component]
+pub fn DateField(
+    label: &'static str,
+    name: &'static str,
+    value: String,
+    #[prop(optional)] required: bool,
+) -> impl IntoView {
+    view! {
+        <label>
+            {label}
+            <input type="date" name=name value=value required=required />
+        </label>
+    }
+}
+
+#[component]
+pub fn CheckboxField(label: &'static str, name: &'static str, checked: bool) -> impl IntoView {
+    view! {
+        <label class="admin-checkbox">
+            <input type="checkbox" name=name value="true" checked=checked />
+            {label}
+        </label>
+    }
+}
+
+/// Select over `options`, given as `(value, label)` pairs.
+#[component]
+pub fn SelectField(
+    label: &'static str,
+    name: &'static str,
+    options: Vec<(String, String)>,
+    selected: String,
+) -> impl IntoView {
+    view! {
+        <label>
+            {label}
+            <select name=name>
+                {options
+                    .into_iter()
+                    .map(|(value, text)| {
+                        let is_selected = value == selected;
+                        view! { <option value=value selected=is_selected>{text}</option> }
+                    })
+                    .collect::<Vec<_>>()}
+            </select>
+        </label>
+    }
+}
@@ -0,0 +1,80 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use super::fields::TextAreaField;
+use crate::api::admin::resume::{ImportEntry, ImportOutcome, ImportReport, ImportResume};
+use crate::components::{
+    common::FormError,
+    global::protected::{Protected, RequiredRole},
+};
+
+#[component]
+fn Report(report: ImportReport) -> impl IntoView {
+    // counted before the entries move into the table
+    let summary = format!(
+        "{} created, {} updated, {} skipped.",
+        report.count("Created"),
+        report.count("Updated"),
+        report.count("Skipped")
+    );
+
+    view! {
+        <p class="admin-message">{summary}</p>
+        {(!report.entries.is_empty()).then(|| view! {
+            <table class="admin-table">
+                <thead>
+                    <tr>
+                        <th>"Type"</th>
+                        <th>"Entry"</th>
+                        <th>"Outcome"</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {report.entries.into_iter().map(|entry| {
+                        let ImportEntry { kind, title, outcome } = entry;
+                        let reason = match &outcome {
+                            ImportOutcome::Skipped(reason) => format!(": {reason}"),
+                            _ => String::new(),
+                        };
+                        view! {
+                            <tr>
+                                <td>{kind.label()}</td>
+                                <td>{title}</td>
+                                <td>{outcome.label()}{reason}</td>
+                            </tr>
+                        }
+                    }).collect::<Vec<_>>()}
+                </tbody>
+            </table>
+        })}
+    }
+}
+
+/// Imports a JSON Resume document, e.g. one exported from `/cv.json`.
+#[component]
+pub fn AdminImportPage() -> impl IntoView {
+    let action = ServerAction::<ImportResume>::new();
+    let error = Signal::derive(move || action.value().get().and_then(Result::err));
+
+    view! {
+        <section class="admin">
+            <A href="/admin">"← Admin"</A>
+            <Protected role=RequiredRole::Staff>
+                <h2>"IMPORT CV"</h2>
+                <p class="admin-message">
+                    "Paste a "
+                    <a href="https://jsonresume.org/schema" target="_blank">"JSON Resume"</a>
+                    " document. Entries matching existing ones are updated, skills need their technology to exist."
+                </p>
+                <ActionForm action attr:class="admin-form">
+                    <TextAreaField label="JSON Resume" name="resume" value=String::new() required=true rows=20 />
+                    <button type="submit" disabled=move || action.pending().get()>"Import"</button>
+                </ActionForm>
+                <FormError error />
+                {move || action.value().get().and_then(Result::ok).map(|report| view! {
+                    <Report report />
+                })}
+            </Protected>
+        </section>
+    }
+}
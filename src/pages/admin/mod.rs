@@ -0,0 +1,23 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod audit;
+mod blog;
+mod content;
+mod editors;
+mod fields;
+mod import;
+mod series;
+mod tags;
+mod users;
+
+pub use audit::AdminAuditPage;
+pub use blog::{AdminPostEditPage, AdminPostsPage};
+pub use content::{AdminContentEditPage, AdminContentPage, AdminPage};
+pub use import::AdminImportPage;
+pub use series::AdminSeriesPage;
+pub use tags::AdminTagsPage;
+pub use users::{AdminUserPage, AdminUsersPage};
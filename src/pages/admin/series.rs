@@ -0,0 +1,192 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use super::fields::SelectField;
+use crate::api::{
+    ApiError,
+    admin::{
+        blog::{AdminPostRow, list_admin_posts},
+        series::{AddSeriesPost, AdminSeries, MoveSeriesPost, RemoveSeriesPost, list_admin_series},
+    },
+};
+use crate::components::{
+    common::FormError,
+    global::protected::{Protected, RequiredRole},
+};
+
+/// Actions of the series page, sharing a reload of the series.
+#[derive(Clone, Copy)]
+struct SeriesActions {
+    add: ServerAction<AddSeriesPost>,
+    move_post: ServerAction<MoveSeriesPost>,
+    remove: ServerAction<RemoveSeriesPost>,
+}
+
+impl SeriesActions {
+    fn pending(self) -> bool {
+        self.add.pending().get() || self.move_post.pending().get() || self.remove.pending().get()
+    }
+}
+
+#[component]
+fn AddPostForm(
+    series_id: i32,
+    posts: Vec<AdminPostRow>,
+    add: ServerAction<AddSeriesPost>,
+) -> impl IntoView {
+    let options = posts
+        .into_iter()
+        .map(|post| (post.id.to_string(), post.title))
+        .collect::<Vec<_>>();
+    let selected = options
+        .first()
+        .map(|(value, _)| value.clone())
+        .unwrap_or_default();
+
+    view! {
+        <ActionForm action=add attr:class="admin-form admin-inline-form">
+            <input type="hidden" name="series_id" value=series_id />
+            <SelectField label="Post" name="post_id" options selected />
+            <button type="submit" disabled=move || add.pending().get()>"Add"</button>
+        </ActionForm>
+    }
+}
+
+#[component]
+fn SeriesTable(
+    series: AdminSeries,
+    posts: Vec<AdminPostRow>,
+    actions: SeriesActions,
+) -> impl IntoView {
+    let series_id = series.id;
+    let count = series.posts.len();
+    let candidates = posts
+        .into_iter()
+        .filter(|post| !series.posts.iter().any(|in_series| in_series.id == post.id))
+        .collect::<Vec<_>>();
+
+    view! {
+        <h3>{series.title}</h3>
+        {if series.posts.is_empty() {
+            view! { <p class="admin-message">"No posts in the series yet."</p> }.into_any()
+        } else {
+            view! {
+                <table class="admin-table">
+                    <thead>
+                        <tr>
+                            <th>"#"</th>
+                            <th>"Post"</th>
+                            <th>"Status"</th>
+                            <th></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {series.posts.into_iter().enumerate().map(|(index, post)| {
+                            let post_id = post.id;
+                            let move_post = move |later| {
+                                actions.move_post.dispatch(MoveSeriesPost { series_id, post_id, later });
+                            };
+                            let title = post.title.clone();
+                            let remove = move |_| {
+                                let confirmed = window()
+                                    .confirm_with_message(&format!("Remove \"{title}\" from the series?"))
+                                    .unwrap_or(false);
+                                if confirmed {
+                                    actions.remove.dispatch(RemoveSeriesPost { series_id, post_id });
+                                }
+                            };
+                            view! {
+                                <tr>
+                                    <td>{index + 1}</td>
+                                    <td>
+                                        <A href=format!("/admin/blog/{post_id}")>{post.title}</A>
+                                    </td>
+                                    <td>{post.status.label()}</td>
+                                    <td>
+                                        <button
+                                            class="secondary"
+                                            on:click=move |_| move_post(false)
+                                            disabled=move || index == 0 || actions.pending()
+                                        >
+                                            "Up"
+                                        </button>
+                                        <button
+                                            class="secondary"
+                                            on:click=move |_| move_post(true)
+                                            disabled=move || index + 1 == count || actions.pending()
+                                        >
+                                            "Down"
+                                        </button>
+                                        <button class="secondary" on:click=remove disabled=move || actions.pending()>
+                                            "Remove"
+                                        </button>
+                                    </td>
+                                </tr>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </tbody>
+                </table>
+            }.into_any()
+        }}
+        {(!candidates.is_empty()).then(|| view! {
+            <AddPostForm series_id posts=candidates add=actions.add />
+        })}
+    }
+}
+
+/// Lists the blog series, ordering the posts in each.
+#[component]
+pub fn AdminSeriesPage() -> impl IntoView {
+    let actions = SeriesActions {
+        add: ServerAction::<AddSeriesPost>::new(),
+        move_post: ServerAction::<MoveSeriesPost>::new(),
+        remove: ServerAction::<RemoveSeriesPost>::new(),
+    };
+    let error = Signal::derive(move || {
+        actions
+            .add
+            .value()
+            .get()
+            .and_then(Result::err)
+            .or_else(|| actions.move_post.value().get().and_then(Result::err))
+            .or_else(|| actions.remove.value().get().and_then(Result::err))
+    });
+    let series = Resource::new(
+        move || {
+            (
+                actions.add.version().get(),
+                actions.move_post.version().get(),
+                actions.remove.version().get(),
+            )
+        },
+        |_| async { Ok::<_, ApiError>((list_admin_series().await?, list_admin_posts().await?)) },
+    );
+
+    view! {
+        <section class="admin">
+            <A href="/admin">"← Admin"</A>
+            <h2>"SERIES"</h2>
+            <Protected role=RequiredRole::Staff>
+                <p class="admin-message">
+                    "Readers go through the posts of a series in this order. Drafts keep their place, they show once published."
+                </p>
+                <FormError error />
+                <Transition fallback=|| view! { <p class="admin-message">"Loading..."</p> }>
+                    {move || Suspend::new(async move {
+                        match series.await {
+                            Ok((series, _)) if series.is_empty() => {
+                                view! { <p class="admin-message">"No series yet."</p> }.into_any()
+                            }
+                            Ok((series, posts)) => series
+                                .into_iter()
+                                .map(|series| view! { <SeriesTable series posts=posts.clone() actions /> })
+                                .collect::<Vec<_>>()
+                                .into_any(),
+                            Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                        }
+                    })}
+                </Transition>
+            </Protected>
+        </section>
+    }
+}
@@ -0,0 +1,98 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, location::Url};
+
+use super::fields::SelectField;
+use crate::api::admin::tags::{AdminTagRow, MergeTags, list_admin_tags};
+use crate::components::{
+    common::FormError,
+    global::protected::{Protected, RequiredRole},
+};
+
+#[component]
+fn MergeForm(tags: Vec<AdminTagRow>, merge: ServerAction<MergeTags>) -> impl IntoView {
+    let options = tags
+        .into_iter()
+        .map(|tag| {
+            (
+                tag.id.to_string(),
+                format!("{} ({})", tag.name, tag.post_count),
+            )
+        })
+        .collect::<Vec<_>>();
+    let selected = options
+        .first()
+        .map(|(value, _)| value.clone())
+        .unwrap_or_default();
+
+    view! {
+        <ActionForm action=merge attr:class="admin-form">
+            <SelectField
+                label="Merge"
+                name="source_id"
+                options=options.clone()
+                selected=selected.clone()
+            />
+            <SelectField label="Into" name="target_id" options selected />
+            <button type="submit" disabled=move || merge.pending().get()>"Merge"</button>
+        </ActionForm>
+    }
+}
+
+#[component]
+fn TagsTable(tags: Vec<AdminTagRow>) -> impl IntoView {
+    view! {
+        <table class="admin-table">
+            <thead>
+                <tr>
+                    <th>"Tag"</th>
+                    <th>"Posts"</th>
+                </tr>
+            </thead>
+            <tbody>
+                {tags.into_iter().map(|tag| view! {
+                    <tr>
+                        <td>
+                            <A href=format!("/blog/tags/{}", Url::escape(&tag.name))>{tag.name}</A>
+                        </td>
+                        <td>{tag.post_count}</td>
+                    </tr>
+                }).collect::<Vec<_>>()}
+            </tbody>
+        </table>
+    }
+}
+
+/// Lists the common tags posts are linked to, merging duplicates.
+#[component]
+pub fn AdminTagsPage() -> impl IntoView {
+    let merge = ServerAction::<MergeTags>::new();
+    let error = Signal::derive(move || merge.value().get().and_then(Result::err));
+    let tags = Resource::new(move || merge.version().get(), |_| list_admin_tags());
+
+    view! {
+        <section class="admin">
+            <A href="/admin">"← Admin"</A>
+            <h2>"TAGS"</h2>
+            <Protected role=RequiredRole::Staff>
+                <p class="admin-message">
+                    "Tags are normalized when posts are saved. Merge the ones that still mean the same, posts saved with the merged tag get the other one."
+                </p>
+                <FormError error />
+                <Transition fallback=|| view! { <p class="admin-message">"Loading..."</p> }>
+                    {move || Suspend::new(async move {
+                        match tags.await {
+                            Ok(tags) if tags.is_empty() => {
+                                view! { <p class="admin-message">"No tags yet."</p> }.into_any()
+                            }
+                            Ok(tags) => view! {
+                                {(tags.len() > 1).then(|| view! { <MergeForm tags=tags.clone() merge /> })}
+                                <TagsTable tags />
+                            }.into_any(),
+                            Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                        }
+                    })}
+                </Transition>
+            </Protected>
+        </section>
+    }
+}
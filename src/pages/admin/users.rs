@@ -0,0 +1,237 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map};
+
+use crate::api::moderation::{
+    LiftSuspension, ModeratedUser, SaveSuspension, SuspensionItem, UpdateUserRoles,
+    get_moderated_user, list_users,
+};
+use crate::components::{
+    common::FormError,
+    global::protected::{Protected, RequiredRole},
+};
+
+fn roles(user: &ModeratedUser) -> &'static str {
+    match (user.is_admin, user.is_staff) {
+        (true, _) => "Admin",
+        (false, true) => "Staff",
+        (false, false) => "User",
+    }
+}
+
+fn suspension_status(suspension: Option<&SuspensionItem>) -> &'static str {
+    match suspension {
+        Some(suspension) if suspension.in_effect && suspension.is_banned => "Banned",
+        Some(suspension) if suspension.in_effect => "Suspended",
+        _ => "Active",
+    }
+}
+
+#[component]
+fn UsersTable() -> impl IntoView {
+    let users = Resource::new(|| (), |_| list_users());
+
+    view! {
+        <Suspense fallback=|| view! { <p class="admin-message">"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match users.await {
+                    Ok(users) => view! {
+                        <table class="admin-table">
+                            <thead>
+                                <tr>
+                                    <th>"Name"</th>
+                                    <th>"Email"</th>
+                                    <th>"Role"</th>
+                                    <th>"Joined"</th>
+                                    <th>"Status"</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {users.into_iter().map(|user| {
+                                    let role = roles(&user);
+                                    let status = suspension_status(user.suspension.as_ref());
+                                    view! {
+                                        <tr>
+                                            <td>
+                                                <A href=format!("/admin/users/{}", user.id)>{user.name}</A>
+                                            </td>
+                                            <td>{user.email}</td>
+                                            <td>{role}</td>
+                                            <td>{user.joined_at}</td>
+                                            <td>{status}</td>
+                                        </tr>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </tbody>
+                        </table>
+                    }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn AdminUsersPage() -> impl IntoView {
+    view! {
+        <section class="admin">
+            <h2>"USERS"</h2>
+            <Protected role=RequiredRole::Admin>
+                <UsersTable />
+            </Protected>
+        </section>
+    }
+}
+
+#[component]
+fn RolesForm(user: ModeratedUser) -> impl IntoView {
+    let update = use_context::<ServerAction<UpdateUserRoles>>().expect("roles action provided");
+    let error = Signal::derive(move || update.value().get().and_then(Result::err));
+
+    view! {
+        <FormError error />
+        <ActionForm action=update attr:class="admin-form">
+            <input type="hidden" name="id" value=user.id />
+            <input type="hidden" name="version" value=user.version />
+            <label class="admin-checkbox">
+                <input type="checkbox" name="is_active" value="true" checked=user.is_active />
+                "Active"
+            </label>
+            <label class="admin-checkbox">
+                <input type="checkbox" name="is_staff" value="true" checked=user.is_staff />
+                "Staff"
+            </label>
+            <label class="admin-checkbox">
+                <input type="checkbox" name="is_admin" value="true" checked=user.is_admin />
+                "Admin"
+            </label>
+            <button type="submit" disabled=move || update.pending().get()>"Save roles"</button>
+        </ActionForm>
+    }
+}
+
+#[component]
+fn SuspensionForm(user_id: i32, suspension: Option<SuspensionItem>) -> impl IntoView {
+    let save = use_context::<ServerAction<SaveSuspension>>().expect("save action provided");
+    let lift = use_context::<ServerAction<LiftSuspension>>().expect("lift action provided");
+    let error = Signal::derive(move || {
+        let save_error = save.value().get().and_then(Result::err);
+        let lift_error = lift.value().get().and_then(Result::err);
+        save_error.or(lift_error)
+    });
+    let is_active = suspension
+        .as_ref()
+        .is_some_and(|suspension| suspension.is_active);
+    let version = suspension.as_ref().map(|suspension| suspension.version);
+    let (until, reason, is_banned) = suspension
+        .map(|suspension| {
+            (
+                suspension.suspended_until,
+                suspension.reason,
+                suspension.is_banned,
+            )
+        })
+        .unwrap_or_default();
+
+    view! {
+        <FormError error />
+        <ActionForm action=save attr:class="admin-form">
+            <input type="hidden" name="user_id" value=user_id />
+            // omitted until the user has a suspension row
+            {version.map(|version| view! { <input type="hidden" name="version" value=version /> })}
+            <label>
+                "Suspended until (UTC)"
+                <input type="datetime-local" name="suspended_until" value=until />
+            </label>
+            <label>
+                "Reason"
+                <textarea name="reason" rows="4" required>{reason}</textarea>
+            </label>
+            <label class="admin-checkbox">
+                <input type="checkbox" name="is_banned" value="true" checked=is_banned />
+                "Ban permanently"
+            </label>
+            <button type="submit" disabled=move || save.pending().get()>
+                {if is_active { "Update suspension" } else { "Suspend" }}
+            </button>
+        </ActionForm>
+        <Show when=move || is_active>
+            <ActionForm action=lift attr:class="admin-form">
+                <input type="hidden" name="user_id" value=user_id />
+                <input type="hidden" name="version" value=version.unwrap_or_default() />
+                <button type="submit" class="secondary" disabled=move || lift.pending().get()>
+                    "Lift suspension"
+                </button>
+            </ActionForm>
+        </Show>
+    }
+}
+
+#[component]
+fn UserModeration() -> impl IntoView {
+    let params = use_params_map();
+    let save = ServerAction::<SaveSuspension>::new();
+    let lift = ServerAction::<LiftSuspension>::new();
+    let update = ServerAction::<UpdateUserRoles>::new();
+    provide_context(save);
+    provide_context(lift);
+    provide_context(update);
+
+    let user = Resource::new(
+        move || {
+            (
+                params
+                    .read()
+                    .get("id")
+                    .and_then(|id| id.parse::<i32>().ok()),
+                // reload after every submission, also picking up the latest
+                // version after a conflict
+                save.version().get(),
+                lift.version().get(),
+                update.version().get(),
+            )
+        },
+        |(id, ..)| get_moderated_user(id.unwrap_or_default()),
+    );
+
+    view! {
+        <Suspense fallback=|| view! { <p class="admin-message">"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match user.await {
+                    Ok(user) => {
+                        let role = roles(&user);
+                        let status = suspension_status(user.suspension.as_ref());
+                        let name = user.name.clone();
+                        let details = format!(
+                            "{} · {role} · joined {} · {status}",
+                            user.email, user.joined_at
+                        );
+                        let user_id = user.id;
+                        let suspension = user.suspension.clone();
+                        view! {
+                            <h3>{name}</h3>
+                            <p class="admin-message">{details}</p>
+                            <h3>"Roles"</h3>
+                            <RolesForm user />
+                            <h3>"Suspension"</h3>
+                            <SuspensionForm user_id suspension />
+                        }.into_any()
+                    }
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn AdminUserPage() -> impl IntoView {
+    view! {
+        <section class="admin">
+            <A href="/admin/users">"← Users"</A>
+            <Protected role=RequiredRole::Admin>
+                <UserModeration />
+            </Protected>
+        </section>
+    }
+}
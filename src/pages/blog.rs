@@ -0,0 +1,72 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_query_map};
+
+use crate::api::blog::{list_featured_posts, list_posts};
+use crate::components::blog::{PostCard, SeriesSidebar, TagCloud};
+
+#[component]
+fn FeaturedPosts() -> impl IntoView {
+    let posts = Resource::new(|| (), |_| list_featured_posts());
+
+    view! {
+        <Suspense>
+            {move || Suspend::new(async move {
+                let posts = posts.await.unwrap_or_default();
+                (!posts.is_empty()).then(|| view! {
+                    <section class="featured-posts">
+                        <h3>"Featured"</h3>
+                        {posts.into_iter().map(|post| view! { <PostCard post /> }).collect::<Vec<_>>()}
+                    </section>
+                })
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn BlogPage() -> impl IntoView {
+    let query = use_query_map();
+    let cursor = Memo::new(move |_| query.read().get("after"));
+    let page = Resource::new(move || cursor.get(), list_posts);
+
+    view! {
+        <section class="blog">
+            <h2>"BLOG"</h2>
+            <div class="blog-layout">
+                <div class="blog-posts">
+                    {move || cursor.get().is_none().then(|| view! { <FeaturedPosts /> })}
+                    <Suspense fallback=|| view! { <p class="blog-message">"Loading..."</p> }>
+                        {move || Suspend::new(async move {
+                            match page.await {
+                                Ok(page) => view! {
+                                    {page.posts.is_empty().then(|| view! {
+                                        <p class="blog-message">"No posts yet."</p>
+                                    })}
+                                    {page.posts.into_iter().map(|post| view! { <PostCard post /> }).collect::<Vec<_>>()}
+                                    <nav class="blog-pagination">
+                                        {cursor.get_untracked().is_some().then(|| view! {
+                                            <A href="/blog">"Latest posts"</A>
+                                        })}
+                                        {page.next_cursor.map(|next| view! {
+                                            <A href=format!("/blog?after={next}")>"Older posts"</A>
+                                        })}
+                                    </nav>
+                                }.into_any(),
+                                Err(err) => {
+                                    err.set_response_status();
+                                    view! {
+                                        <p class="blog-message">"Unable to load posts right now."</p>
+                                    }.into_any()
+                                }
+                            }
+                        })}
+                    </Suspense>
+                </div>
+                <div class="blog-sidebar">
+                    <SeriesSidebar />
+                    <TagCloud />
+                </div>
+            </div>
+        </section>
+    }
+}
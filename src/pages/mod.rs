@@ -4,12 +4,40 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+mod admin;
+mod blog;
 mod cv;
 mod home;
+mod portfolio;
+mod post;
 mod privacy_policy;
+mod profile;
+mod project;
+mod search;
+mod series;
+mod sign_in;
+mod suspension;
+mod tag;
+mod technology;
 mod terms_and_conditions;
 
+pub use admin::{
+    AdminAuditPage, AdminContentEditPage, AdminContentPage, AdminImportPage, AdminPage,
+    AdminPostEditPage, AdminPostsPage, AdminSeriesPage, AdminTagsPage, AdminUserPage,
+    AdminUsersPage,
+};
+pub use blog::BlogPage;
 pub use cv::CvPage;
 pub use home::HomePage;
+pub use portfolio::PortfolioPage;
+pub use post::PostPage;
 pub use privacy_policy::PrivacyPolicy;
+pub use profile::ProfilePage;
+pub use project::ProjectPage;
+pub use search::SearchPage;
+pub use series::SeriesPage;
+pub use sign_in::SignInPage;
+pub use suspension::SuspensionPage;
+pub use tag::TagPage;
+pub use technology::TechnologyPage;
 pub use terms_and_conditions::TermsAndConditions;
@@ -0,0 +1,52 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_query_map;
+
+use crate::api::portfolio::{ProjectStatus, list_projects};
+use crate::components::portfolio::{ProjectCard, ProjectFilters};
+
+#[component]
+pub fn PortfolioPage() -> impl IntoView {
+    let query = use_query_map();
+    let technology = Memo::new(move |_| {
+        query
+            .read()
+            .get("technology")
+            .and_then(|value| value.parse::<i32>().ok())
+    });
+    let status = Memo::new(move |_| {
+        query
+            .read()
+            .get("status")
+            .and_then(|value| value.parse::<ProjectStatus>().ok())
+    });
+    let projects = Resource::new(
+        move || (technology.get(), status.get()),
+        |(technology, status)| list_projects(technology, status),
+    );
+
+    view! {
+        <section class="portfolio">
+            <h2>"PORTFOLIO"</h2>
+            <ProjectFilters technology status />
+            <Suspense fallback=|| view! { <p class="portfolio-message">"Loading..."</p> }>
+                {move || Suspend::new(async move {
+                    match projects.await {
+                        Ok(projects) if projects.is_empty() => view! {
+                            <p class="portfolio-message">"No projects match these filters."</p>
+                        }.into_any(),
+                        Ok(projects) => view! {
+                            <div class="project-grid">
+                                {projects.into_iter().map(|project| {
+                                    view! { <ProjectCard project /> }
+                                }).collect::<Vec<_>>()}
+                            </div>
+                        }.into_any(),
+                        Err(_) => view! {
+                            <p class="portfolio-message">"Unable to load projects right now."</p>
+                        }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </section>
+    }
+}
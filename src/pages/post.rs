@@ -0,0 +1,118 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map, location::Url};
+
+use crate::api::blog::{PostDetail, SeriesNavigation, TocEntry, get_post};
+use crate::components::{
+    blog::{Comments, PostReactions},
+    common::ResponsiveImage,
+};
+
+#[component]
+fn TableOfContents(#[prop(into)] toc: Vec<TocEntry>) -> impl IntoView {
+    view! {
+        <nav class="post-toc">
+            <h3>"Contents"</h3>
+            <ul>
+                {toc.into_iter().map(|entry| {
+                    view! {
+                        <li class=format!("toc-level-{}", entry.level)>
+                            <a href=format!("#{}", entry.id)>{entry.title}</a>
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+        </nav>
+    }
+}
+
+#[component]
+fn Tags(tags: Vec<String>) -> impl IntoView {
+    view! {
+        <ul class="post-tags">
+            {tags.into_iter().map(|tag| {
+                view! {
+                    <li>
+                        <A href=format!("/blog/tags/{}", Url::escape(&tag))>{format!("#{tag}")}</A>
+                    </li>
+                }
+            }).collect::<Vec<_>>()}
+        </ul>
+    }
+}
+
+#[component]
+fn SeriesNav(navigation: SeriesNavigation) -> impl IntoView {
+    view! {
+        <nav class="series-nav">
+            <p>
+                <A href=format!("/blog/series/{}", navigation.series_id)>{navigation.series_title}</A>
+                <span class="series-position">
+                    {format!(" · Part {} of {}", navigation.position, navigation.post_count)}
+                </span>
+            </p>
+            <div class="series-nav-links">
+                {navigation.previous.map(|post| view! {
+                    <A href=format!("/blog/{}", post.slug) attr:class="series-previous">
+                        {format!("← {}", post.title)}
+                    </A>
+                })}
+                {navigation.next.map(|post| view! {
+                    <A href=format!("/blog/{}", post.slug) attr:class="series-next">
+                        {format!("{} →", post.title)}
+                    </A>
+                })}
+            </div>
+        </nav>
+    }
+}
+
+#[component]
+fn Post(post: PostDetail) -> impl IntoView {
+    let has_toc = !post.toc.is_empty();
+    let has_tags = !post.tags.is_empty();
+    let post_id = post.id;
+
+    view! {
+        <article class="post">
+            {post.cover_image_url.map(|url| {
+                view! { <ResponsiveImage class="post-cover" src=url alt=post.title.clone() /> }
+            })}
+            <h2>{post.title.clone()}</h2>
+            <p class="date">{post.published_at}</p>
+            {has_tags.then(|| view! { <Tags tags=post.tags /> })}
+            {has_toc.then(|| view! { <TableOfContents toc=post.toc /> })}
+            <div class="post-content" inner_html=post.html></div>
+            {post.series.into_iter().map(|navigation| view! { <SeriesNav navigation /> }).collect::<Vec<_>>()}
+            <PostReactions post_id />
+        </article>
+        <Comments post_id />
+    }
+}
+
+#[component]
+pub fn PostPage() -> impl IntoView {
+    let params = use_params_map();
+    let post = Resource::new(
+        move || params.read().get("slug").unwrap_or_default(),
+        get_post,
+    );
+
+    view! {
+        <section class="blog">
+            <Suspense fallback=|| view! { <p class="blog-message">"Loading..."</p> }>
+                {move || Suspend::new(async move {
+                    match post.await {
+                        Ok(post) => view! { <Post post /> }.into_any(),
+                        Err(err) => {
+                            err.set_response_status();
+                            view! {
+                                <p class="blog-message">{err.to_string()}</p>
+                                <A href="/blog">"Back to the blog"</A>
+                            }.into_any()
+                        }
+                    }
+                })}
+            </Suspense>
+        </section>
+    }
+}
@@ -0,0 +1,76 @@
+use leptos::prelude::*;
+
+use crate::api::profile::{Profile, UpdateProfile, get_profile};
+use crate::components::{
+    common::FormError,
+    global::{auth::refresh_auth_state, protected::Protected},
+};
+
+#[component]
+fn ProfileForm(profile: Profile, action: ServerAction<UpdateProfile>) -> impl IntoView {
+    let error = Signal::derive(move || action.value().get().and_then(Result::err));
+    let saved = move || matches!(action.value().get(), Some(Ok(_)));
+
+    view! {
+        <FormError error />
+        <Show when=saved>
+            <p class="admin-message">"Profile saved."</p>
+        </Show>
+        <ActionForm action attr:class="admin-form">
+            <input type="hidden" name="version" value=profile.version />
+            <label>
+                "Name"
+                <input type="text" name="name" maxlength="200" required value=profile.name />
+            </label>
+            <label>
+                "Email"
+                <input type="email" value=profile.email disabled />
+            </label>
+            <button type="submit" disabled=move || action.pending().get()>"Save"</button>
+        </ActionForm>
+        <p class="admin-message">
+            {format!(
+                "Joined {} · signs in with {}",
+                profile.joined_at,
+                profile.providers.join(", "),
+            )}
+        </p>
+    }
+}
+
+#[component]
+fn ProfileDetails() -> impl IntoView {
+    let action = ServerAction::<UpdateProfile>::new();
+    // reloads after every save, so a conflict shows the latest name and version
+    let profile = Resource::new(move || action.version().get(), |_| get_profile());
+
+    // the navbar shows the first name
+    Effect::new(move |_| {
+        if matches!(action.value().get(), Some(Ok(_))) {
+            refresh_auth_state();
+        }
+    });
+
+    view! {
+        <Suspense fallback=|| view! { <p class="admin-message">"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match profile.await {
+                    Ok(profile) => view! { <ProfileForm profile action /> }.into_any(),
+                    Err(err) => view! { <p class="admin-message">{err.to_string()}</p> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn ProfilePage() -> impl IntoView {
+    view! {
+        <section class="admin">
+            <h2>"PROFILE"</h2>
+            <Protected>
+                <ProfileDetails />
+            </Protected>
+        </section>
+    }
+}
@@ -0,0 +1,111 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map};
+
+use crate::api::portfolio::{ImageGroup, ProjectDetail, RelatedProject, get_project};
+use crate::components::{
+    common::ResponsiveImage,
+    portfolio::{ProjectLinks, StatusBadge, TechnologyIcons},
+};
+
+#[component]
+fn Gallery(#[prop(into)] gallery: Vec<ImageGroup>, #[prop(into)] title: String) -> impl IntoView {
+    view! {
+        <section class="project-gallery">
+            {gallery.into_iter().map(|group| {
+                let title = title.clone();
+                view! {
+                    <div class="project-gallery-group">
+                        <h3>{group.image_type}</h3>
+                        <div class="project-gallery-images">
+                            {group.images.into_iter().map(|url| {
+                                view! {
+                                    <ResponsiveImage src=url alt=title.clone() sizes="(min-width: 768px) 33vw, 100vw" lazy=true />
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+                    </div>
+                }
+            }).collect::<Vec<_>>()}
+        </section>
+    }
+}
+
+#[component]
+fn RelatedProjects(#[prop(into)] related: Vec<RelatedProject>) -> impl IntoView {
+    view! {
+        <section class="related-projects">
+            <h3>"Related Projects"</h3>
+            <ul>
+                {related.into_iter().map(|project| {
+                    view! {
+                        <li>
+                            <A href=format!("/portfolio/{}", project.slug)>{project.title}</A>
+                            <StatusBadge status=project.status />
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+        </section>
+    }
+}
+
+#[component]
+fn Project(project: ProjectDetail) -> impl IntoView {
+    let has_gallery = !project.gallery.is_empty();
+    let has_related = !project.related.is_empty();
+
+    view! {
+        <article class="project">
+            <div class="project-header">
+                <h2>{project.title.clone()}</h2>
+                <StatusBadge status=project.status />
+            </div>
+            {project.subtitle.map(|subtitle| view! { <h4>{subtitle}</h4> })}
+            <ul class="project-dates">
+                <li>"Started " {project.start_date}</li>
+                {project.end_date.map(|date| view! { <li>"Finished " {date}</li> })}
+                {project.drop_date.map(|date| view! { <li>"Dropped " {date}</li> })}
+            </ul>
+            <TechnologyIcons technologies=project.technologies />
+            <ProjectLinks links=project.links />
+            <div class="project-description">
+                {project.description
+                    .split("\n\n")
+                    .map(str::trim)
+                    .filter(|paragraph| !paragraph.is_empty())
+                    .map(|paragraph| view! { <p>{paragraph.to_string()}</p> })
+                    .collect::<Vec<_>>()}
+            </div>
+            {has_gallery.then(|| view! { <Gallery gallery=project.gallery title=project.title /> })}
+            {has_related.then(|| view! { <RelatedProjects related=project.related /> })}
+        </article>
+    }
+}
+
+#[component]
+pub fn ProjectPage() -> impl IntoView {
+    let params = use_params_map();
+    let project = Resource::new(
+        move || params.read().get("slug").unwrap_or_default(),
+        get_project,
+    );
+
+    view! {
+        <section class="portfolio">
+            <Suspense fallback=|| view! { <p class="portfolio-message">"Loading..."</p> }>
+                {move || Suspend::new(async move {
+                    match project.await {
+                        Ok(project) => view! { <Project project /> }.into_any(),
+                        Err(err) => {
+                            err.set_response_status();
+                            view! {
+                                <p class="portfolio-message">{err.to_string()}</p>
+                                <A href="/portfolio">"Back to portfolio"</A>
+                            }.into_any()
+                        }
+                    }
+                })}
+            </Suspense>
+        </section>
+    }
+}
@@ -0,0 +1,90 @@
+use leptos::prelude::*;
+use leptos_router::{
+    components::{A, Form},
+    hooks::use_query_map,
+};
+
+use crate::api::search::{QUERY_MAX_LENGTH, SearchGroup, SearchHit, SnippetPart, search};
+
+#[component]
+fn Snippet(parts: Vec<SnippetPart>) -> impl IntoView {
+    view! {
+        <p class="search-snippet">
+            {parts.into_iter().map(|part| {
+                if part.highlighted {
+                    view! { <mark>{part.text}</mark> }.into_any()
+                } else {
+                    part.text.into_any()
+                }
+            }).collect::<Vec<_>>()}
+        </p>
+    }
+}
+
+#[component]
+fn Hit(hit: SearchHit) -> impl IntoView {
+    view! {
+        <li class="search-hit">
+            <A href=hit.url>{hit.title}</A>
+            <Snippet parts=hit.snippet />
+        </li>
+    }
+}
+
+#[component]
+fn Group(group: SearchGroup) -> impl IntoView {
+    view! {
+        <section class="search-group">
+            <h3>{group.kind.plural_label()}</h3>
+            <ul>
+                {group.hits.into_iter().map(|hit| view! { <Hit hit /> }).collect::<Vec<_>>()}
+            </ul>
+        </section>
+    }
+}
+
+#[component]
+pub fn SearchPage() -> impl IntoView {
+    let query = use_query_map();
+    let q = Memo::new(move |_| query.read().get("q").unwrap_or_default());
+    let groups = Resource::new(move || q.get(), search);
+
+    view! {
+        <section class="search">
+            <h2>"SEARCH"</h2>
+            <Form method="GET" action="">
+                <div class="search-form">
+                    <input
+                        type="search"
+                        name="q"
+                        aria-label="Search"
+                        placeholder="Search posts, projects and technologies"
+                        maxlength=QUERY_MAX_LENGTH
+                        value=move || q.get()
+                    />
+                    <button type="submit">"Search"</button>
+                </div>
+            </Form>
+            <Suspense fallback=|| view! { <p class="search-message">"Searching..."</p> }>
+                {move || Suspend::new(async move {
+                    match groups.await {
+                        Ok(_) if q.get_untracked().trim().is_empty() => ().into_any(),
+                        Ok(groups) if groups.is_empty() => view! {
+                            <p class="search-message">
+                                {format!("Nothing matches \"{}\".", q.get_untracked().trim())}
+                            </p>
+                        }.into_any(),
+                        Ok(groups) => groups
+                            .into_iter()
+                            .map(|group| view! { <Group group /> })
+                            .collect::<Vec<_>>()
+                            .into_any(),
+                        Err(_) => view! {
+                            <p class="search-message">"Unable to search right now."</p>
+                        }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </section>
+    }
+}
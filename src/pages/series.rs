@@ -0,0 +1,72 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map};
+
+use crate::api::blog::{SeriesDetail, get_series};
+use crate::components::{
+    blog::{PostCard, SeriesSidebar},
+    common::ResponsiveImage,
+    portfolio::TechnologyIcons,
+};
+
+#[component]
+fn Series(series: SeriesDetail) -> impl IntoView {
+    let has_technologies = !series.technologies.is_empty();
+
+    view! {
+        <header class="series-header">
+            {series.cover_image_url.map(|url| {
+                view! { <ResponsiveImage class="post-cover" src=url alt=series.title.clone() /> }
+            })}
+            <h2>{series.title}</h2>
+            {series.subtitle.map(|subtitle| view! { <p class="series-subtitle">{subtitle}</p> })}
+            {has_technologies.then(|| view! { <TechnologyIcons technologies=series.technologies /> })}
+        </header>
+        <div class="blog-layout">
+            <div class="blog-posts">
+                {series.posts.is_empty().then(|| view! {
+                    <p class="blog-message">"No posts in this series yet."</p>
+                })}
+                <ol class="series-posts">
+                    {series.posts.into_iter().map(|post| view! {
+                        <li><PostCard post /></li>
+                    }).collect::<Vec<_>>()}
+                </ol>
+            </div>
+            <SeriesSidebar />
+        </div>
+    }
+}
+
+#[component]
+pub fn SeriesPage() -> impl IntoView {
+    let params = use_params_map();
+    let series = Resource::new(
+        move || {
+            params
+                .read()
+                .get("id")
+                .and_then(|id| id.parse::<i32>().ok())
+                .unwrap_or_default()
+        },
+        get_series,
+    );
+
+    view! {
+        <section class="blog">
+            <Suspense fallback=|| view! { <p class="blog-message">"Loading..."</p> }>
+                {move || Suspend::new(async move {
+                    match series.await {
+                        Ok(series) => view! { <Series series /> }.into_any(),
+                        Err(err) => {
+                            err.set_response_status();
+                            view! {
+                                <p class="blog-message">{err.to_string()}</p>
+                                <A href="/blog">"Back to the blog"</A>
+                            }.into_any()
+                        }
+                    }
+                })}
+            </Suspense>
+        </section>
+    }
+}
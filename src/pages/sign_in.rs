@@ -0,0 +1,71 @@
+use leptos::prelude::*;
+use leptos_router::{hooks::use_query_map, location::Url};
+
+use crate::api::auth::{OAuthProvider, list_sign_in_providers, safe_return_to};
+
+fn error_message(code: &str) -> &'static str {
+    match code {
+        "denied" => "Sign in was cancelled.",
+        "state" => "Your sign in attempt expired, please try again.",
+        "email" => "Your account needs a verified email address to sign in.",
+        "inactive" => "This account has been deactivated.",
+        "banned" => "This account has been banned.",
+        _ => "Unable to sign in right now, please try again later.",
+    }
+}
+
+#[component]
+fn ProviderButton(
+    provider: OAuthProvider,
+    #[prop(into)] return_to: Signal<String>,
+) -> impl IntoView {
+    let href = move || {
+        format!(
+            "{}?return_to={}",
+            provider.sign_in_url(),
+            Url::escape(&return_to.get())
+        )
+    };
+
+    view! {
+        // server route, so skip the client-side router
+        <a class=format!("sign-in-button {}", provider.as_str()) href=href rel="external">
+            {format!("Continue with {}", provider.label())}
+        </a>
+    }
+}
+
+#[component]
+pub fn SignInPage() -> impl IntoView {
+    let query = use_query_map();
+    let return_to =
+        Signal::derive(move || safe_return_to(query.read().get("return_to").as_deref()));
+    let error = move || query.read().get("error").map(|code| error_message(&code));
+    let providers = Resource::new(|| (), |_| list_sign_in_providers());
+
+    view! {
+        <section class="sign-in">
+            <h2>"SIGN IN"</h2>
+            {move || error().map(|message| view! { <p class="sign-in-error">{message}</p> })}
+            <Suspense fallback=|| view! { <p class="sign-in-message">"Loading..."</p> }>
+                {move || Suspend::new(async move {
+                    match providers.await {
+                        Ok(providers) if providers.is_empty() => view! {
+                            <p class="sign-in-message">"Sign in is currently unavailable."</p>
+                        }.into_any(),
+                        Ok(providers) => view! {
+                            <div class="sign-in-providers">
+                                {providers.into_iter().map(|provider| {
+                                    view! { <ProviderButton provider return_to /> }
+                                }).collect::<Vec<_>>()}
+                            </div>
+                        }.into_any(),
+                        Err(_) => view! {
+                            <p class="sign-in-message">"Sign in is currently unavailable."</p>
+                        }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </section>
+    }
+}
@@ -0,0 +1,51 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::api::{ApiError, moderation::get_my_suspension};
+
+#[component]
+pub fn SuspensionPage() -> impl IntoView {
+    let suspension = Resource::new(|| (), |_| get_my_suspension());
+
+    view! {
+        <section class="suspension">
+            <h2>"ACCOUNT STATUS"</h2>
+            <Suspense fallback=|| view! { <p class="suspension-message">"Loading..."</p> }>
+                {move || Suspend::new(async move {
+                    match suspension.await {
+                        Ok(None) => view! {
+                            <p class="suspension-message">"Your account is in good standing."</p>
+                        }.into_any(),
+                        Ok(Some(notice)) => {
+                            let headline = match notice.until {
+                                Some(until) => format!("Your account is suspended until {until}."),
+                                None => "Your account has been banned.".to_string(),
+                            };
+                            view! {
+                                <p class="suspension-headline">{headline}</p>
+                                <h3>"Reason"</h3>
+                                <p class="suspension-reason">{notice.reason}</p>
+                                <p class="suspension-message">
+                                    {if notice.is_banned {
+                                        "You can no longer sign in."
+                                    } else {
+                                        "Until then you can read the blog but not comment or react to posts."
+                                    }}
+                                </p>
+                            }.into_any()
+                        }
+                        Err(ApiError::Unauthorized(_)) => view! {
+                            <p class="suspension-message">
+                                <A href="/sign-in?return_to=/suspension">"Sign in"</A>
+                                " to see the status of your account."
+                            </p>
+                        }.into_any(),
+                        Err(_) => view! {
+                            <p class="suspension-message">"Unable to load your account status right now."</p>
+                        }.into_any(),
+                    }
+                })}
+            </Suspense>
+        </section>
+    }
+}
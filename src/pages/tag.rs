@@ -0,0 +1,47 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map};
+
+use crate::api::tags::list_tag_posts;
+use crate::components::blog::{PostCard, TagCloud};
+
+#[component]
+pub fn TagPage() -> impl IntoView {
+    let params = use_params_map();
+    let tag = Resource::new(
+        move || params.read().get("name").unwrap_or_default(),
+        list_tag_posts,
+    );
+
+    view! {
+        <section class="blog">
+            <Suspense fallback=|| view! { <p class="blog-message">"Loading..."</p> }>
+                {move || Suspend::new(async move {
+                    match tag.await {
+                        Ok(tag) => view! {
+                            <h2>{format!("#{}", tag.name)}</h2>
+                            <div class="blog-layout">
+                                <div class="blog-posts">
+                                    {tag.posts.is_empty().then(|| view! {
+                                        <p class="blog-message">"No posts with this tag yet."</p>
+                                    })}
+                                    {tag.posts.into_iter().map(|post| view! { <PostCard post /> }).collect::<Vec<_>>()}
+                                    <nav class="blog-pagination">
+                                        <A href="/blog">"All posts"</A>
+                                    </nav>
+                                </div>
+                                <TagCloud />
+                            </div>
+                        }.into_any(),
+                        Err(err) => {
+                            err.set_response_status();
+                            view! {
+                                <p class="blog-message">{err.to_string()}</p>
+                                <A href="/blog">"Back to the blog"</A>
+                            }.into_any()
+                        }
+                    }
+                })}
+            </Suspense>
+        </section>
+    }
+}
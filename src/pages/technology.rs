@@ -0,0 +1,109 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map};
+
+use crate::api::technologies::{TechnologyBadge, TechnologyDetail, TechnologyUse, get_technology};
+
+#[component]
+fn RelatedTechnologies(title: &'static str, technologies: Vec<TechnologyBadge>) -> impl IntoView {
+    (!technologies.is_empty()).then(|| {
+        view! {
+            <section class="technology-related">
+                <h3>{title}</h3>
+                <ul>
+                    {technologies.into_iter().map(|technology| view! {
+                        <li>
+                            <A href=format!("/technologies/{}", technology.id)>
+                                <img src=technology.icon alt="" />
+                                {technology.name}
+                            </A>
+                        </li>
+                    }).collect::<Vec<_>>()}
+                </ul>
+            </section>
+        }
+    })
+}
+
+#[component]
+fn Uses(title: &'static str, uses: Vec<TechnologyUse>) -> impl IntoView {
+    (!uses.is_empty()).then(|| {
+        view! {
+            <section class="technology-uses">
+                <h3>{title}</h3>
+                <ul>
+                    {uses.into_iter().map(|usage| view! {
+                        <li>
+                            <A href=usage.url>{usage.title}</A>
+                            {usage.details.map(|details| view! { <span class="details">{details}</span> })}
+                            {usage.via.map(|via| view! { <span class="via">{format!("via {via}")}</span> })}
+                        </li>
+                    }).collect::<Vec<_>>()}
+                </ul>
+            </section>
+        }
+    })
+}
+
+#[component]
+fn Technology(technology: TechnologyDetail) -> impl IntoView {
+    let is_unused = technology.projects.is_empty()
+        && technology.posts.is_empty()
+        && technology.series.is_empty()
+        && technology.skills.is_empty();
+
+    view! {
+        <header class="technology-header">
+            <img src=technology.icon alt="" />
+            <div>
+                <h2>{technology.name}</h2>
+                <p class="technology-type">{technology.tech_type.label()}</p>
+            </div>
+        </header>
+        {technology.description.map(|description| view! {
+            <p class="technology-description">{description}</p>
+        })}
+        <RelatedTechnologies title="Part of" technologies=technology.parents />
+        <RelatedTechnologies title="Includes" technologies=technology.children />
+        {is_unused.then(|| view! { <p class="blog-message">"Nothing uses it yet."</p> })}
+        <Uses title="Projects" uses=technology.projects />
+        <Uses title="Blog posts" uses=technology.posts />
+        <Uses title="Series" uses=technology.series />
+        <Uses title="Skills" uses=technology.skills />
+    }
+}
+
+/// A technology with the technologies around it in the graph, and what uses
+/// it or any technology it includes.
+#[component]
+pub fn TechnologyPage() -> impl IntoView {
+    let params = use_params_map();
+    let technology = Resource::new(
+        move || {
+            params
+                .read()
+                .get("id")
+                .and_then(|id| id.parse::<i32>().ok())
+                .unwrap_or_default()
+        },
+        get_technology,
+    );
+
+    view! {
+        <section class="technology">
+            <Suspense fallback=|| view! { <p class="blog-message">"Loading..."</p> }>
+                {move || Suspend::new(async move {
+                    match technology.await {
+                        Ok(technology) => view! { <Technology technology /> }.into_any(),
+                        Err(err) => {
+                            err.set_response_status();
+                            view! {
+                                <p class="blog-message">{err.to_string()}</p>
+                                <A href="/portfolio">"Back to the portfolio"</A>
+                            }.into_any()
+                        }
+                    }
+                })}
+            </Suspense>
+        </section>
+    }
+}
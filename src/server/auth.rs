@@ -0,0 +1,455 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Sign-in routes: `/auth/{provider}` sends the user to the identity provider
+//! and `/auth/{provider}/callback` creates or links the local user.
+
+use std::fmt;
+
+use actix_web::{
+    HttpRequest, HttpResponse,
+    cookie::{Cookie, CookieJar, Key, SameSite, time::Duration},
+    get,
+    http::header,
+    web,
+};
+use entity::{
+    auth_provider,
+    prelude::{AuthProvider, User},
+    user,
+};
+use reqwest::Client;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::api::auth::{OAuthProvider, safe_return_to};
+
+use super::{
+    config::{Config, OAuthProviderConfig},
+    guards::is_banned,
+    oauth::{self, OAuthError, OAuthProfile},
+    session::{SessionError, SessionStore, session_cookie},
+};
+
+/// Cookie holding the state and PKCE verifier between the redirect to the
+/// provider and its callback.
+const PENDING_COOKIE: &str = "oauth_pending";
+const PENDING_MAX_AGE: Duration = Duration::minutes(10);
+
+/// Cookie letting a banned user, who gets no session, see why on the
+/// suspension page.
+pub(crate) const SUSPENSION_NOTICE_COOKIE: &str = "suspension_notice";
+const SUSPENSION_NOTICE_MAX_AGE: Duration = Duration::minutes(10);
+
+/// Matches the `user.name` and `user.email` column lengths.
+const NAME_MAX_LENGTH: usize = 200;
+const EMAIL_MAX_LENGTH: usize = 250;
+
+#[derive(Debug)]
+enum SignInError {
+    /// The user refused to authorise the app.
+    Denied,
+    /// Missing or mismatching state, e.g. an expired or forged callback.
+    InvalidState,
+    OAuth(OAuthError),
+    Inactive,
+    /// Banned users are refused sessions.
+    Banned(i32),
+    Database(DbErr),
+    Session(SessionError),
+}
+
+impl SignInError {
+    /// Code shown to the user on the sign-in page.
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Denied => "denied",
+            Self::InvalidState => "state",
+            Self::OAuth(OAuthError::UnverifiedEmail) => "email",
+            Self::OAuth(_) => "provider",
+            Self::Inactive => "inactive",
+            Self::Banned(_) => "banned",
+            Self::Database(_) | Self::Session(_) => "unavailable",
+        }
+    }
+}
+
+impl fmt::Display for SignInError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Denied => write!(f, "authorisation denied by the user"),
+            Self::InvalidState => write!(f, "invalid or expired sign-in state"),
+            Self::OAuth(err) => write!(f, "{err}"),
+            Self::Inactive => write!(f, "user is not active"),
+            Self::Banned(id) => write!(f, "user {id} is banned"),
+            Self::Database(err) => write!(f, "database error: {err}"),
+            Self::Session(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<OAuthError> for SignInError {
+    fn from(err: OAuthError) -> Self {
+        Self::OAuth(err)
+    }
+}
+
+impl From<SessionError> for SignInError {
+    fn from(err: SessionError) -> Self {
+        Self::Session(err)
+    }
+}
+
+impl From<DbErr> for SignInError {
+    fn from(err: DbErr) -> Self {
+        Self::Database(err)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct PendingSignIn {
+    provider: OAuthProvider,
+    state: String,
+    verifier: String,
+    return_to: String,
+}
+
+/// Encrypts `cookie` with `key`, so its value can neither be read nor forged.
+fn encrypt(key: &Key, cookie: Cookie<'static>) -> Cookie<'static> {
+    let mut jar = CookieJar::new();
+    jar.private_mut(key).add(cookie.clone());
+    jar.get(cookie.name()).cloned().unwrap_or(cookie)
+}
+
+pub(crate) fn decrypt(req: &HttpRequest, key: &Key, name: &str) -> Option<Cookie<'static>> {
+    let mut jar = CookieJar::new();
+    jar.add_original(req.cookie(name)?);
+    jar.private(key).get(name)
+}
+
+fn secure(config: &Config) -> bool {
+    config.site.url.starts_with("https://")
+}
+
+fn pending_cookie(config: &Config, pending: &PendingSignIn) -> Option<Cookie<'static>> {
+    let value = serde_json::to_string(pending).ok()?;
+    let cookie = Cookie::build(PENDING_COOKIE, value)
+        .path("/auth")
+        .http_only(true)
+        .secure(secure(config))
+        // sent along with the top-level redirect back from the provider
+        .same_site(SameSite::Lax)
+        .max_age(PENDING_MAX_AGE)
+        .finish();
+    Some(encrypt(&config.auth.cookie_key, cookie))
+}
+
+/// Sign-in started by the user, unless its cookie is missing, expired or was
+/// tampered with.
+fn pending_sign_in(req: &HttpRequest, key: &Key) -> Option<PendingSignIn> {
+    let cookie = decrypt(req, key, PENDING_COOKIE)?;
+    serde_json::from_str(cookie.value()).ok()
+}
+
+fn clear_pending_cookie() -> Cookie<'static> {
+    let mut cookie = Cookie::build(PENDING_COOKIE, "").path("/auth").finish();
+    cookie.make_removal();
+    cookie
+}
+
+fn suspension_notice_cookie(config: &Config, user_id: i32) -> Cookie<'static> {
+    let cookie = Cookie::build(SUSPENSION_NOTICE_COOKIE, user_id.to_string())
+        .path("/")
+        .http_only(true)
+        .secure(secure(config))
+        .same_site(SameSite::Lax)
+        .max_age(SUSPENSION_NOTICE_MAX_AGE)
+        .finish();
+    encrypt(&config.auth.cookie_key, cookie)
+}
+
+/// Banned user whose sign-in was just refused, if any.
+pub(crate) fn suspension_notice_user(req: &HttpRequest, key: &Key) -> Option<i32> {
+    decrypt(req, key, SUSPENSION_NOTICE_COOKIE)?
+        .value()
+        .parse()
+        .ok()
+}
+
+fn redirect_uri(config: &Config, provider: OAuthProvider) -> String {
+    format!("{}/auth/{}/callback", config.site.url, provider.as_str())
+}
+
+fn redirect(location: &str) -> actix_web::HttpResponseBuilder {
+    let mut response = HttpResponse::Found();
+    response.insert_header((header::LOCATION, location));
+    response
+}
+
+fn enabled_provider<'a>(
+    config: &'a Config,
+    name: &str,
+) -> Option<(OAuthProvider, &'a OAuthProviderConfig)> {
+    let provider = name.parse::<OAuthProvider>().ok()?;
+    Some((provider, config.auth.provider(provider)?))
+}
+
+/// Finds the user with the profile's email, creating it on first sign-in, and
+/// records that it signs in with `provider`.
+pub async fn find_or_link_user(
+    db: &DatabaseConnection,
+    provider: OAuthProvider,
+    profile: OAuthProfile,
+) -> Result<user::Model, DbErr> {
+    let txn = db.begin().await?;
+
+    let existing = User::find()
+        .filter(user::Column::Email.eq(&profile.email))
+        .one(&txn)
+        .await?;
+    let user = match existing {
+        Some(user) => user,
+        None => {
+            user::ActiveModel {
+                name: Set(profile.name.chars().take(NAME_MAX_LENGTH).collect()),
+                email: Set(profile.email),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await?
+        }
+    };
+
+    let linked = AuthProvider::find()
+        .filter(auth_provider::Column::UserId.eq(user.id))
+        .filter(auth_provider::Column::Name.eq(provider.as_str()))
+        .one(&txn)
+        .await?
+        .is_some();
+    if !linked {
+        auth_provider::ActiveModel {
+            name: Set(provider.as_str().to_string()),
+            user_id: Set(user.id),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+    }
+
+    txn.commit().await?;
+    Ok(user)
+}
+
+#[derive(Deserialize)]
+struct SignInQuery {
+    return_to: Option<String>,
+}
+
+#[get("/auth/{provider}")]
+pub async fn sign_in(
+    path: web::Path<String>,
+    query: web::Query<SignInQuery>,
+    config: web::Data<Config>,
+) -> HttpResponse {
+    let Some((provider, provider_config)) = enabled_provider(&config, &path) else {
+        return HttpResponse::NotFound().finish();
+    };
+
+    let pending = PendingSignIn {
+        provider,
+        state: oauth::random_token(),
+        verifier: oauth::random_token(),
+        return_to: safe_return_to(query.return_to.as_deref()),
+    };
+    let url = oauth::authorize_url(
+        provider,
+        provider_config,
+        &redirect_uri(&config, provider),
+        &pending.state,
+        &pending.verifier,
+    );
+
+    match (url, pending_cookie(&config, &pending)) {
+        (Ok(url), Some(cookie)) => redirect(url.as_str()).cookie(cookie).finish(),
+        (Err(err), _) => {
+            log::error!("cannot start {} sign-in: {err}", provider.as_str());
+            redirect("/sign-in?error=provider").finish()
+        }
+        (_, None) => redirect("/sign-in?error=unavailable").finish(),
+    }
+}
+
+#[derive(Deserialize)]
+struct CallbackQuery {
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+}
+
+/// Checks the callback against the pending sign-in and loads the user's
+/// profile, returning it along with where to send the user afterwards.
+async fn authenticate(
+    req: &HttpRequest,
+    provider: OAuthProvider,
+    provider_config: &OAuthProviderConfig,
+    query: CallbackQuery,
+    config: &Config,
+    client: &Client,
+) -> Result<(OAuthProfile, String), SignInError> {
+    if query.error.is_some() {
+        return Err(SignInError::Denied);
+    }
+
+    let pending = pending_sign_in(req, &config.auth.cookie_key)
+        .filter(|pending| pending.provider == provider)
+        .filter(|pending| query.state.as_deref() == Some(pending.state.as_str()))
+        .ok_or(SignInError::InvalidState)?;
+    let code = query.code.ok_or(SignInError::InvalidState)?;
+
+    let access_token = oauth::exchange_code(
+        client,
+        provider_config,
+        &redirect_uri(config, provider),
+        &code,
+        &pending.verifier,
+    )
+    .await?;
+    let profile = oauth::fetch_profile(client, provider, provider_config, &access_token).await?;
+    if profile.email.len() > EMAIL_MAX_LENGTH {
+        return Err(OAuthError::Provider("email too long".to_string()).into());
+    }
+
+    Ok((profile, pending.return_to))
+}
+
+#[get("/auth/{provider}/callback")]
+pub async fn callback(
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<CallbackQuery>,
+    config: web::Data<Config>,
+    db: web::Data<DatabaseConnection>,
+    client: web::Data<Client>,
+    sessions: web::Data<SessionStore>,
+) -> HttpResponse {
+    let Some((provider, provider_config)) = enabled_provider(&config, &path) else {
+        return HttpResponse::NotFound().finish();
+    };
+
+    let result = async {
+        let (profile, return_to) = authenticate(
+            &req,
+            provider,
+            provider_config,
+            query.into_inner(),
+            &config,
+            &client,
+        )
+        .await?;
+        let user = find_or_link_user(&db, provider, profile).await?;
+        if !user.is_active {
+            return Err(SignInError::Inactive);
+        }
+        if is_banned(db.get_ref(), user.id).await? {
+            return Err(SignInError::Banned(user.id));
+        }
+        let session_id = sessions.create(user.id).await?;
+        Ok((user, session_id, return_to))
+    }
+    .await;
+
+    match result {
+        Ok((user, session_id, return_to)) => {
+            log::info!("user {} signed in with {}", user.id, provider.as_str());
+            redirect(&return_to)
+                .cookie(clear_pending_cookie())
+                .cookie(session_cookie(&config, sessions.ttl(), &session_id))
+                .finish()
+        }
+        Err(SignInError::Banned(user_id)) => {
+            log::warn!(
+                "refused {} sign-in of banned user {user_id}",
+                provider.as_str()
+            );
+            redirect("/suspension")
+                .cookie(clear_pending_cookie())
+                .cookie(suspension_notice_cookie(&config, user_id))
+                .finish()
+        }
+        Err(err) => {
+            log::warn!("{} sign-in failed: {err}", provider.as_str());
+            redirect(&format!("/sign-in?error={}", err.code()))
+                .cookie(clear_pending_cookie())
+                .finish()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    fn pending() -> PendingSignIn {
+        PendingSignIn {
+            provider: OAuthProvider::Github,
+            state: oauth::random_token(),
+            verifier: oauth::random_token(),
+            return_to: "/blog".to_string(),
+        }
+    }
+
+    fn encrypted_pending(key: &Key, pending: &PendingSignIn) -> Cookie<'static> {
+        let value = serde_json::to_string(pending).unwrap();
+        encrypt(key, Cookie::new(PENDING_COOKIE, value))
+    }
+
+    #[test]
+    fn pending_sign_in_round_trips() {
+        let key = Key::generate();
+        let pending = pending();
+        let cookie = encrypted_pending(&key, &pending);
+        assert!(!cookie.value().contains(&pending.verifier));
+
+        let req = TestRequest::default().cookie(cookie).to_http_request();
+        let decrypted = pending_sign_in(&req, &key).unwrap();
+
+        assert_eq!(decrypted.provider, pending.provider);
+        assert_eq!(decrypted.state, pending.state);
+        assert_eq!(decrypted.verifier, pending.verifier);
+        assert_eq!(decrypted.return_to, pending.return_to);
+    }
+
+    #[test]
+    fn pending_sign_in_rejects_forged_cookies() {
+        let key = Key::generate();
+        let cookie = encrypted_pending(&key, &pending());
+
+        let other_key = TestRequest::default()
+            .cookie(cookie.clone())
+            .to_http_request();
+        assert!(pending_sign_in(&other_key, &Key::generate()).is_none());
+
+        let mut tampered = cookie.value().to_string();
+        tampered.replace_range(..1, if tampered.starts_with('A') { "B" } else { "A" });
+        let tampered = TestRequest::default()
+            .cookie(Cookie::new(PENDING_COOKIE, tampered))
+            .to_http_request();
+        assert!(pending_sign_in(&tampered, &key).is_none());
+
+        let plain = TestRequest::default()
+            .cookie(Cookie::new(
+                PENDING_COOKIE,
+                serde_json::to_string(&pending()).unwrap(),
+            ))
+            .to_http_request();
+        assert!(pending_sign_in(&plain, &key).is_none());
+    }
+}
@@ -0,0 +1,435 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{env, fmt, path::PathBuf, str::FromStr, time::Duration};
+
+use actix_web::cookie::Key;
+use leptos::prelude::*;
+
+use crate::api::auth::OAuthProvider;
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Missing(&'static str),
+    Invalid(&'static str, String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(name) => write!(f, "missing environment variable {name}"),
+            Self::Invalid(name, value) => {
+                write!(f, "invalid value {value:?} for environment variable {name}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+fn required(name: &'static str) -> Result<String, ConfigError> {
+    env::var(name).map_err(|_| ConfigError::Missing(name))
+}
+
+fn optional<T: FromStr>(name: &'static str, default: T) -> Result<T, ConfigError> {
+    match env::var(name) {
+        Ok(value) => value.parse().map_err(|_| ConfigError::Invalid(name, value)),
+        Err(_) => Ok(default),
+    }
+}
+
+/// What the server does with `migration::Migrator` before it starts listening.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MigrationMode {
+    /// Do not touch or inspect the schema.
+    Off,
+    /// Only check the schema, warning about pending migrations (safe mode).
+    #[default]
+    Verify,
+    /// Apply every pending migration.
+    Apply,
+}
+
+impl FromStr for MigrationMode {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "off" => Ok(Self::Off),
+            "verify" => Ok(Self::Verify),
+            "apply" => Ok(Self::Apply),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DatabaseConfig {
+    pub url: String,
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub connect_timeout: Duration,
+    pub migrations: MigrationMode,
+}
+
+impl DatabaseConfig {
+    fn from_env() -> Result<Self, ConfigError> {
+        Ok(Self {
+            url: required("DATABASE_URL")?,
+            max_connections: optional("DATABASE_MAX_CONNECTIONS", 10)?,
+            min_connections: optional("DATABASE_MIN_CONNECTIONS", 1)?,
+            connect_timeout: Duration::from_secs(optional("DATABASE_CONNECT_TIMEOUT", 8)?),
+            migrations: optional("DATABASE_MIGRATIONS", MigrationMode::default())?,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SiteConfig {
+    /// Email of the user whose CV, portfolio and blog the site shows.
+    pub owner_email: String,
+    /// Public base URL of the site, without a trailing slash.
+    pub url: String,
+}
+
+impl SiteConfig {
+    fn from_env() -> Result<Self, ConfigError> {
+        let url: String = optional("SITE_URL", "http://localhost:3000".to_string())?;
+
+        Ok(Self {
+            owner_email: required("SITE_OWNER_EMAIL")?,
+            url: url.trim_end_matches('/').to_string(),
+        })
+    }
+}
+
+/// Client credentials and endpoints of an OAuth 2.0 identity provider.
+///
+/// The endpoints default to the real provider but can be overridden, e.g. to
+/// point the sign-in flow at a local mock identity provider.
+#[derive(Clone)]
+pub struct OAuthProviderConfig {
+    pub client_id: String,
+    pub client_secret: String,
+    pub authorize_url: String,
+    pub token_url: String,
+    pub userinfo_url: String,
+    /// Endpoint listing the user's emails, for providers that may omit the
+    /// email from the user info response (GitHub).
+    pub emails_url: Option<String>,
+}
+
+impl fmt::Debug for OAuthProviderConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthProviderConfig")
+            .field("client_id", &self.client_id)
+            .field("authorize_url", &self.authorize_url)
+            .field("token_url", &self.token_url)
+            .field("userinfo_url", &self.userinfo_url)
+            .field("emails_url", &self.emails_url)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Reads a provider's client id and secret, a provider without either is
+/// disabled.
+fn credentials(
+    id_name: &'static str,
+    secret_name: &'static str,
+) -> Result<Option<(String, String)>, ConfigError> {
+    match (env::var(id_name).ok(), env::var(secret_name).ok()) {
+        (Some(id), Some(secret)) => Ok(Some((id, secret))),
+        (None, None) => Ok(None),
+        (Some(_), None) => Err(ConfigError::Missing(secret_name)),
+        (None, Some(_)) => Err(ConfigError::Missing(id_name)),
+    }
+}
+
+impl OAuthProviderConfig {
+    fn github() -> Result<Option<Self>, ConfigError> {
+        let Some((client_id, client_secret)) =
+            credentials("GITHUB_CLIENT_ID", "GITHUB_CLIENT_SECRET")?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self {
+            client_id,
+            client_secret,
+            authorize_url: optional(
+                "GITHUB_AUTHORIZE_URL",
+                "https://github.com/login/oauth/authorize".to_string(),
+            )?,
+            token_url: optional(
+                "GITHUB_TOKEN_URL",
+                "https://github.com/login/oauth/access_token".to_string(),
+            )?,
+            userinfo_url: optional(
+                "GITHUB_USERINFO_URL",
+                "https://api.github.com/user".to_string(),
+            )?,
+            emails_url: Some(optional(
+                "GITHUB_EMAILS_URL",
+                "https://api.github.com/user/emails".to_string(),
+            )?),
+        }))
+    }
+
+    fn google() -> Result<Option<Self>, ConfigError> {
+        let Some((client_id, client_secret)) =
+            credentials("GOOGLE_CLIENT_ID", "GOOGLE_CLIENT_SECRET")?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self {
+            client_id,
+            client_secret,
+            authorize_url: optional(
+                "GOOGLE_AUTHORIZE_URL",
+                "https://accounts.google.com/o/oauth2/v2/auth".to_string(),
+            )?,
+            token_url: optional(
+                "GOOGLE_TOKEN_URL",
+                "https://oauth2.googleapis.com/token".to_string(),
+            )?,
+            userinfo_url: optional(
+                "GOOGLE_USERINFO_URL",
+                "https://openidconnect.googleapis.com/v1/userinfo".to_string(),
+            )?,
+            emails_url: None,
+        }))
+    }
+}
+
+#[derive(Clone)]
+pub struct AuthConfig {
+    /// Key signing and encrypting the auth cookies, derived from `COOKIE_SECRET`.
+    pub cookie_key: Key,
+    pub github: Option<OAuthProviderConfig>,
+    pub google: Option<OAuthProviderConfig>,
+}
+
+impl fmt::Debug for AuthConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthConfig")
+            .field("github", &self.github)
+            .field("google", &self.google)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AuthConfig {
+    fn from_env() -> Result<Self, ConfigError> {
+        let secret = required("COOKIE_SECRET")?;
+        if secret.len() < 32 {
+            return Err(ConfigError::Invalid(
+                "COOKIE_SECRET",
+                "<at least 32 bytes required>".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            cookie_key: Key::derive_from(secret.as_bytes()),
+            github: OAuthProviderConfig::github()?,
+            google: OAuthProviderConfig::google()?,
+        })
+    }
+
+    pub fn provider(&self, provider: OAuthProvider) -> Option<&OAuthProviderConfig> {
+        match provider {
+            OAuthProvider::Github => self.github.as_ref(),
+            OAuthProvider::Google => self.google.as_ref(),
+        }
+    }
+
+    /// Providers with credentials configured, in the order they are offered.
+    pub fn enabled_providers(&self) -> Vec<OAuthProvider> {
+        OAuthProvider::ALL
+            .into_iter()
+            .filter(|provider| self.provider(*provider).is_some())
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SessionConfig {
+    /// Valkey/Redis URL, sessions are kept in memory when unset.
+    pub redis_url: Option<String>,
+    /// How long a session lives without requests.
+    pub ttl: Duration,
+}
+
+impl SessionConfig {
+    fn from_env() -> Result<Self, ConfigError> {
+        Ok(Self {
+            redis_url: env::var("REDIS_URL").ok(),
+            ttl: Duration::from_secs(optional("SESSION_TTL", 7 * 24 * 60 * 60)?),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CommentsConfig {
+    /// Deepest reply level, top-level comments being at depth 0.
+    pub max_depth: usize,
+    /// Maximum length of a comment, in characters.
+    pub max_length: usize,
+    /// How long authors can edit their comments after posting them.
+    pub edit_window: Duration,
+    /// How many comments a user can post per `rate_window`.
+    pub rate_limit: u64,
+    pub rate_window: Duration,
+}
+
+impl CommentsConfig {
+    fn from_env() -> Result<Self, ConfigError> {
+        Ok(Self {
+            max_depth: optional("COMMENTS_MAX_DEPTH", 4)?,
+            max_length: optional("COMMENTS_MAX_LENGTH", 2000)?,
+            edit_window: Duration::from_secs(optional("COMMENTS_EDIT_WINDOW", 15 * 60)?),
+            rate_limit: optional("COMMENTS_RATE_LIMIT", 5)?,
+            rate_window: Duration::from_secs(optional("COMMENTS_RATE_WINDOW", 5 * 60)?),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BlogConfig {
+    /// How often scheduled posts whose time has come are published.
+    pub publish_interval: Duration,
+}
+
+impl BlogConfig {
+    fn from_env() -> Result<Self, ConfigError> {
+        let publish_interval = optional("BLOG_PUBLISH_INTERVAL", 60)?;
+        // the publisher's timer cannot tick every 0 seconds
+        if publish_interval == 0 {
+            return Err(ConfigError::Invalid(
+                "BLOG_PUBLISH_INTERVAL",
+                publish_interval.to_string(),
+            ));
+        }
+
+        Ok(Self {
+            publish_interval: Duration::from_secs(publish_interval),
+        })
+    }
+}
+
+/// Credentials and location of an S3-compatible bucket.
+#[derive(Clone)]
+pub struct S3Config {
+    /// Endpoint of the service, e.g. `https://s3.eu-west-1.amazonaws.com` or
+    /// a MinIO/R2 URL, without a trailing slash.
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+impl fmt::Debug for S3Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("S3Config")
+            .field("endpoint", &self.endpoint)
+            .field("bucket", &self.bucket)
+            .field("region", &self.region)
+            .field("access_key_id", &self.access_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl S3Config {
+    fn from_env() -> Result<Self, ConfigError> {
+        let endpoint = required("S3_ENDPOINT")?;
+
+        Ok(Self {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            bucket: required("S3_BUCKET")?,
+            region: optional("S3_REGION", "us-east-1".to_string())?,
+            access_key_id: required("S3_ACCESS_KEY_ID")?,
+            secret_access_key: required("S3_SECRET_ACCESS_KEY")?,
+        })
+    }
+}
+
+/// Where uploaded images are stored.
+#[derive(Clone, Debug)]
+pub enum StorageConfig {
+    /// A directory served by the app under `/media`.
+    Local {
+        dir: PathBuf,
+    },
+    S3(S3Config),
+}
+
+#[derive(Clone, Debug)]
+pub struct UploadsConfig {
+    pub storage: StorageConfig,
+    /// Base URL the stored images are served from, without a trailing slash.
+    pub public_url: String,
+    /// Largest accepted upload, in bytes.
+    pub max_size: usize,
+}
+
+impl UploadsConfig {
+    fn from_env() -> Result<Self, ConfigError> {
+        let backend: String = optional("UPLOADS_STORAGE", "local".to_string())?;
+        let (storage, default_url) = match backend.to_ascii_lowercase().as_str() {
+            "local" => (
+                StorageConfig::Local {
+                    dir: PathBuf::from(optional("UPLOADS_DIR", "uploads".to_string())?),
+                },
+                "/media".to_string(),
+            ),
+            "s3" => {
+                let s3 = S3Config::from_env()?;
+                let url = format!("{}/{}", s3.endpoint, s3.bucket);
+                (StorageConfig::S3(s3), url)
+            }
+            _ => return Err(ConfigError::Invalid("UPLOADS_STORAGE", backend)),
+        };
+        let public_url: String = optional("UPLOADS_PUBLIC_URL", default_url)?;
+
+        Ok(Self {
+            storage,
+            public_url: public_url.trim_end_matches('/').to_string(),
+            max_size: optional("UPLOADS_MAX_SIZE", 10 * 1024 * 1024)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub database: DatabaseConfig,
+    pub site: SiteConfig,
+    pub auth: AuthConfig,
+    pub session: SessionConfig,
+    pub comments: CommentsConfig,
+    pub blog: BlogConfig,
+    pub uploads: UploadsConfig,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Ok(Self {
+            database: DatabaseConfig::from_env()?,
+            site: SiteConfig::from_env()?,
+            auth: AuthConfig::from_env()?,
+            session: SessionConfig::from_env()?,
+            comments: CommentsConfig::from_env()?,
+            blog: BlogConfig::from_env()?,
+            uploads: UploadsConfig::from_env()?,
+        })
+    }
+}
+
+/// Returns the configuration provided to the reactive context by the server.
+pub fn use_config() -> Result<Config, ServerFnErrorErr> {
+    use_context::<Config>()
+        .ok_or_else(|| ServerFnErrorErr::ServerError("configuration not provided".to_string()))
+}
@@ -0,0 +1,393 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Downloadable versions of the site owner's CV: `/cv.pdf`, laid out on the
+//! server with [`super::pdf`], and `/cv.json`, a [`Resume`] document.
+//!
+//! The rendered PDF is cached until a CV row is created, updated or deleted,
+//! which is detected from each table's row count and latest `updated_at`.
+
+use std::sync::{Arc, RwLock};
+
+use actix_web::{HttpRequest, HttpResponse, get, http::header, web};
+use chrono::NaiveDateTime;
+use entity::{
+    certificate, education, experience, open_source_contribution,
+    prelude::{
+        Certificate, Education, Experience, OpenSourceContribution, Skill, Summary, Technology,
+    },
+    skill, summary, technology, user,
+};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Select, sea_query::Expr,
+};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    api::{
+        ApiError,
+        cv::{CodeSource, skill_last_usage, split_points},
+    },
+    utils::dates::{date_range, month_year},
+};
+
+use super::{
+    config::Config,
+    json_resume::Resume,
+    pdf::{Font, PdfDocument},
+    site::find_owner,
+};
+
+/// Every CV row of the site owner, most recent first.
+pub struct CvData {
+    pub owner: user::Model,
+    pub summaries: Vec<summary::Model>,
+    pub experiences: Vec<experience::Model>,
+    pub educations: Vec<education::Model>,
+    pub certificates: Vec<certificate::Model>,
+    /// Skills with their technology, `None` if it was deleted meanwhile.
+    pub skills: Vec<(skill::Model, Option<technology::Model>)>,
+    pub contributions: Vec<open_source_contribution::Model>,
+}
+
+impl CvData {
+    pub async fn load<C: ConnectionTrait>(db: &C, owner: user::Model) -> Result<Self, DbErr> {
+        Ok(Self {
+            summaries: Summary::find()
+                .filter(summary::Column::CreatedById.eq(owner.id))
+                .order_by_desc(summary::Column::UpdatedAt)
+                .all(db)
+                .await?,
+            experiences: Experience::find()
+                .filter(experience::Column::CreatedById.eq(owner.id))
+                .order_by_desc(experience::Column::StartDate)
+                .all(db)
+                .await?,
+            educations: Education::find()
+                .filter(education::Column::CreatedById.eq(owner.id))
+                .order_by_desc(education::Column::StartDate)
+                .all(db)
+                .await?,
+            certificates: Certificate::find()
+                .filter(certificate::Column::CreatedById.eq(owner.id))
+                .order_by_desc(certificate::Column::IssueDate)
+                .all(db)
+                .await?,
+            skills: Skill::find()
+                .find_also_related(Technology)
+                .filter(skill::Column::CreatedById.eq(owner.id))
+                .order_by_desc(skill::Column::IsCurrentlyUsed)
+                .order_by_asc(skill::Column::InitialUsageDate)
+                .all(db)
+                .await?,
+            contributions: OpenSourceContribution::find()
+                .filter(open_source_contribution::Column::CreatedById.eq(owner.id))
+                .order_by_asc(open_source_contribution::Column::Name)
+                .all(db)
+                .await?,
+            owner,
+        })
+    }
+}
+
+/// Row count and latest `updated_at` of the rows `query` selects.
+async fn table_version<E, C>(
+    db: &C,
+    query: Select<E>,
+    updated_at: E::Column,
+) -> Result<String, DbErr>
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    let (count, latest) = query
+        .select_only()
+        .column_as(Expr::col(updated_at).count(), "count")
+        .column_as(Expr::col(updated_at).max(), "latest")
+        .into_tuple::<(i64, Option<NaiveDateTime>)>()
+        .one(db)
+        .await?
+        .unwrap_or_default();
+    Ok(format!(
+        "{count}@{}",
+        latest.map_or(0, |latest| latest.and_utc().timestamp_micros())
+    ))
+}
+
+/// Changes whenever a CV row, or a technology its skills are named after,
+/// changes. Used as the cache key and `ETag` of the exports.
+pub async fn cv_version<C: ConnectionTrait>(db: &C, owner: &user::Model) -> Result<String, DbErr> {
+    let versions = [
+        table_version(
+            db,
+            Summary::find().filter(summary::Column::CreatedById.eq(owner.id)),
+            summary::Column::UpdatedAt,
+        )
+        .await?,
+        table_version(
+            db,
+            Experience::find().filter(experience::Column::CreatedById.eq(owner.id)),
+            experience::Column::UpdatedAt,
+        )
+        .await?,
+        table_version(
+            db,
+            Education::find().filter(education::Column::CreatedById.eq(owner.id)),
+            education::Column::UpdatedAt,
+        )
+        .await?,
+        table_version(
+            db,
+            Certificate::find().filter(certificate::Column::CreatedById.eq(owner.id)),
+            certificate::Column::UpdatedAt,
+        )
+        .await?,
+        table_version(
+            db,
+            Skill::find().filter(skill::Column::CreatedById.eq(owner.id)),
+            skill::Column::UpdatedAt,
+        )
+        .await?,
+        table_version(
+            db,
+            OpenSourceContribution::find()
+                .filter(open_source_contribution::Column::CreatedById.eq(owner.id)),
+            open_source_contribution::Column::UpdatedAt,
+        )
+        .await?,
+        table_version(db, Technology::find(), technology::Column::UpdatedAt).await?,
+    ];
+
+    let digest = Sha256::digest(format!("{}|{}", owner.updated_at, versions.join("|")));
+    Ok(digest[..12]
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+/// Last rendered PDF, with the CV version it was rendered from.
+#[derive(Clone, Default)]
+pub struct CvPdfCache(Arc<RwLock<Option<(String, Arc<Vec<u8>>)>>>);
+
+impl CvPdfCache {
+    fn get(&self, version: &str) -> Option<Arc<Vec<u8>>> {
+        let cached = self.0.read().ok()?;
+        cached
+            .as_ref()
+            .filter(|(cached_version, _)| cached_version == version)
+            .map(|(_, pdf)| pdf.clone())
+    }
+
+    fn insert(&self, version: String, pdf: Arc<Vec<u8>>) {
+        if let Ok(mut cached) = self.0.write() {
+            *cached = Some((version, pdf));
+        }
+    }
+}
+
+const BODY_SIZE: f32 = 10.0;
+const DETAIL_SIZE: f32 = 9.0;
+
+fn render_pdf(cv: &CvData, config: &Config) -> Vec<u8> {
+    let mut pdf = PdfDocument::new(format!("{} - CV", cv.owner.name));
+
+    pdf.text(&cv.owner.name, Font::Bold, 22.0);
+    pdf.text(
+        &format!("{} · {}", cv.owner.email, config.site.url),
+        Font::Regular,
+        DETAIL_SIZE,
+    );
+
+    if !cv.summaries.is_empty() {
+        pdf.heading("Summary");
+        for summary in &cv.summaries {
+            pdf.text(&summary.summary, Font::Regular, BODY_SIZE);
+            pdf.space(4.0);
+        }
+    }
+
+    if !cv.experiences.is_empty() {
+        pdf.heading("Experience");
+        for experience in &cv.experiences {
+            let end_date = if experience.is_current {
+                None
+            } else {
+                experience.end_date
+            };
+            pdf.row(
+                &format!("{} - {}", experience.title, experience.company),
+                &date_range(experience.start_date, end_date),
+                BODY_SIZE,
+            );
+            pdf.text(&experience.location, Font::Regular, DETAIL_SIZE);
+            for point in experience
+                .achievements
+                .as_deref()
+                .map(split_points)
+                .unwrap_or_default()
+            {
+                pdf.bullet(&point, BODY_SIZE);
+            }
+            pdf.space(6.0);
+        }
+    }
+
+    if !cv.educations.is_empty() {
+        pdf.heading("Education");
+        for education in &cv.educations {
+            pdf.row(
+                &format!("{} - {}", education.degree, education.institution),
+                &date_range(education.start_date, education.end_date),
+                BODY_SIZE,
+            );
+            let details = match &education.grade {
+                Some(grade) => format!("{} · Grade: {grade}", education.location),
+                None => education.location.clone(),
+            };
+            pdf.text(&details, Font::Regular, DETAIL_SIZE);
+            if let Some(description) = &education.description {
+                pdf.text(description, Font::Regular, BODY_SIZE);
+            }
+            pdf.space(6.0);
+        }
+    }
+
+    if !cv.certificates.is_empty() {
+        pdf.heading("Certificates");
+        for certificate in &cv.certificates {
+            let date = match certificate.expiration_date {
+                Some(expiration_date) => format!(
+                    "{} - {}",
+                    month_year(certificate.issue_date),
+                    month_year(expiration_date)
+                ),
+                None => month_year(certificate.issue_date),
+            };
+            pdf.row(
+                &format!("{} - {}", certificate.title, certificate.issuer),
+                &date,
+                BODY_SIZE,
+            );
+            if let Some(code) = &certificate.verification_code {
+                pdf.text(
+                    &format!("Verification code: {code}"),
+                    Font::Regular,
+                    DETAIL_SIZE,
+                );
+            }
+            if let Some(description) = &certificate.description {
+                pdf.text(description, Font::Regular, BODY_SIZE);
+            }
+            pdf.space(6.0);
+        }
+    }
+
+    if !cv.skills.is_empty() {
+        pdf.heading("Skills");
+        for (skill, technology) in &cv.skills {
+            let Some(technology) = technology else {
+                continue;
+            };
+            pdf.row(
+                &technology.name,
+                &date_range(skill.initial_usage_date, skill_last_usage(skill)),
+                BODY_SIZE,
+            );
+            if let Some(description) = &skill.description {
+                pdf.text(description, Font::Regular, BODY_SIZE);
+            }
+            pdf.space(2.0);
+        }
+    }
+
+    if !cv.contributions.is_empty() {
+        pdf.heading("Open source");
+        for contribution in &cv.contributions {
+            pdf.row(
+                &contribution.name,
+                CodeSource::from(contribution.source.clone()).label(),
+                BODY_SIZE,
+            );
+            pdf.text(&contribution.url, Font::Regular, DETAIL_SIZE);
+            if let Some(description) = &contribution.description {
+                pdf.text(description, Font::Regular, BODY_SIZE);
+            }
+            pdf.space(6.0);
+        }
+    }
+
+    pdf.finish()
+}
+
+fn not_modified(req: &HttpRequest, etag: &str) -> bool {
+    req.headers()
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.split(',').any(|tag| tag.trim() == etag))
+}
+
+#[get("/cv.pdf")]
+pub async fn cv_pdf(
+    req: HttpRequest,
+    config: web::Data<Config>,
+    db: web::Data<DatabaseConnection>,
+    cache: web::Data<CvPdfCache>,
+) -> Result<HttpResponse, ApiError> {
+    let owner = find_owner(db.get_ref(), &config.site).await?;
+    let version = cv_version(db.get_ref(), &owner).await?;
+    let etag = format!("\"{version}\"");
+    if not_modified(&req, &etag) {
+        return Ok(HttpResponse::NotModified()
+            .insert_header((header::ETAG, etag))
+            .finish());
+    }
+
+    let pdf = match cache.get(&version) {
+        Some(pdf) => pdf,
+        None => {
+            let cv = CvData::load(db.get_ref(), owner).await?;
+            let config = config.clone();
+            let pdf = web::block(move || render_pdf(&cv, &config))
+                .await
+                .map_err(|err| {
+                    log::error!("cannot render the CV: {err}");
+                    ApiError::Internal("Something went wrong, please try again later.".to_string())
+                })?;
+            let pdf = Arc::new(pdf);
+            cache.insert(version, pdf.clone());
+            pdf
+        }
+    };
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/pdf")
+        .insert_header((header::ETAG, etag))
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .insert_header((header::CONTENT_DISPOSITION, "inline; filename=\"cv.pdf\""))
+        .body(pdf.as_ref().clone()))
+}
+
+#[get("/cv.json")]
+pub async fn cv_json(
+    req: HttpRequest,
+    config: web::Data<Config>,
+    db: web::Data<DatabaseConnection>,
+) -> Result<HttpResponse, ApiError> {
+    let owner = find_owner(db.get_ref(), &config.site).await?;
+    let etag = format!("\"{}\"", cv_version(db.get_ref(), &owner).await?);
+    if not_modified(&req, &etag) {
+        return Ok(HttpResponse::NotModified()
+            .insert_header((header::ETAG, etag))
+            .finish());
+    }
+
+    let cv = CvData::load(db.get_ref(), owner).await?;
+    Ok(HttpResponse::Ok()
+        .insert_header((header::ETAG, etag))
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .json(Resume::from_cv(&cv, &config.site.url)))
+}
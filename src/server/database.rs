@@ -0,0 +1,28 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use leptos::prelude::*;
+use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbErr};
+
+use super::config::DatabaseConfig;
+
+pub async fn connect(config: &DatabaseConfig) -> Result<DatabaseConnection, DbErr> {
+    let mut options = ConnectOptions::new(&config.url);
+    options
+        .max_connections(config.max_connections)
+        .min_connections(config.min_connections)
+        .connect_timeout(config.connect_timeout)
+        .sqlx_logging(false);
+
+    Database::connect(options).await
+}
+
+/// Returns the connection pool provided to the reactive context by the server.
+pub fn use_database() -> Result<DatabaseConnection, ServerFnErrorErr> {
+    use_context::<DatabaseConnection>().ok_or_else(|| {
+        ServerFnErrorErr::ServerError("database connection not provided".to_string())
+    })
+}
@@ -0,0 +1,157 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Authorization guards, as `require_*` functions for server functions and as
+//! extractors for Actix routes. Both answer 401 without a signed in user and
+//! 403 when the user lacks the required role or is suspended.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::{FromRequest, HttpMessage, HttpRequest, dev::Payload, web};
+use chrono::Utc;
+use entity::{prelude::UserSuspension, user, user_suspension};
+use sea_orm::{ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
+
+use crate::{api::ApiError, utils::dates::day_month_year};
+
+use super::{database::use_database, session::CurrentSession, session::use_current_user};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// Any active user that is not suspended.
+    Active,
+    Staff,
+    Admin,
+}
+
+impl Role {
+    pub fn allows(self, user: &user::Model) -> bool {
+        match self {
+            Self::Active => true,
+            // admins can do anything staff can
+            Self::Staff => user.is_staff || user.is_admin,
+            Self::Admin => user.is_admin,
+        }
+    }
+}
+
+/// Suspension currently in effect for the user, if any: a ban or a temporary
+/// suspension that has not expired yet.
+pub async fn active_suspension<C: ConnectionTrait>(
+    db: &C,
+    user_id: i32,
+) -> Result<Option<user_suspension::Model>, DbErr> {
+    let now = Utc::now().naive_utc();
+    let suspension = UserSuspension::find()
+        .filter(user_suspension::Column::UserId.eq(user_id))
+        .filter(user_suspension::Column::IsActive.eq(true))
+        .one(db)
+        .await?;
+    Ok(suspension.filter(|suspension| suspension.is_banned || suspension.suspended_until > now))
+}
+
+/// Whether the user is banned, banned users cannot hold a session.
+pub async fn is_banned<C: ConnectionTrait>(db: &C, user_id: i32) -> Result<bool, DbErr> {
+    Ok(active_suspension(db, user_id)
+        .await?
+        .is_some_and(|suspension| suspension.is_banned))
+}
+
+/// Checks `user` against `role`, without setting the response status.
+async fn authorize<C: ConnectionTrait>(
+    db: &C,
+    user: Option<user::Model>,
+    role: Role,
+) -> Result<user::Model, ApiError> {
+    let user =
+        user.ok_or_else(|| ApiError::Unauthorized("You need to sign in first.".to_string()))?;
+    if !user.is_active {
+        return Err(ApiError::Forbidden(
+            "Your account has been deactivated.".to_string(),
+        ));
+    }
+    if let Some(suspension) = active_suspension(db, user.id).await? {
+        return Err(ApiError::Forbidden(if suspension.is_banned {
+            "Your account has been banned.".to_string()
+        } else {
+            format!(
+                "Your account is suspended until {}.",
+                day_month_year(suspension.suspended_until)
+            )
+        }));
+    }
+    if !role.allows(&user) {
+        return Err(ApiError::Forbidden(
+            "You don't have permission to do this.".to_string(),
+        ));
+    }
+    Ok(user)
+}
+
+/// Returns the signed in user making the server function call if it has
+/// `role`.
+pub async fn require(role: Role) -> Result<user::Model, ApiError> {
+    let db = use_database()?;
+    authorize(&db, use_current_user(), role).await
+}
+
+pub async fn require_active_user() -> Result<user::Model, ApiError> {
+    require(Role::Active).await
+}
+
+pub async fn require_staff() -> Result<user::Model, ApiError> {
+    require(Role::Staff).await
+}
+
+pub async fn require_admin() -> Result<user::Model, ApiError> {
+    require(Role::Admin).await
+}
+
+async fn extract(req: HttpRequest, role: Role) -> Result<user::Model, ApiError> {
+    let db = req
+        .app_data::<web::Data<DatabaseConnection>>()
+        .ok_or_else(|| ApiError::Internal("database not provided".to_string()))?;
+    let user = req
+        .extensions()
+        .get::<CurrentSession>()
+        .map(|session| session.user.clone());
+    authorize(db.get_ref(), user, role).await
+}
+
+macro_rules! role_extractor {
+    ($(#[$meta:meta])* $name:ident, $role:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug)]
+        pub struct $name(pub user::Model);
+
+        impl FromRequest for $name {
+            type Error = ApiError;
+            type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+            fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+                let req = req.clone();
+                Box::pin(async move { extract(req, $role).await.map($name) })
+            }
+        }
+    };
+}
+
+role_extractor!(
+    /// Extracts the active, non-suspended user making the request.
+    ActiveUser,
+    Role::Active
+);
+role_extractor!(
+    /// Extracts the signed in staff (or admin) user making the request.
+    StaffUser,
+    Role::Staff
+);
+role_extractor!(
+    /// Extracts the signed in admin making the request.
+    AdminUser,
+    Role::Admin
+);
@@ -0,0 +1,164 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Turns uploaded images into the resized variants the site serves, named as
+//! described in [`crate::utils::images`].
+//!
+//! Only the decoded pixels are re-encoded, which drops the EXIF data (camera,
+//! GPS position, etc.) and any other metadata of the upload. The EXIF
+//! orientation is applied to the pixels beforehand so photos keep their
+//! rotation.
+
+use std::{fmt, io::Cursor};
+
+use image::{
+    DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits,
+    codecs::{jpeg::JpegEncoder, png::PngEncoder, webp::WebPEncoder},
+    imageops::FilterType,
+};
+
+use crate::utils::images::variant_widths;
+
+/// Accepted MIME types and the format their content must have.
+const ACCEPTED_TYPES: [(&str, ImageFormat); 4] = [
+    ("image/jpeg", ImageFormat::Jpeg),
+    ("image/png", ImageFormat::Png),
+    ("image/webp", ImageFormat::WebP),
+    ("image/gif", ImageFormat::Gif),
+];
+
+/// Largest width or height decoded, guarding against decompression bombs.
+const MAX_DIMENSION: u32 = 12_000;
+
+const JPEG_QUALITY: u8 = 82;
+
+#[derive(Debug)]
+pub enum ImageError {
+    /// The declared MIME type is not an accepted one.
+    UnsupportedType(String),
+    /// The content is not an image of its declared type.
+    Mismatch,
+    Decode(image::ImageError),
+    Encode(image::ImageError),
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedType(content_type) => {
+                write!(f, "unsupported image type {content_type:?}")
+            }
+            Self::Mismatch => write!(f, "content does not match its image type"),
+            Self::Decode(err) => write!(f, "cannot decode image: {err}"),
+            Self::Encode(err) => write!(f, "cannot encode image: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+/// A resized and re-encoded version of an upload.
+#[derive(Debug)]
+pub struct Variant {
+    pub width: u32,
+    pub extension: &'static str,
+    pub content_type: &'static str,
+    pub bytes: Vec<u8>,
+}
+
+/// Whether uploads declaring `content_type` are accepted.
+pub fn is_accepted(content_type: &str) -> bool {
+    ACCEPTED_TYPES
+        .iter()
+        .any(|(accepted, _)| accepted.eq_ignore_ascii_case(content_type))
+}
+
+fn decode(bytes: &[u8], content_type: &str) -> Result<DynamicImage, ImageError> {
+    let format = ACCEPTED_TYPES
+        .iter()
+        .find(|(accepted, _)| accepted.eq_ignore_ascii_case(content_type))
+        .map(|(_, format)| *format)
+        .ok_or_else(|| ImageError::UnsupportedType(content_type.to_string()))?;
+    // the declared type is chosen by the client, the content has to agree
+    if image::guess_format(bytes).ok() != Some(format) {
+        return Err(ImageError::Mismatch);
+    }
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+    let mut reader = ImageReader::with_format(Cursor::new(bytes), format);
+    reader.limits(limits);
+
+    let mut decoder = reader.into_decoder().map_err(ImageError::Decode)?;
+    let orientation = decoder.orientation().map_err(ImageError::Decode)?;
+    let mut image = DynamicImage::from_decoder(decoder).map_err(ImageError::Decode)?;
+    image.apply_orientation(orientation);
+    Ok(image)
+}
+
+fn encode(
+    image: &DynamicImage,
+    write: impl FnOnce(&DynamicImage, &mut Vec<u8>) -> image::ImageResult<()>,
+) -> Result<Vec<u8>, ImageError> {
+    let mut bytes = Vec::new();
+    write(image, &mut bytes).map_err(ImageError::Encode)?;
+    Ok(bytes)
+}
+
+/// Decodes an upload and encodes its variants, a WebP and a fallback for each
+/// width. The fallback is a PNG for images with transparency and a JPEG
+/// otherwise.
+///
+/// This is CPU bound and should run on a blocking thread.
+pub fn process(bytes: &[u8], content_type: &str) -> Result<Vec<Variant>, ImageError> {
+    let image = decode(bytes, content_type)?;
+    let has_alpha = image.color().has_alpha();
+    // the encoders only take 8-bit pixels, and JPEG no transparency
+    let image = if has_alpha {
+        DynamicImage::ImageRgba8(image.to_rgba8())
+    } else {
+        DynamicImage::ImageRgb8(image.to_rgb8())
+    };
+
+    let mut variants = Vec::new();
+    for width in variant_widths(image.width()) {
+        let resized = if width < image.width() {
+            image.resize(width, u32::MAX, FilterType::Lanczos3)
+        } else {
+            image.clone()
+        };
+
+        variants.push(Variant {
+            width,
+            extension: "webp",
+            content_type: "image/webp",
+            bytes: encode(&resized, |image, bytes| {
+                image.write_with_encoder(WebPEncoder::new_lossless(bytes))
+            })?,
+        });
+        variants.push(if has_alpha {
+            Variant {
+                width,
+                extension: "png",
+                content_type: "image/png",
+                bytes: encode(&resized, |image, bytes| {
+                    image.write_with_encoder(PngEncoder::new(bytes))
+                })?,
+            }
+        } else {
+            Variant {
+                width,
+                extension: "jpg",
+                content_type: "image/jpeg",
+                bytes: encode(&resized, |image, bytes| {
+                    image.write_with_encoder(JpegEncoder::new_with_quality(bytes, JPEG_QUALITY))
+                })?,
+            }
+        });
+    }
+    Ok(variants)
+}
@@ -0,0 +1,386 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! The parts of the [JSON Resume](https://jsonresume.org/schema) schema the CV
+//! tables map onto.
+//!
+//! Values the schema has no property for, e.g. the location of an education or
+//! the usage dates of a skill, are kept in extra properties, which the schema
+//! allows, so an exported document can be imported back as is.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{
+        admin::cv::{
+            CertificateForm, EducationForm, ExperienceForm, OpenSourceContributionForm, SkillForm,
+        },
+        cv::{CodeSource, skill_last_usage, split_points},
+    },
+    utils::dates::date_input,
+};
+
+use super::cv_export::CvData;
+
+pub const SCHEMA_URL: &str =
+    "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Resume {
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    pub basics: Basics,
+    pub work: Vec<Work>,
+    pub education: Vec<Education>,
+    pub certificates: Vec<Certificate>,
+    pub skills: Vec<Skill>,
+    pub projects: Vec<Project>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Basics {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Work {
+    /// Company.
+    pub name: String,
+    pub position: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    pub start_date: String,
+    /// Missing for the current position.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    pub highlights: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Education {
+    pub institution: String,
+    /// Degree, e.g. "Bachelor".
+    pub study_type: String,
+    /// Field of study, e.g. "Economics".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
+    pub start_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    /// Grade.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<String>,
+    /// Not in the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Not in the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Certificate {
+    pub name: String,
+    pub issuer: String,
+    /// Issue date.
+    pub date: String,
+    /// Not in the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_date: Option<String>,
+    /// Not in the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_code: Option<String>,
+    /// Not in the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Skill {
+    /// Name of the technology.
+    pub name: String,
+    pub keywords: Vec<String>,
+    /// Not in the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// Not in the schema, missing for skills still in use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    /// Not in the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+/// An open source contribution.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Project {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub url: String,
+    /// Where the project is hosted, e.g. "GitHub".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Meta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canonical: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// Parses a JSON Resume date, `YYYY-MM-DD`, `YYYY-MM` or `YYYY`, the missing
+/// parts being the first month or day.
+pub fn parse_date(value: &str) -> Option<NaiveDate> {
+    let mut parts = value.trim().splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next().map_or(Some(1), |month| month.parse().ok())?;
+    let day = parts.next().map_or(Some(1), |day| day.parse().ok())?;
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// Turns a JSON Resume date into a date input value, leaving values that are
+/// not dates for the form validation to reject.
+pub fn date_value(value: &str) -> String {
+    parse_date(value).map_or_else(|| value.trim().to_string(), date_input)
+}
+
+fn optional_date_value(value: Option<&str>) -> String {
+    value.map(date_value).unwrap_or_default()
+}
+
+impl Resume {
+    /// The owner's CV, as published on the site at `site_url`.
+    pub fn from_cv(cv: &CvData, site_url: &str) -> Self {
+        Self {
+            schema: Some(SCHEMA_URL.to_string()),
+            basics: Basics {
+                name: Some(cv.owner.name.clone()),
+                email: Some(cv.owner.email.clone()),
+                url: Some(site_url.to_string()),
+                summary: cv.summaries.first().map(|summary| summary.summary.clone()),
+            },
+            work: cv
+                .experiences
+                .iter()
+                .map(|experience| Work {
+                    name: experience.company.clone(),
+                    position: experience.title.clone(),
+                    location: Some(experience.location.clone()),
+                    start_date: date_input(experience.start_date),
+                    end_date: experience
+                        .end_date
+                        .filter(|_| !experience.is_current)
+                        .map(date_input),
+                    highlights: experience
+                        .achievements
+                        .as_deref()
+                        .map(split_points)
+                        .unwrap_or_default(),
+                })
+                .collect(),
+            education: cv
+                .educations
+                .iter()
+                .map(|education| Education {
+                    institution: education.institution.clone(),
+                    study_type: education.degree.clone(),
+                    area: None,
+                    start_date: date_input(education.start_date),
+                    end_date: education.end_date.map(date_input),
+                    score: education.grade.clone(),
+                    location: Some(education.location.clone()),
+                    summary: education.description.clone(),
+                })
+                .collect(),
+            certificates: cv
+                .certificates
+                .iter()
+                .map(|certificate| Certificate {
+                    name: certificate.title.clone(),
+                    issuer: certificate.issuer.clone(),
+                    date: date_input(certificate.issue_date),
+                    expiration_date: certificate.expiration_date.map(date_input),
+                    verification_code: certificate.verification_code.clone(),
+                    summary: certificate.description.clone(),
+                })
+                .collect(),
+            skills: cv
+                .skills
+                .iter()
+                .filter_map(|(skill, technology)| {
+                    let technology = technology.as_ref()?;
+                    Some(Skill {
+                        name: technology.name.clone(),
+                        keywords: Vec::new(),
+                        start_date: Some(date_input(skill.initial_usage_date)),
+                        end_date: skill_last_usage(skill).map(date_input),
+                        summary: skill.description.clone(),
+                    })
+                })
+                .collect(),
+            projects: cv
+                .contributions
+                .iter()
+                .map(|contribution| Project {
+                    name: contribution.name.clone(),
+                    description: contribution.description.clone(),
+                    url: contribution.url.clone(),
+                    entity: Some(
+                        CodeSource::from(contribution.source.clone())
+                            .label()
+                            .to_string(),
+                    ),
+                })
+                .collect(),
+            meta: Some(Meta {
+                canonical: Some(format!("{site_url}/cv.json")),
+                version: Some("v1.0.0".to_string()),
+            }),
+        }
+    }
+}
+
+impl Work {
+    pub fn to_form(&self) -> ExperienceForm {
+        ExperienceForm {
+            company: self.name.trim().to_string(),
+            location: self
+                .location
+                .as_deref()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+            title: self.position.trim().to_string(),
+            start_date: date_value(&self.start_date),
+            end_date: optional_date_value(self.end_date.as_deref()),
+            achievements: self
+                .highlights
+                .iter()
+                .map(|highlight| highlight.trim())
+                .filter(|highlight| !highlight.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            is_current: self.end_date.is_none(),
+        }
+    }
+}
+
+impl Education {
+    pub fn to_form(&self) -> EducationForm {
+        let study_type = self.study_type.trim();
+        let degree = match self.area.as_deref().map(str::trim) {
+            Some(area) if !area.is_empty() && study_type.is_empty() => area.to_string(),
+            Some(area) if !area.is_empty() => format!("{study_type} in {area}"),
+            _ => study_type.to_string(),
+        };
+
+        EducationForm {
+            institution: self.institution.trim().to_string(),
+            location: self
+                .location
+                .as_deref()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+            degree,
+            start_date: date_value(&self.start_date),
+            end_date: optional_date_value(self.end_date.as_deref()),
+            grade: self.score.as_deref().unwrap_or_default().trim().to_string(),
+            description: self
+                .summary
+                .as_deref()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+        }
+    }
+}
+
+impl Certificate {
+    pub fn to_form(&self) -> CertificateForm {
+        CertificateForm {
+            title: self.name.trim().to_string(),
+            issuer: self.issuer.trim().to_string(),
+            issue_date: date_value(&self.date),
+            expiration_date: optional_date_value(self.expiration_date.as_deref()),
+            verification_code: self
+                .verification_code
+                .as_deref()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+            description: self
+                .summary
+                .as_deref()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+        }
+    }
+}
+
+impl Skill {
+    pub fn to_form(&self, technology_id: i32) -> SkillForm {
+        SkillForm {
+            technology_id,
+            initial_usage_date: optional_date_value(self.start_date.as_deref()),
+            last_usage_date: optional_date_value(self.end_date.as_deref()),
+            is_currently_used: self.end_date.is_none(),
+            description: self
+                .summary
+                .as_deref()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+        }
+    }
+}
+
+impl Project {
+    pub fn to_form(&self) -> OpenSourceContributionForm {
+        let entity = self.entity.as_deref().unwrap_or_default().trim();
+        let source = CodeSource::ALL
+            .into_iter()
+            .find(|source| {
+                source.label().eq_ignore_ascii_case(entity)
+                    || source.as_str().eq_ignore_ascii_case(entity)
+            })
+            .unwrap_or(CodeSource::Other);
+
+        OpenSourceContributionForm {
+            url: self.url.trim().to_string(),
+            name: self.name.trim().to_string(),
+            source,
+            description: self
+                .description
+                .as_deref()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+        }
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Renders blog posts, authored in CommonMark with the GitHub extensions, to
+//! sanitized HTML.
+//!
+//! Besides standard markdown, posts may reference their own uploaded images
+//! by id, e.g. `![A diagram](image:42)` for the `blog_post_image` row 42.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use chrono::NaiveDateTime;
+use pulldown_cmark::{CodeBlockKind, CowStr, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use syntect::{
+    html::{ClassStyle, ClassedHTMLGenerator},
+    parsing::SyntaxSet,
+    util::LinesWithEndings,
+};
+
+use crate::{api::blog::TocEntry, utils::slug::slugify};
+
+/// Scheme of image references resolved against a post's uploaded images.
+const IMAGE_SCHEME: &str = "image:";
+
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+
+static SANITIZER: LazyLock<ammonia::Builder<'static>> = LazyLock::new(|| {
+    let mut builder = ammonia::Builder::default();
+    builder
+        .add_tags(["input"])
+        .add_tag_attributes("input", ["type", "checked", "disabled"])
+        .add_tag_attributes("a", ["class"])
+        .add_tag_attributes("code", ["class"])
+        .add_tag_attributes("pre", ["class"])
+        .add_tag_attributes("span", ["class"])
+        .add_tag_attributes("sup", ["class"])
+        .add_tag_attributes("div", ["class", "id"]);
+    for heading in ["h1", "h2", "h3", "h4", "h5", "h6"] {
+        builder.add_tag_attributes(heading, ["id"]);
+    }
+    builder
+});
+
+fn options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_GFM
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Rendered {
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+}
+
+/// Context a post is rendered with.
+pub struct RenderContext<'a> {
+    /// Lowercase names of the code block languages to highlight.
+    pub languages: &'a HashSet<String>,
+    /// URLs of the post's images by `blog_post_image` id.
+    pub images: &'a HashMap<i32, String>,
+}
+
+fn heading_level(level: HeadingLevel) -> u8 {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+fn highlight(code: &str, language: &str) -> Option<String> {
+    let syntax = SYNTAXES.find_syntax_by_token(language)?;
+    let mut generator = ClassedHTMLGenerator::new_with_class_style(
+        syntax,
+        &SYNTAXES,
+        ClassStyle::SpacedPrefixed { prefix: "hl-" },
+    );
+    for line in LinesWithEndings::from(code) {
+        generator
+            .parse_html_for_line_which_includes_newline(line)
+            .ok()?;
+    }
+    Some(format!(
+        "<pre class=\"highlight\"><code class=\"language-{language}\">{}</code></pre>",
+        generator.finalize()
+    ))
+}
+
+fn resolve_image<'a>(url: CowStr<'a>, images: &HashMap<i32, String>) -> CowStr<'a> {
+    match url
+        .strip_prefix(IMAGE_SCHEME)
+        .and_then(|id| id.parse::<i32>().ok())
+        .and_then(|id| images.get(&id))
+    {
+        Some(resolved) => CowStr::from(resolved.clone()),
+        None => url,
+    }
+}
+
+/// Renders a post to sanitized HTML along with its table of contents.
+pub fn render(markdown: &str, context: &RenderContext<'_>) -> Rendered {
+    let mut events = Vec::new();
+    let mut toc = Vec::new();
+    let mut used_ids = HashSet::new();
+    let mut parser = Parser::new_ext(markdown, options());
+
+    while let Some(event) = parser.next() {
+        match event {
+            Event::Start(Tag::Heading {
+                level,
+                classes,
+                attrs,
+                ..
+            }) => {
+                let inner = parser
+                    .by_ref()
+                    .take_while(|event| !matches!(event, Event::End(TagEnd::Heading(_))))
+                    .collect::<Vec<_>>();
+                let title = inner
+                    .iter()
+                    .filter_map(|event| match event {
+                        Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                        _ => None,
+                    })
+                    .collect::<String>();
+
+                let base = match slugify(&title) {
+                    slug if slug.is_empty() => "section".to_string(),
+                    slug => slug,
+                };
+                let mut id = base.clone();
+                let mut suffix = 1;
+                while !used_ids.insert(id.clone()) {
+                    id = format!("{base}-{suffix}");
+                    suffix += 1;
+                }
+
+                toc.push(TocEntry {
+                    level: heading_level(level),
+                    id: id.clone(),
+                    title,
+                });
+                events.push(Event::Start(Tag::Heading {
+                    level,
+                    id: Some(CowStr::from(id.clone())),
+                    classes,
+                    attrs,
+                }));
+                events.extend(inner);
+                events.push(Event::InlineHtml(CowStr::from(format!(
+                    " <a class=\"anchor\" href=\"#{id}\">#</a>"
+                ))));
+                events.push(Event::End(TagEnd::Heading(level)));
+            }
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                let code = parser
+                    .by_ref()
+                    .take_while(|event| !matches!(event, Event::End(TagEnd::CodeBlock)))
+                    .filter_map(|event| match event {
+                        Event::Text(text) => Some(text),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                let code = code.iter().map(|text| text.as_ref()).collect::<String>();
+                let language = info
+                    .split([',', ' '])
+                    .next()
+                    .unwrap_or_default()
+                    .to_lowercase();
+
+                let highlighted = context
+                    .languages
+                    .contains(&language)
+                    .then(|| highlight(&code, &language))
+                    .flatten();
+                match highlighted {
+                    Some(html) => events.push(Event::Html(CowStr::from(html))),
+                    None => {
+                        events.push(Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))));
+                        events.push(Event::Text(CowStr::from(code)));
+                        events.push(Event::End(TagEnd::CodeBlock));
+                    }
+                }
+            }
+            Event::Start(Tag::Image {
+                link_type,
+                dest_url,
+                title,
+                id,
+            }) => events.push(Event::Start(Tag::Image {
+                link_type,
+                dest_url: resolve_image(dest_url, context.images),
+                title,
+                id,
+            })),
+            event => events.push(event),
+        }
+    }
+
+    let mut html = String::with_capacity(markdown.len() * 3 / 2);
+    pulldown_cmark::html::push_html(&mut html, events.into_iter());
+
+    Rendered {
+        html: SANITIZER.clean(&html).to_string(),
+        toc,
+    }
+}
+
+/// Returns the plain text of the first paragraph of a post.
+pub fn first_paragraph(markdown: &str) -> String {
+    Parser::new_ext(markdown, options())
+        .skip_while(|event| !matches!(event, Event::Start(Tag::Paragraph)))
+        .take_while(|event| !matches!(event, Event::End(TagEnd::Paragraph)))
+        .fold(String::new(), |mut text, event| {
+            match event {
+                Event::Text(value) | Event::Code(value) => text.push_str(&value),
+                Event::SoftBreak | Event::HardBreak => text.push(' '),
+                _ => {}
+            }
+            text
+        })
+}
+
+/// Rendered posts, kept until the post's `updated_at` changes.
+#[derive(Clone, Default)]
+pub struct RenderCache(Arc<RwLock<HashMap<i32, (NaiveDateTime, Rendered)>>>);
+
+impl RenderCache {
+    pub fn get(&self, id: i32, updated_at: NaiveDateTime) -> Option<Rendered> {
+        let cache = self.0.read().ok()?;
+        cache
+            .get(&id)
+            .filter(|(cached_at, _)| *cached_at == updated_at)
+            .map(|(_, rendered)| rendered.clone())
+    }
+
+    pub fn insert(&self, id: i32, updated_at: NaiveDateTime, rendered: Rendered) {
+        if let Ok(mut cache) = self.0.write() {
+            cache.insert(id, (updated_at, rendered));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_with(markdown: &str, languages: &[&str], images: &[(i32, &str)]) -> Rendered {
+        let languages = languages
+            .iter()
+            .map(|language| language.to_string())
+            .collect();
+        let images = images
+            .iter()
+            .map(|(id, url)| (*id, url.to_string()))
+            .collect();
+        render(
+            markdown,
+            &RenderContext {
+                languages: &languages,
+                images: &images,
+            },
+        )
+    }
+
+    #[test]
+    fn headings_get_unique_anchors_and_toc_entries() {
+        let rendered = render_with(
+            "# Hello, World!\n\n## Hello World\n\n### `code` title",
+            &[],
+            &[],
+        );
+
+        assert!(rendered.html.contains(r##"<h1 id="hello-world">"##));
+        assert!(
+            rendered
+                .html
+                .contains(r##"<a class="anchor" href="#hello-world""##)
+        );
+        assert!(rendered.html.contains(r##"<h2 id="hello-world-1">"##));
+        assert_eq!(
+            rendered
+                .toc
+                .iter()
+                .map(|entry| (entry.level, entry.id.as_str(), entry.title.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (1, "hello-world", "Hello, World!"),
+                (2, "hello-world-1", "Hello World"),
+                (3, "code-title", "code title"),
+            ]
+        );
+    }
+
+    #[test]
+    fn headings_without_text_are_sections() {
+        let rendered = render_with("# !!!\n\n# ???", &[], &[]);
+
+        let ids = rendered
+            .toc
+            .iter()
+            .map(|entry| entry.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["section", "section-1"]);
+    }
+
+    #[test]
+    fn image_references_resolve_to_uploads() {
+        let rendered = render_with(
+            "![A diagram](image:42) ![Missing](image:7)",
+            &[],
+            &[(42, "https://cdn.example.com/42.webp")],
+        );
+
+        assert!(
+            rendered
+                .html
+                .contains(r#"src="https://cdn.example.com/42.webp""#)
+        );
+        // unknown ids keep the `image:` scheme, which the sanitizer drops
+        assert!(!rendered.html.contains("image:7"));
+    }
+
+    #[test]
+    fn html_is_sanitized() {
+        let rendered = render_with(
+            "<script>alert(1)</script>\n\n<a href=\"javascript:alert(1)\" onclick=\"alert(1)\">link</a>",
+            &[],
+            &[],
+        );
+
+        assert!(!rendered.html.contains("<script"));
+        assert!(!rendered.html.contains("javascript:"));
+        assert!(!rendered.html.contains("onclick"));
+        assert!(rendered.html.contains("link"));
+    }
+
+    #[test]
+    fn only_technology_languages_are_highlighted() {
+        let markdown = "```rust\nfn main() {}\n```";
+
+        let highlighted = render_with(markdown, &["rust"], &[]);
+        assert!(highlighted.html.contains(r#"<pre class="highlight">"#));
+        assert!(highlighted.html.contains("hl-"));
+
+        let plain = render_with(markdown, &[], &[]);
+        assert!(!plain.html.contains("highlight"));
+        assert!(
+            plain
+                .html
+                .contains(r#"<code class="language-rust">fn main() {}"#)
+        );
+    }
+
+    #[test]
+    fn first_paragraph_is_plain_text() {
+        let markdown = "# Title\n\nSome *emphasis* and `code`\nover two lines.\n\nSecond.";
+
+        assert_eq!(
+            first_paragraph(markdown),
+            "Some emphasis and code over two lines."
+        );
+    }
+}
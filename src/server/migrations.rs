@@ -0,0 +1,94 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{collections::HashSet, fmt};
+
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{DatabaseConnection, DbErr};
+
+use super::config::MigrationMode;
+
+#[derive(Debug)]
+pub enum MigrationError {
+    Database(DbErr),
+    /// The database has migrations applied that this binary does not know about.
+    SchemaAhead(Vec<String>),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Database(err) => write!(f, "failed to run migrations: {err}"),
+            Self::SchemaAhead(versions) => write!(
+                f,
+                "database schema is ahead of this binary, unknown migrations: {}",
+                versions.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
+
+impl From<DbErr> for MigrationError {
+    fn from(err: DbErr) -> Self {
+        Self::Database(err)
+    }
+}
+
+/// Brings the schema in line with the migrations compiled into this binary,
+/// according to `mode`.
+pub async fn run(db: &DatabaseConnection, mode: MigrationMode) -> Result<(), MigrationError> {
+    if mode == MigrationMode::Off {
+        log::info!("database migrations are disabled");
+        return Ok(());
+    }
+
+    let known = Migrator::migrations()
+        .iter()
+        .map(|migration| migration.name().to_string())
+        .collect::<HashSet<_>>();
+    let applied = Migrator::get_migration_models(db)
+        .await?
+        .into_iter()
+        .map(|model| model.version)
+        .collect::<Vec<_>>();
+
+    let unknown = applied
+        .iter()
+        .filter(|version| !known.contains(*version))
+        .cloned()
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        return Err(MigrationError::SchemaAhead(unknown));
+    }
+
+    let pending = Migrator::get_pending_migrations(db)
+        .await?
+        .iter()
+        .map(|migration| migration.name().to_string())
+        .collect::<Vec<_>>();
+    if pending.is_empty() {
+        log::info!("database schema is up to date");
+        return Ok(());
+    }
+
+    match mode {
+        MigrationMode::Apply => {
+            Migrator::up(db, None).await?;
+            for name in &pending {
+                log::info!("applied migration {name}");
+            }
+        }
+        _ => {
+            for name in &pending {
+                log::warn!("pending migration {name} was not applied (DATABASE_MIGRATIONS=verify)");
+            }
+        }
+    }
+
+    Ok(())
+}
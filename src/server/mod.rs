@@ -0,0 +1,23 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub mod auth;
+pub mod config;
+pub mod cv_export;
+pub mod database;
+pub mod guards;
+pub mod images;
+pub mod json_resume;
+pub mod markdown;
+pub mod migrations;
+pub mod oauth;
+pub mod pdf;
+pub mod publisher;
+pub mod session;
+pub mod site;
+pub mod storage;
+pub mod uploads;
+pub mod versioning;
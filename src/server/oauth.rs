@@ -0,0 +1,409 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! OAuth 2.0 authorization code flow with PKCE (RFC 7636) against the
+//! providers configured in [`AuthConfig`](super::config::AuthConfig).
+
+use std::fmt;
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use reqwest::{Client, Url, header};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use crate::api::auth::OAuthProvider;
+
+use super::config::OAuthProviderConfig;
+
+const USER_AGENT: &str = "tugascript";
+
+#[derive(Debug)]
+pub enum OAuthError {
+    Http(reqwest::Error),
+    /// The provider answered with an OAuth error or an unexpected payload.
+    Provider(String),
+    /// The provider did not return a verified email to identify the user by.
+    UnverifiedEmail,
+}
+
+impl fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Http(err) => write!(f, "identity provider request failed: {err}"),
+            Self::Provider(message) => write!(f, "identity provider error: {message}"),
+            Self::UnverifiedEmail => write!(f, "identity provider returned no verified email"),
+        }
+    }
+}
+
+impl std::error::Error for OAuthError {}
+
+impl From<reqwest::Error> for OAuthError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Http(err)
+    }
+}
+
+/// Random URL-safe token used for both the `state` and the PKCE verifier.
+pub fn random_token() -> String {
+    URL_SAFE_NO_PAD.encode(rand::random::<[u8; 32]>())
+}
+
+/// PKCE `S256` code challenge of `verifier`.
+pub fn code_challenge(verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+fn scopes(provider: OAuthProvider) -> &'static str {
+    match provider {
+        OAuthProvider::Github => "read:user user:email",
+        OAuthProvider::Google => "openid email profile",
+    }
+}
+
+/// URL the user is sent to in order to authorise the app with the provider.
+pub fn authorize_url(
+    provider: OAuthProvider,
+    config: &OAuthProviderConfig,
+    redirect_uri: &str,
+    state: &str,
+    verifier: &str,
+) -> Result<Url, OAuthError> {
+    Url::parse_with_params(
+        &config.authorize_url,
+        &[
+            ("response_type", "code"),
+            ("client_id", config.client_id.as_str()),
+            ("redirect_uri", redirect_uri),
+            ("scope", scopes(provider)),
+            ("state", state),
+            ("code_challenge", code_challenge(verifier).as_str()),
+            ("code_challenge_method", "S256"),
+        ],
+    )
+    .map_err(|err| OAuthError::Provider(format!("invalid authorize url: {err}")))
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+/// Exchanges the authorization `code` for an access token.
+pub async fn exchange_code(
+    client: &Client,
+    config: &OAuthProviderConfig,
+    redirect_uri: &str,
+    code: &str,
+    verifier: &str,
+) -> Result<String, OAuthError> {
+    let response = client
+        .post(&config.token_url)
+        .header(header::ACCEPT, "application/json")
+        .form(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+            ("client_id", config.client_id.as_str()),
+            ("client_secret", config.client_secret.as_str()),
+            ("code_verifier", verifier),
+        ])
+        .send()
+        .await?
+        .json::<TokenResponse>()
+        .await?;
+
+    // GitHub reports token errors with a 200 response, so check the body
+    match response {
+        TokenResponse {
+            access_token: Some(token),
+            error: None,
+            ..
+        } => Ok(token),
+        TokenResponse {
+            error,
+            error_description,
+            ..
+        } => Err(OAuthError::Provider(
+            error_description
+                .or(error)
+                .unwrap_or_else(|| "missing access token".to_string()),
+        )),
+    }
+}
+
+/// Identity of the signed in user, as reported by the provider.
+#[derive(Clone, Debug)]
+pub struct OAuthProfile {
+    pub email: String,
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+struct GithubUser {
+    login: String,
+    name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GithubEmail {
+    email: String,
+    primary: bool,
+    verified: bool,
+}
+
+#[derive(Deserialize)]
+struct GoogleUser {
+    email: Option<String>,
+    #[serde(default)]
+    email_verified: bool,
+    name: Option<String>,
+}
+
+async fn get_json<T: for<'de> Deserialize<'de>>(
+    client: &Client,
+    url: &str,
+    access_token: &str,
+) -> Result<T, OAuthError> {
+    Ok(client
+        .get(url)
+        .bearer_auth(access_token)
+        .header(header::ACCEPT, "application/json")
+        .header(header::USER_AGENT, USER_AGENT)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<T>()
+        .await?)
+}
+
+/// Loads the user's verified email and display name from the provider.
+pub async fn fetch_profile(
+    client: &Client,
+    provider: OAuthProvider,
+    config: &OAuthProviderConfig,
+    access_token: &str,
+) -> Result<OAuthProfile, OAuthError> {
+    let profile = match provider {
+        OAuthProvider::Github => {
+            let user: GithubUser = get_json(client, &config.userinfo_url, access_token).await?;
+            let emails_url = config
+                .emails_url
+                .as_deref()
+                .ok_or_else(|| OAuthError::Provider("missing emails url".to_string()))?;
+            // the public profile email is not necessarily verified
+            let email = get_json::<Vec<GithubEmail>>(client, emails_url, access_token)
+                .await?
+                .into_iter()
+                .find(|email| email.primary && email.verified)
+                .ok_or(OAuthError::UnverifiedEmail)?
+                .email;
+
+            OAuthProfile {
+                email,
+                name: user.name.unwrap_or(user.login),
+            }
+        }
+        OAuthProvider::Google => {
+            let user: GoogleUser = get_json(client, &config.userinfo_url, access_token).await?;
+            let email = user
+                .email
+                .filter(|_| user.email_verified)
+                .ok_or(OAuthError::UnverifiedEmail)?;
+
+            OAuthProfile {
+                name: user.name.unwrap_or_else(|| email.clone()),
+                email,
+            }
+        }
+    };
+
+    Ok(OAuthProfile {
+        email: profile.email.trim().to_lowercase(),
+        name: profile.name.trim().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, net::TcpListener};
+
+    use actix_web::{App, HttpRequest, HttpResponse, HttpServer, http::header::AUTHORIZATION, web};
+    use serde_json::json;
+
+    use super::*;
+
+    const CODE: &str = "authorization-code";
+    const VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+    const ACCESS_TOKEN: &str = "access-token";
+
+    async fn token(form: web::Form<HashMap<String, String>>) -> HttpResponse {
+        let field = |name: &str| form.get(name).map(String::as_str);
+        if field("grant_type") == Some("authorization_code")
+            && field("code") == Some(CODE)
+            && field("code_verifier") == Some(VERIFIER)
+            && field("client_secret") == Some("secret")
+        {
+            HttpResponse::Ok().json(json!({ "access_token": ACCESS_TOKEN, "token_type": "bearer" }))
+        } else {
+            // like GitHub, reported with a 200 response
+            HttpResponse::Ok().json(json!({
+                "error": "bad_verification_code",
+                "error_description": "The code passed is incorrect or expired.",
+            }))
+        }
+    }
+
+    fn authorized(req: &HttpRequest, body: serde_json::Value) -> HttpResponse {
+        let bearer = format!("Bearer {ACCESS_TOKEN}");
+        match req.headers().get(AUTHORIZATION) {
+            Some(value) if value.as_bytes() == bearer.as_bytes() => HttpResponse::Ok().json(body),
+            _ => HttpResponse::Unauthorized().finish(),
+        }
+    }
+
+    async fn github_user(req: HttpRequest) -> HttpResponse {
+        authorized(&req, json!({ "login": "octocat", "name": null }))
+    }
+
+    async fn github_emails(req: HttpRequest) -> HttpResponse {
+        authorized(
+            &req,
+            json!([
+                { "email": "octocat@example.com", "primary": false, "verified": true },
+                { "email": " OctoCat@Example.com ", "primary": true, "verified": true },
+            ]),
+        )
+    }
+
+    async fn google_user(req: HttpRequest, verified: web::Path<bool>) -> HttpResponse {
+        authorized(
+            &req,
+            json!({
+                "email": "jane@example.com",
+                "email_verified": *verified,
+                "name": " Jane Doe ",
+            }),
+        )
+    }
+
+    /// Serves a mock identity provider on a random local port, returning its
+    /// URL.
+    fn identity_provider() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = HttpServer::new(|| {
+            App::new()
+                .route("/token", web::post().to(token))
+                .route("/user", web::get().to(github_user))
+                .route("/user/emails", web::get().to(github_emails))
+                .route("/userinfo/{verified}", web::get().to(google_user))
+        })
+        .workers(1)
+        .listen(listener)
+        .unwrap()
+        .run();
+        actix_web::rt::spawn(server);
+        url
+    }
+
+    fn provider_config(url: &str, userinfo_path: &str) -> OAuthProviderConfig {
+        OAuthProviderConfig {
+            client_id: "client".to_string(),
+            client_secret: "secret".to_string(),
+            authorize_url: format!("{url}/authorize"),
+            token_url: format!("{url}/token"),
+            userinfo_url: format!("{url}{userinfo_path}"),
+            emails_url: Some(format!("{url}/user/emails")),
+        }
+    }
+
+    #[test]
+    fn code_challenge_matches_rfc_7636() {
+        // the example of RFC 7636, appendix B
+        assert_eq!(
+            code_challenge(VERIFIER),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn authorize_url_requests_an_s256_challenge() {
+        let config = provider_config("https://idp.example.com", "/user");
+        let url = authorize_url(
+            OAuthProvider::Github,
+            &config,
+            "https://example.com/auth/github/callback",
+            "state",
+            VERIFIER,
+        )
+        .unwrap();
+        let params = url.query_pairs().into_owned().collect::<HashMap<_, _>>();
+
+        assert_eq!(url.path(), "/authorize");
+        assert_eq!(params["client_id"], "client");
+        assert_eq!(
+            params["redirect_uri"],
+            "https://example.com/auth/github/callback"
+        );
+        assert_eq!(params["state"], "state");
+        assert_eq!(params["code_challenge"], code_challenge(VERIFIER));
+        assert_eq!(params["code_challenge_method"], "S256");
+    }
+
+    #[actix_web::test]
+    async fn github_sign_in_uses_the_primary_verified_email() {
+        let url = identity_provider();
+        let config = provider_config(&url, "/user");
+        let client = Client::new();
+
+        let access_token = exchange_code(&client, &config, "http://callback", CODE, VERIFIER)
+            .await
+            .unwrap();
+        let profile = fetch_profile(&client, OAuthProvider::Github, &config, &access_token)
+            .await
+            .unwrap();
+
+        assert_eq!(profile.email, "octocat@example.com");
+        assert_eq!(profile.name, "octocat");
+    }
+
+    #[actix_web::test]
+    async fn exchange_code_reports_token_errors() {
+        let url = identity_provider();
+        let config = provider_config(&url, "/user");
+
+        let err = exchange_code(&Client::new(), &config, "http://callback", CODE, "forged")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            OAuthError::Provider(message) if message == "The code passed is incorrect or expired."
+        ));
+    }
+
+    #[actix_web::test]
+    async fn google_sign_in_requires_a_verified_email() {
+        let url = identity_provider();
+        let client = Client::new();
+
+        let verified = provider_config(&url, "/userinfo/true");
+        let profile = fetch_profile(&client, OAuthProvider::Google, &verified, ACCESS_TOKEN)
+            .await
+            .unwrap();
+        assert_eq!(profile.email, "jane@example.com");
+        assert_eq!(profile.name, "Jane Doe");
+
+        let unverified = provider_config(&url, "/userinfo/false");
+        let err = fetch_profile(&client, OAuthProvider::Google, &unverified, ACCESS_TOKEN)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, OAuthError::UnverifiedEmail));
+    }
+}
@@ -0,0 +1,320 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Minimal flowing layout of A4 PDF documents: headings, wrapped paragraphs,
+//! bullet points and title/date rows, paginated with page numbers.
+//!
+//! Text is set in Helvetica, one of the standard PDF fonts every reader ships,
+//! so no font is embedded. The standard fonts only cover the Windows-1252
+//! characters, others are replaced by `?`.
+
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+/// Line height, relative to the font size.
+const LEADING: f32 = 1.35;
+const BULLET_INDENT: f32 = 12.0;
+
+const REGULAR_NAME: Name<'static> = Name(b"F1");
+const BOLD_NAME: Name<'static> = Name(b"F2");
+
+/// Advance widths of the printable ASCII characters, from `' '` to `'~'`, in
+/// thousandths of the font size, from the Adobe font metrics.
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611, 975, 722, 722, 722, 722, 667,
+    611, 778, 722, 278, 556, 722, 611, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 333, 278, 333, 584, 556, 333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556,
+    278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn name(self) -> Name<'static> {
+        match self {
+            Self::Regular => REGULAR_NAME,
+            Self::Bold => BOLD_NAME,
+        }
+    }
+
+    /// Width of `text` set at `size` points.
+    fn width(self, text: &str, size: f32) -> f32 {
+        let widths = match self {
+            Self::Regular => &HELVETICA_WIDTHS,
+            Self::Bold => &HELVETICA_BOLD_WIDTHS,
+        };
+        let units = text
+            .chars()
+            .map(|c| match c {
+                ' '..='~' => u32::from(widths[c as usize - 0x20]),
+                '•' => 350,
+                '–' | '€' => 556,
+                '—' | '…' | '‰' => 1000,
+                '‘' | '’' | '‚' => 222,
+                '“' | '”' | '„' => 333,
+                // close enough for the accented letters
+                _ => 556,
+            })
+            .sum::<u32>();
+        units as f32 * size / 1000.0
+    }
+}
+
+/// Encodes `text` in Windows-1252 (`WinAnsiEncoding`).
+fn encode(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            ' '..='~' | '\u{a0}'..='\u{ff}' => c as u8,
+            '€' => 0x80,
+            '‚' => 0x82,
+            'ƒ' => 0x83,
+            '„' => 0x84,
+            '…' => 0x85,
+            '†' => 0x86,
+            '‡' => 0x87,
+            'ˆ' => 0x88,
+            '‰' => 0x89,
+            'Š' => 0x8a,
+            '‹' => 0x8b,
+            'Œ' => 0x8c,
+            'Ž' => 0x8e,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            '˜' => 0x98,
+            '™' => 0x99,
+            'š' => 0x9a,
+            '›' => 0x9b,
+            'œ' => 0x9c,
+            'ž' => 0x9e,
+            'Ÿ' => 0x9f,
+            '\t' => b' ',
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Breaks `text` into lines at most `max_width` wide, words wider than a line
+/// getting a line of their own.
+fn wrap(text: &str, font: Font, size: f32, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{line} {word}")
+        };
+        if line.is_empty() || font.width(&candidate, size) <= max_width {
+            line = candidate;
+        } else {
+            lines.push(std::mem::replace(&mut line, word.to_string()));
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// A document being laid out, top to bottom.
+pub struct PdfDocument {
+    title: String,
+    pages: Vec<Content>,
+    /// Baseline position of the next line on the last page.
+    y: f32,
+}
+
+impl PdfDocument {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            pages: vec![Content::new()],
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    /// Starts a new page unless `height` more points fit on the current one.
+    fn reserve(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.pages.push(Content::new());
+            self.y = PAGE_HEIGHT - MARGIN;
+        }
+    }
+
+    fn page(&mut self) -> &mut Content {
+        self.pages.last_mut().expect("a document always has a page")
+    }
+
+    fn show(&mut self, x: f32, text: &str, font: Font, size: f32) {
+        let y = self.y;
+        let content = self.page();
+        content.begin_text();
+        content.set_font(font.name(), size);
+        content.next_line(x, y);
+        content.show(Str(&encode(text)));
+        content.end_text();
+    }
+
+    /// Moves down to the baseline of a line set at `size` points, starting a
+    /// new page if it does not fit.
+    fn start_line(&mut self, size: f32) {
+        self.reserve(size * LEADING);
+        self.y -= size;
+    }
+
+    /// Moves down past the line started by `start_line`.
+    fn end_line(&mut self, size: f32) {
+        self.y -= size * (LEADING - 1.0);
+    }
+
+    fn line(&mut self, x: f32, text: &str, font: Font, size: f32) {
+        self.start_line(size);
+        self.show(x, text, font, size);
+        self.end_line(size);
+    }
+
+    /// Adds vertical space, dropped at the top of a page.
+    pub fn space(&mut self, height: f32) {
+        if self.y < PAGE_HEIGHT - MARGIN {
+            self.y -= height;
+        }
+    }
+
+    /// Wrapped paragraphs, one per line of `text`.
+    pub fn text(&mut self, text: &str, font: Font, size: f32) {
+        for paragraph in text.lines().filter(|line| !line.trim().is_empty()) {
+            for line in wrap(paragraph, font, size, CONTENT_WIDTH) {
+                self.line(MARGIN, &line, font, size);
+            }
+        }
+    }
+
+    /// Bullet point, its wrapped lines indented past the bullet.
+    pub fn bullet(&mut self, text: &str, size: f32) {
+        let lines = wrap(text, Font::Regular, size, CONTENT_WIDTH - BULLET_INDENT);
+        for (index, line) in lines.iter().enumerate() {
+            self.start_line(size);
+            if index == 0 {
+                self.show(MARGIN, "•", Font::Regular, size);
+            }
+            self.show(MARGIN + BULLET_INDENT, line, Font::Regular, size);
+            self.end_line(size);
+        }
+    }
+
+    /// Section heading, underlined and kept with the first lines after it.
+    pub fn heading(&mut self, text: &str) {
+        let size = 13.0;
+        self.space(10.0);
+        self.reserve(size * LEADING + 40.0);
+        self.line(MARGIN, &text.to_uppercase(), Font::Bold, size);
+        let y = self.y + 2.0;
+        let content = self.page();
+        content.set_line_width(0.75);
+        content.move_to(MARGIN, y);
+        content.line_to(PAGE_WIDTH - MARGIN, y);
+        content.stroke();
+        self.y -= 6.0;
+    }
+
+    /// Bold title with a date, or another detail, aligned right on its first
+    /// line.
+    pub fn row(&mut self, title: &str, detail: &str, size: f32) {
+        let detail_width = Font::Regular.width(detail, size);
+        let lines = wrap(title, Font::Bold, size, CONTENT_WIDTH - detail_width - 10.0);
+        // keeps the title's lines together
+        self.reserve(size * LEADING * lines.len() as f32);
+        for (index, line) in lines.iter().enumerate() {
+            self.start_line(size);
+            if index == 0 {
+                self.show(
+                    PAGE_WIDTH - MARGIN - detail_width,
+                    detail,
+                    Font::Regular,
+                    size,
+                );
+            }
+            self.show(MARGIN, line, Font::Bold, size);
+            self.end_line(size);
+        }
+    }
+
+    /// Writes the document, numbering its pages.
+    pub fn finish(mut self) -> Vec<u8> {
+        let catalog_id = Ref::new(1);
+        let page_tree_id = Ref::new(2);
+        let regular_id = Ref::new(3);
+        let bold_id = Ref::new(4);
+        let info_id = Ref::new(5);
+        let first_page_id = 6;
+
+        let count = self.pages.len();
+        let page_ids = (0..count)
+            .map(|index| Ref::new(first_page_id + 2 * index as i32))
+            .collect::<Vec<_>>();
+
+        let mut pdf = Pdf::new();
+        pdf.catalog(catalog_id).pages(page_tree_id);
+        pdf.pages(page_tree_id)
+            .kids(page_ids.iter().copied())
+            .count(count as i32);
+        pdf.document_info(info_id)
+            .title(TextStr(&self.title))
+            .creator(TextStr("tugascript"));
+        for (id, name) in [(regular_id, "Helvetica"), (bold_id, "Helvetica-Bold")] {
+            pdf.type1_font(id)
+                .base_font(Name(name.as_bytes()))
+                .encoding_predefined(Name(b"WinAnsiEncoding"));
+        }
+
+        for (index, (mut content, page_id)) in self.pages.drain(..).zip(page_ids).enumerate() {
+            let footer = format!("{} of {count}", index + 1);
+            let size = 8.0;
+            content.begin_text();
+            content.set_font(REGULAR_NAME, size);
+            content.next_line(
+                PAGE_WIDTH - MARGIN - Font::Regular.width(&footer, size),
+                MARGIN / 2.0,
+            );
+            content.show(Str(&encode(&footer)));
+            content.end_text();
+
+            let content_id = Ref::new(page_id.get() + 1);
+            let mut page = pdf.page(page_id);
+            page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT));
+            page.parent(page_tree_id);
+            page.contents(content_id);
+            page.resources()
+                .fonts()
+                .pair(REGULAR_NAME, regular_id)
+                .pair(BOLD_NAME, bold_id);
+            page.finish();
+            pdf.stream(content_id, &content.finish());
+        }
+
+        pdf.finish()
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Publishes scheduled blog posts, i.e. unpublished posts with a
+//! `published_at`, once their time comes.
+
+use std::time::Duration;
+
+use entity::{blog_post, prelude::BlogPost};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    sea_query::{Expr, Func},
+};
+
+/// Publishes the scheduled posts due by now, returning how many there were.
+/// A post published for the first time keeps its scheduled date as its
+/// `first_published_at`.
+pub async fn publish_due(db: &DatabaseConnection) -> Result<u64, DbErr> {
+    let now = chrono::Utc::now().naive_utc();
+    let result = BlogPost::update_many()
+        .col_expr(blog_post::Column::IsPublished, Expr::value(true))
+        .col_expr(
+            blog_post::Column::FirstPublishedAt,
+            Func::coalesce([
+                Expr::col(blog_post::Column::FirstPublishedAt).into(),
+                Expr::col(blog_post::Column::PublishedAt).into(),
+            ])
+            .into(),
+        )
+        .col_expr(blog_post::Column::UpdatedAt, Expr::value(now))
+        .filter(blog_post::Column::IsPublished.eq(false))
+        .filter(blog_post::Column::PublishedAt.lte(now))
+        .exec(db)
+        .await?;
+    Ok(result.rows_affected)
+}
+
+/// Runs [`publish_due`] every `interval` for as long as the server runs.
+pub fn spawn(db: DatabaseConnection, interval: Duration) {
+    actix_web::rt::spawn(async move {
+        let mut ticks = actix_web::rt::time::interval(interval);
+        loop {
+            ticks.tick().await;
+            match publish_due(&db).await {
+                Ok(0) => {}
+                Ok(count) => log::info!("published {count} scheduled blog post(s)"),
+                Err(err) => log::error!("failed to publish scheduled blog posts: {err}"),
+            }
+        }
+    });
+}
@@ -0,0 +1,438 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Server-side sessions: the browser only holds a signed, HttpOnly cookie with
+//! the session id while the session itself lives in Valkey (or in memory when
+//! no `REDIS_URL` is configured, e.g. in tests) and expires after
+//! `SESSION_TTL` seconds without requests.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use actix_web::{
+    HttpMessage,
+    body::MessageBody,
+    cookie::{Cookie, CookieJar, Key, SameSite, time},
+    dev::{ServiceRequest, ServiceResponse},
+    middleware::Next,
+    web,
+};
+use chrono::Utc;
+use entity::{prelude::User, user};
+use leptos::prelude::*;
+use redis::{AsyncCommands, aio::ConnectionManager};
+use sea_orm::{DatabaseConnection, EntityTrait};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    config::{Config, SessionConfig},
+    guards::is_banned,
+    oauth::random_token,
+};
+
+pub const SESSION_COOKIE: &str = "session";
+
+const KEY_PREFIX: &str = "session:";
+
+/// Requests that never need the session, served before it would be used.
+const SKIPPED_PREFIXES: [&str; 4] = ["/pkg/", "/assets/", "/media/", "/favicon.ico"];
+
+#[derive(Debug)]
+pub enum SessionError {
+    Valkey(redis::RedisError),
+    Serde(serde_json::Error),
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Valkey(err) => write!(f, "session store error: {err}"),
+            Self::Serde(err) => write!(f, "invalid session data: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+impl From<redis::RedisError> for SessionError {
+    fn from(err: redis::RedisError) -> Self {
+        Self::Valkey(err)
+    }
+}
+
+impl From<serde_json::Error> for SessionError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Serde(err)
+    }
+}
+
+/// Data stored for each session.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Session {
+    pub user_id: i32,
+    /// Unix timestamp of the sign-in that created the session.
+    pub created_at: i64,
+}
+
+#[derive(Clone)]
+enum Backend {
+    Valkey(ConnectionManager),
+    Memory(Arc<Mutex<HashMap<String, (Session, Instant)>>>),
+}
+
+#[derive(Clone)]
+pub struct SessionStore {
+    backend: Backend,
+    ttl: Duration,
+}
+
+impl SessionStore {
+    /// Connects to Valkey, falling back to an in-memory store when no URL is
+    /// configured.
+    pub async fn connect(config: &SessionConfig) -> Result<Self, SessionError> {
+        let backend = match &config.redis_url {
+            Some(url) => Backend::Valkey(
+                redis::Client::open(url.as_str())?
+                    .get_connection_manager()
+                    .await?,
+            ),
+            None => {
+                log::warn!("REDIS_URL is not set, sessions are kept in memory");
+                Backend::Memory(Arc::default())
+            }
+        };
+
+        Ok(Self {
+            backend,
+            ttl: config.ttl,
+        })
+    }
+
+    /// In-memory store, sessions are lost when the process stops.
+    pub fn memory(ttl: Duration) -> Self {
+        Self {
+            backend: Backend::Memory(Arc::default()),
+            ttl,
+        }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Starts a session for `user_id`, returning its id.
+    pub async fn create(&self, user_id: i32) -> Result<String, SessionError> {
+        let id = random_token();
+        let session = Session {
+            user_id,
+            created_at: Utc::now().timestamp(),
+        };
+
+        match &self.backend {
+            Backend::Valkey(conn) => {
+                let value = serde_json::to_string(&session)?;
+                conn.clone()
+                    .set_ex::<_, _, ()>(format!("{KEY_PREFIX}{id}"), value, self.ttl.as_secs())
+                    .await?;
+            }
+            Backend::Memory(sessions) => {
+                let mut sessions = sessions.lock().unwrap_or_else(|err| err.into_inner());
+                let now = Instant::now();
+                sessions.retain(|_, (_, expires_at)| *expires_at > now);
+                sessions.insert(id.clone(), (session, now + self.ttl));
+            }
+        }
+
+        Ok(id)
+    }
+
+    /// Loads a session, extending its expiry.
+    pub async fn touch(&self, id: &str) -> Result<Option<Session>, SessionError> {
+        match &self.backend {
+            Backend::Valkey(conn) => {
+                let value: Option<String> = redis::cmd("GETEX")
+                    .arg(format!("{KEY_PREFIX}{id}"))
+                    .arg("EX")
+                    .arg(self.ttl.as_secs())
+                    .query_async(&mut conn.clone())
+                    .await?;
+                Ok(value
+                    .map(|value| serde_json::from_str(&value))
+                    .transpose()?)
+            }
+            Backend::Memory(sessions) => {
+                let mut sessions = sessions.lock().unwrap_or_else(|err| err.into_inner());
+                let now = Instant::now();
+                match sessions.get_mut(id) {
+                    Some((session, expires_at)) if *expires_at > now => {
+                        *expires_at = now + self.ttl;
+                        Ok(Some(session.clone()))
+                    }
+                    Some(_) => {
+                        sessions.remove(id);
+                        Ok(None)
+                    }
+                    None => Ok(None),
+                }
+            }
+        }
+    }
+
+    pub async fn destroy(&self, id: &str) -> Result<(), SessionError> {
+        match &self.backend {
+            Backend::Valkey(conn) => {
+                conn.clone()
+                    .del::<_, ()>(format!("{KEY_PREFIX}{id}"))
+                    .await?;
+            }
+            Backend::Memory(sessions) => {
+                sessions
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner())
+                    .remove(id);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Signed cookie carrying the session id.
+pub fn session_cookie(config: &Config, ttl: Duration, id: &str) -> Cookie<'static> {
+    let cookie = Cookie::build(SESSION_COOKIE, id.to_string())
+        .path("/")
+        .http_only(true)
+        .secure(config.site.url.starts_with("https://"))
+        .same_site(SameSite::Lax)
+        .max_age(time::Duration::seconds(ttl.as_secs() as i64))
+        .finish();
+
+    sign(&config.auth.cookie_key, cookie)
+}
+
+/// Signs `cookie` with `key`, so its value cannot be forged.
+fn sign(key: &Key, cookie: Cookie<'static>) -> Cookie<'static> {
+    let mut jar = CookieJar::new();
+    jar.signed_mut(key).add(cookie.clone());
+    jar.get(cookie.name()).cloned().unwrap_or(cookie)
+}
+
+/// Session id of a session cookie, unless its signature does not match.
+fn verified_session_id(key: &Key, cookie: Cookie<'static>) -> Option<String> {
+    let mut jar = CookieJar::new();
+    jar.add_original(cookie);
+    jar.signed(key)
+        .get(SESSION_COOKIE)
+        .map(|cookie| cookie.value().to_string())
+}
+
+pub fn clear_session_cookie() -> Cookie<'static> {
+    let mut cookie = Cookie::build(SESSION_COOKIE, "").path("/").finish();
+    cookie.make_removal();
+    cookie
+}
+
+/// Session of the signed in user making the current request.
+#[derive(Clone, Debug)]
+pub struct CurrentSession {
+    pub id: String,
+    pub user: user::Model,
+}
+
+enum Resolved {
+    /// No session cookie was sent.
+    Anonymous,
+    /// The cookie is forged, expired or belongs to a user that cannot sign in,
+    /// e.g. a banned one.
+    Invalid,
+    Valid(CurrentSession),
+}
+
+async fn resolve(req: &ServiceRequest) -> Resolved {
+    let Some(cookie) = req.cookie(SESSION_COOKIE) else {
+        return Resolved::Anonymous;
+    };
+    let (Some(config), Some(store), Some(db)) = (
+        req.app_data::<web::Data<Config>>(),
+        req.app_data::<web::Data<SessionStore>>(),
+        req.app_data::<web::Data<DatabaseConnection>>(),
+    ) else {
+        return Resolved::Anonymous;
+    };
+
+    let Some(id) = verified_session_id(&config.auth.cookie_key, cookie) else {
+        return Resolved::Invalid;
+    };
+
+    let session = match store.touch(&id).await {
+        Ok(Some(session)) => session,
+        Ok(None) => return Resolved::Invalid,
+        Err(err) => {
+            // keep the cookie, the store may only be unavailable for a moment
+            log::error!("{err}");
+            return Resolved::Anonymous;
+        }
+    };
+
+    let user = match User::find_by_id(session.user_id).one(db.get_ref()).await {
+        Ok(user) => user.filter(|user| user.is_active),
+        Err(err) => {
+            log::error!("database error: {err}");
+            return Resolved::Anonymous;
+        }
+    };
+    let banned = match &user {
+        Some(user) => is_banned(db.get_ref(), user.id)
+            .await
+            .unwrap_or_else(|err| {
+                log::error!("database error: {err}");
+                false
+            }),
+        None => false,
+    };
+
+    match user {
+        Some(user) if !banned => Resolved::Valid(CurrentSession { id, user }),
+        // deleted, deactivated or banned since signing in
+        _ => {
+            if let Err(err) = store.destroy(&id).await {
+                log::error!("{err}");
+            }
+            Resolved::Invalid
+        }
+    }
+}
+
+/// Resolves the session of every request into a [`CurrentSession`] request
+/// extension, renewing the cookie (sliding expiry) or removing it when the
+/// session is no longer valid.
+pub async fn middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    if SKIPPED_PREFIXES
+        .iter()
+        .any(|prefix| req.path().starts_with(prefix))
+    {
+        return next.call(req).await;
+    }
+
+    let resolved = resolve(&req).await;
+    let renewed = match &resolved {
+        Resolved::Valid(session) => {
+            let config = req.app_data::<web::Data<Config>>();
+            let store = req.app_data::<web::Data<SessionStore>>();
+            config
+                .zip(store)
+                .map(|(config, store)| session_cookie(config, store.ttl(), &session.id))
+        }
+        Resolved::Invalid => Some(clear_session_cookie()),
+        Resolved::Anonymous => None,
+    };
+    if let Resolved::Valid(session) = resolved {
+        req.extensions_mut().insert(session);
+    }
+
+    let mut res = next.call(req).await?;
+    // sign-in and sign-out set the cookie themselves
+    let handled = res
+        .response()
+        .cookies()
+        .any(|cookie| cookie.name() == SESSION_COOKIE);
+    if let Some(cookie) = renewed.filter(|_| !handled) {
+        res.response_mut().add_cookie(&cookie)?;
+    }
+    Ok(res)
+}
+
+/// Provides the request's [`CurrentSession`], if any, to the reactive context
+/// of server-side rendering and server functions.
+pub fn provide_current_session() {
+    let session = use_context::<leptos_actix::Request>()
+        .and_then(|req| req.extensions().get::<CurrentSession>().cloned());
+    if let Some(session) = session {
+        provide_context(session);
+    }
+}
+
+pub fn use_current_session() -> Option<CurrentSession> {
+    use_context::<CurrentSession>()
+}
+
+/// Returns the signed in user making the current request.
+pub fn use_current_user() -> Option<user::Model> {
+    use_current_session().map(|session| session.user)
+}
+
+/// Returns the session store provided to the reactive context by the server.
+pub fn use_session_store() -> Result<SessionStore, ServerFnErrorErr> {
+    use_context::<SessionStore>()
+        .ok_or_else(|| ServerFnErrorErr::ServerError("session store not provided".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread::sleep;
+
+    use super::*;
+
+    const TTL: Duration = Duration::from_millis(300);
+
+    #[actix_web::test]
+    async fn sessions_expire_unless_used() {
+        let store = SessionStore::memory(TTL);
+        let used = store.create(1).await.unwrap();
+        let idle = store.create(2).await.unwrap();
+
+        for _ in 0..3 {
+            sleep(TTL / 2);
+            let session = store.touch(&used).await.unwrap();
+            assert_eq!(session.map(|session| session.user_id), Some(1));
+        }
+        assert!(store.touch(&idle).await.unwrap().is_none());
+
+        sleep(TTL + TTL / 2);
+        assert!(store.touch(&used).await.unwrap().is_none());
+    }
+
+    #[actix_web::test]
+    async fn destroy_ends_only_that_session() {
+        let store = SessionStore::memory(TTL);
+        let signed_out = store.create(1).await.unwrap();
+        let other = store.create(1).await.unwrap();
+
+        store.destroy(&signed_out).await.unwrap();
+
+        assert!(store.touch(&signed_out).await.unwrap().is_none());
+        assert!(store.touch(&other).await.unwrap().is_some());
+    }
+
+    #[test]
+    fn session_cookies_are_signed() {
+        let key = Key::generate();
+        let id = random_token();
+        let cookie = sign(&key, Cookie::new(SESSION_COOKIE, id.clone()));
+        assert_ne!(cookie.value(), id);
+
+        assert_eq!(verified_session_id(&key, cookie.clone()), Some(id.clone()));
+        assert_eq!(verified_session_id(&Key::generate(), cookie.clone()), None);
+
+        // another session id with the original signature
+        let value = cookie.value();
+        let (signature, _) = value.split_at(value.len() - id.len());
+        let forged = format!("{signature}{}", random_token());
+        assert_eq!(
+            verified_session_id(&key, Cookie::new(SESSION_COOKIE, forged)),
+            None
+        );
+
+        let unsigned = Cookie::new(SESSION_COOKIE, random_token());
+        assert_eq!(verified_session_id(&key, unsigned), None);
+    }
+}
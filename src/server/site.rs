@@ -0,0 +1,22 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use entity::{prelude::User, user};
+use sea_orm::{ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter};
+
+use super::config::SiteConfig;
+
+/// Loads the user the site belongs to, whose content is shown publicly.
+pub async fn find_owner<C: ConnectionTrait>(
+    db: &C,
+    config: &SiteConfig,
+) -> Result<user::Model, DbErr> {
+    User::find()
+        .filter(user::Column::Email.eq(&config.owner_email))
+        .one(db)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound(format!("site owner {}", config.owner_email)))
+}
@@ -0,0 +1,380 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Image uploads: `POST /uploads/{target}/{id}` takes a multipart form with the
+//! image in its `image` field, stores its resized variants and records the URL
+//! of the widest one on the row picked by [`UploadTarget`].
+//!
+//! The route answers with the [`UploadedImage`] as JSON, or redirects plain
+//! HTML forms to their `return_to` field.
+
+use actix_multipart::{Field, Multipart};
+use actix_web::{HttpResponse, http::header, post, web};
+use entity::{
+    blog_post, blog_post_comment, blog_post_image, blog_series,
+    prelude::{BlogPost, BlogPostComment, BlogSeries, Project},
+    project_image, user,
+};
+use futures_util::TryStreamExt;
+use sea_orm::{ActiveModelTrait, ActiveValue::Set, DatabaseConnection, EntityTrait};
+
+use crate::{
+    api::{
+        ApiError,
+        admin::URL_LENGTH,
+        auth::safe_return_to,
+        comments::is_editable_by,
+        uploads::{UploadTarget, UploadedImage},
+    },
+    utils::images::variant_url,
+};
+
+use super::{
+    config::Config,
+    guards::{ActiveUser, Role},
+    images::{self, ImageError, Variant},
+    oauth::random_token,
+    storage::Storage,
+};
+
+/// Matches the `project_image.image_type` column length.
+const IMAGE_TYPE_MAX_LENGTH: usize = 100;
+/// Largest accepted text field, e.g. `return_to`.
+const TEXT_FIELD_MAX_SIZE: usize = 2048;
+/// Most fields read from a form.
+const MAX_FIELDS: usize = 8;
+
+#[derive(Default)]
+struct UploadForm {
+    /// Declared MIME type and content of the image.
+    image: Option<(String, Vec<u8>)>,
+    image_type: Option<String>,
+    return_to: Option<String>,
+}
+
+fn bad_request(message: impl Into<String>) -> ApiError {
+    ApiError::BadRequest(message.into())
+}
+
+fn internal(err: impl std::fmt::Display) -> ApiError {
+    log::error!("upload failed: {err}");
+    ApiError::Internal("Something went wrong, please try again later.".to_string())
+}
+
+fn not_found(target: UploadTarget) -> ApiError {
+    ApiError::NotFound(
+        match target {
+            UploadTarget::ProjectImage => "Project not found.",
+            UploadTarget::PostImage | UploadTarget::PostCover => "Post not found.",
+            UploadTarget::SeriesCover => "Series not found.",
+            UploadTarget::CommentImage => "Comment not found.",
+        }
+        .to_string(),
+    )
+}
+
+fn role(target: UploadTarget) -> Role {
+    match target {
+        UploadTarget::CommentImage => Role::Active,
+        UploadTarget::ProjectImage
+        | UploadTarget::PostImage
+        | UploadTarget::PostCover
+        | UploadTarget::SeriesCover => Role::Staff,
+    }
+}
+
+/// Checks `user` can attach an image to row `id`, before reading the upload.
+async fn authorize(
+    db: &DatabaseConnection,
+    config: &Config,
+    user: &user::Model,
+    target: UploadTarget,
+    id: i32,
+) -> Result<(), ApiError> {
+    if !role(target).allows(user) {
+        return Err(ApiError::Forbidden(
+            "You don't have permission to do this.".to_string(),
+        ));
+    }
+
+    let exists = match target {
+        UploadTarget::ProjectImage => Project::find_by_id(id).one(db).await?.is_some(),
+        UploadTarget::PostImage | UploadTarget::PostCover => {
+            BlogPost::find_by_id(id).one(db).await?.is_some()
+        }
+        UploadTarget::SeriesCover => BlogSeries::find_by_id(id).one(db).await?.is_some(),
+        UploadTarget::CommentImage => {
+            let comment = BlogPostComment::find_by_id(id)
+                .one(db)
+                .await?
+                .ok_or_else(|| not_found(target))?;
+            if !is_editable_by(&comment, user, config) {
+                return Err(ApiError::Forbidden(
+                    "You can only add images to your own comments while you can edit them."
+                        .to_string(),
+                ));
+            }
+            true
+        }
+    };
+    if exists {
+        Ok(())
+    } else {
+        Err(not_found(target))
+    }
+}
+
+/// Reads a field, `None` once it exceeds `max_size` bytes.
+async fn read_field(field: &mut Field, max_size: usize) -> Result<Option<Vec<u8>>, ApiError> {
+    let mut bytes = Vec::new();
+    while let Some(chunk) = field
+        .try_next()
+        .await
+        .map_err(|err| bad_request(format!("Invalid upload: {err}.")))?
+    {
+        if bytes.len() + chunk.len() > max_size {
+            return Ok(None);
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(Some(bytes))
+}
+
+async fn read_text(field: &mut Field) -> Result<String, ApiError> {
+    let bytes = read_field(field, TEXT_FIELD_MAX_SIZE)
+        .await?
+        .ok_or_else(|| bad_request("A form field is too long."))?;
+    String::from_utf8(bytes).map_err(|_| bad_request("Form fields must be valid UTF-8."))
+}
+
+async fn read_form(mut payload: Multipart, max_size: usize) -> Result<UploadForm, ApiError> {
+    let mut form = UploadForm::default();
+    let mut fields = 0;
+
+    while let Some(mut field) = payload
+        .try_next()
+        .await
+        .map_err(|err| bad_request(format!("Invalid upload: {err}.")))?
+    {
+        fields += 1;
+        if fields > MAX_FIELDS {
+            return Err(bad_request("The form has too many fields."));
+        }
+
+        let name = field.name().unwrap_or_default().to_string();
+        match name.as_str() {
+            "image" => {
+                let content_type = field
+                    .content_type()
+                    .map(|mime| mime.essence_str().to_string())
+                    .unwrap_or_default();
+                let bytes = read_field(&mut field, max_size).await?.ok_or_else(|| {
+                    bad_request(format!(
+                        "Images must be at most {} MB.",
+                        max_size / (1024 * 1024)
+                    ))
+                })?;
+                // browsers send an empty field when no file was picked
+                if bytes.is_empty() {
+                    continue;
+                }
+                if !images::is_accepted(&content_type) {
+                    return Err(bad_request(
+                        "Only JPEG, PNG, WebP and GIF images can be uploaded.",
+                    ));
+                }
+                form.image = Some((content_type, bytes));
+            }
+            "image_type" => form.image_type = Some(read_text(&mut field).await?),
+            "return_to" => form.return_to = Some(read_text(&mut field).await?),
+            // skipped, still bounded
+            _ => {
+                read_field(&mut field, TEXT_FIELD_MAX_SIZE)
+                    .await?
+                    .ok_or_else(|| bad_request("A form field is too long."))?;
+            }
+        }
+    }
+    Ok(form)
+}
+
+fn image_type(value: Option<String>) -> Result<String, ApiError> {
+    let value = value.unwrap_or_default();
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(bad_request("Image type is required."));
+    }
+    if value.chars().count() > IMAGE_TYPE_MAX_LENGTH {
+        return Err(bad_request(format!(
+            "Image type must have at most {IMAGE_TYPE_MAX_LENGTH} characters."
+        )));
+    }
+    Ok(value.to_lowercase())
+}
+
+async fn remove(storage: &Storage, keys: &[String]) {
+    for key in keys {
+        if let Err(err) = storage.delete(key).await {
+            log::warn!("cannot remove uploaded file {key}: {err}");
+        }
+    }
+}
+
+/// Stores the variants under `base`, returning their keys. Nothing is left
+/// behind when one of them fails.
+async fn store(
+    storage: &Storage,
+    base: &str,
+    variants: Vec<Variant>,
+) -> Result<Vec<String>, ApiError> {
+    let mut keys = Vec::with_capacity(variants.len());
+    for variant in variants {
+        let key = variant_url(base, variant.width, variant.extension);
+        if let Err(err) = storage.put(&key, variant.content_type, variant.bytes).await {
+            remove(storage, &keys).await;
+            return Err(internal(err));
+        }
+        keys.push(key);
+    }
+    Ok(keys)
+}
+
+/// Records `url` on the target row, returning the id of the created image
+/// row, if any.
+async fn attach(
+    db: &DatabaseConnection,
+    user_id: i32,
+    target: UploadTarget,
+    id: i32,
+    image_type: Option<String>,
+    url: String,
+) -> Result<Option<i32>, ApiError> {
+    match target {
+        UploadTarget::ProjectImage => {
+            let image = project_image::ActiveModel {
+                project_id: Set(id),
+                image_url: Set(url),
+                image_type: Set(image_type.unwrap_or_default()),
+                created_by_id: Set(user_id),
+                ..Default::default()
+            }
+            .insert(db)
+            .await?;
+            Ok(Some(image.id))
+        }
+        UploadTarget::PostImage => {
+            let image = blog_post_image::ActiveModel {
+                blog_post_id: Set(id),
+                image_url: Set(url),
+                created_by_id: Set(user_id),
+                ..Default::default()
+            }
+            .insert(db)
+            .await?;
+            Ok(Some(image.id))
+        }
+        UploadTarget::PostCover => {
+            let mut post: blog_post::ActiveModel = BlogPost::find_by_id(id)
+                .one(db)
+                .await?
+                .ok_or_else(|| not_found(target))?
+                .into();
+            post.cover_image_url = Set(Some(url));
+            post.update(db).await?;
+            Ok(None)
+        }
+        UploadTarget::SeriesCover => {
+            let mut series: blog_series::ActiveModel = BlogSeries::find_by_id(id)
+                .one(db)
+                .await?
+                .ok_or_else(|| not_found(target))?
+                .into();
+            series.cover_image_url = Set(Some(url));
+            series.update(db).await?;
+            Ok(None)
+        }
+        UploadTarget::CommentImage => {
+            let mut comment: blog_post_comment::ActiveModel = BlogPostComment::find_by_id(id)
+                .one(db)
+                .await?
+                .ok_or_else(|| not_found(target))?
+                .into();
+            comment.image_url = Set(Some(url));
+            comment.update(db).await?;
+            Ok(None)
+        }
+    }
+}
+
+#[post("/uploads/{target}/{id}")]
+pub async fn upload(
+    path: web::Path<(String, i32)>,
+    user: ActiveUser,
+    payload: Multipart,
+    config: web::Data<Config>,
+    db: web::Data<DatabaseConnection>,
+    storage: web::Data<Storage>,
+) -> Result<HttpResponse, ApiError> {
+    let (target, id) = path.into_inner();
+    let target = target
+        .parse::<UploadTarget>()
+        .map_err(|_| ApiError::NotFound("Not found.".to_string()))?;
+    let ActiveUser(user) = user;
+    authorize(&db, &config, &user, target, id).await?;
+
+    let form = read_form(payload, config.uploads.max_size).await?;
+    let (content_type, bytes) = form
+        .image
+        .ok_or_else(|| bad_request("Choose an image to upload."))?;
+    let image_type = match target {
+        UploadTarget::ProjectImage => Some(image_type(form.image_type)?),
+        _ => None,
+    };
+
+    let variants = web::block(move || images::process(&bytes, &content_type))
+        .await
+        .map_err(internal)?
+        .map_err(|err| match err {
+            ImageError::Encode(_) => internal(err),
+            err => {
+                log::info!("rejected upload of user {}: {err}", user.id);
+                bad_request("The file is not a valid image, or it is too large.")
+            }
+        })?;
+    let base = format!("{}/{id}/{}", target.as_str(), random_token());
+    // variants come narrowest first, with the fallback after the WebP
+    let widest = variants
+        .last()
+        .map(|variant| storage.url(&variant_url(&base, variant.width, variant.extension)))
+        .ok_or_else(|| internal("no image variants"))?;
+    if widest.len() > URL_LENGTH {
+        return Err(internal(format!("image URL {widest} is too long")));
+    }
+
+    let keys = store(&storage, &base, variants).await?;
+    let image_id = match attach(&db, user.id, target, id, image_type, widest.clone()).await {
+        Ok(image_id) => image_id,
+        Err(err) => {
+            remove(&storage, &keys).await;
+            return Err(err);
+        }
+    };
+    log::info!(
+        "user {} uploaded an image to {} {id}",
+        user.id,
+        target.as_str()
+    );
+
+    Ok(match form.return_to {
+        Some(return_to) => HttpResponse::SeeOther()
+            .insert_header((header::LOCATION, safe_return_to(Some(&return_to))))
+            .finish(),
+        None => HttpResponse::Created().json(UploadedImage {
+            id: image_id,
+            url: widest,
+        }),
+    })
+}
@@ -0,0 +1,89 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Optimistic concurrency for entities with a `version` column: an update only
+//! applies if the row still has the version the client last saw, which
+//! `before_save` then increments.
+
+use entity::{user, user_suspension};
+use sea_orm::{
+    ActiveModelBehavior, ActiveModelTrait, ActiveValue::Unchanged, ColumnTrait, ConnectionTrait,
+    DbErr, EntityTrait, IntoActiveModel, QueryFilter, SqlErr,
+};
+
+use crate::api::ApiError;
+
+const CONFLICT_MESSAGE: &str =
+    "Someone else changed this in the meantime. Review the latest values and try again.";
+
+pub trait Versioned: ActiveModelTrait + ActiveModelBehavior + Send {
+    fn version_column() -> <Self::Entity as EntityTrait>::Column;
+
+    fn set_version(&mut self, version: i32);
+}
+
+impl Versioned for user::ActiveModel {
+    fn version_column() -> user::Column {
+        user::Column::Version
+    }
+
+    fn set_version(&mut self, version: i32) {
+        self.version = Unchanged(version);
+    }
+}
+
+impl Versioned for user_suspension::ActiveModel {
+    fn version_column() -> user_suspension::Column {
+        user_suspension::Column::Version
+    }
+
+    fn set_version(&mut self, version: i32) {
+        self.version = Unchanged(version);
+    }
+}
+
+/// Updates `model` if its row is still at version `expected`, incrementing
+/// the version in the same statement, or fails with [`ApiError::Conflict`].
+pub async fn update_versioned<A, C>(
+    db: &C,
+    mut model: A,
+    expected: i32,
+) -> Result<<A::Entity as EntityTrait>::Model, ApiError>
+where
+    A: Versioned,
+    C: ConnectionTrait,
+    <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+{
+    model.set_version(expected);
+    let model = model.before_save(db, false).await?;
+
+    match A::Entity::update(model)
+        .filter(A::version_column().eq(expected))
+        .exec(db)
+        .await
+    {
+        Ok(model) => Ok(model),
+        Err(DbErr::RecordNotUpdated) => Err(ApiError::conflict(CONFLICT_MESSAGE)),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Inserts `model`, reporting a unique constraint violation as a conflict,
+/// i.e. someone else created the row first.
+pub async fn insert_unique<A, C>(
+    db: &C,
+    model: A,
+) -> Result<<A::Entity as EntityTrait>::Model, ApiError>
+where
+    A: ActiveModelTrait + ActiveModelBehavior + Send,
+    C: ConnectionTrait,
+    <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+{
+    model.insert(db).await.map_err(|err| match err.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => ApiError::conflict(CONFLICT_MESSAGE),
+        _ => err.into(),
+    })
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{NaiveDate, NaiveDateTime};
+
+/// Formats a date as shown on the CV, e.g. "FEB, 2024".
+pub fn month_year(date: NaiveDate) -> String {
+    date.format("%b, %Y").to_string().to_uppercase()
+}
+
+/// Formats a date range as shown on the CV, e.g. "JUL, 2023 - FEB, 2024" or
+/// "FEB, 2024 - PRESENT" when there is no end date.
+pub fn date_range(start: NaiveDate, end: Option<NaiveDate>) -> String {
+    match end {
+        Some(end) => format!("{} - {}", month_year(start), month_year(end)),
+        None => format!("{} - PRESENT", month_year(start)),
+    }
+}
+
+/// Formats a timestamp as a publication date, e.g. "12 SEP, 2025".
+pub fn day_month_year(date: NaiveDateTime) -> String {
+    date.format("%d %b, %Y").to_string().to_uppercase()
+}
+
+/// Format of `<input type="date">` values, e.g. "2025-09-12".
+const DATE_INPUT_FORMAT: &str = "%Y-%m-%d";
+
+/// Formats a date as the value of a `date` input.
+pub fn date_input(date: NaiveDate) -> String {
+    date.format(DATE_INPUT_FORMAT).to_string()
+}
+
+/// Parses the value of a `date` input.
+pub fn parse_date_input(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), DATE_INPUT_FORMAT).ok()
+}
+
+/// Format of `<input type="datetime-local">` values, e.g. "2025-09-12T18:30".
+const DATETIME_INPUT_FORMAT: &str = "%Y-%m-%dT%H:%M";
+
+/// Formats a timestamp as the value of a `datetime-local` input.
+pub fn datetime_input(date: NaiveDateTime) -> String {
+    date.format(DATETIME_INPUT_FORMAT).to_string()
+}
+
+/// Parses the value of a `datetime-local` input.
+pub fn parse_datetime_input(value: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value.trim(), DATETIME_INPUT_FORMAT).ok()
+}
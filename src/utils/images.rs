@@ -0,0 +1,114 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Naming of the resized variants of uploaded images.
+//!
+//! Each upload is stored as `{key}-{width}.webp` plus a `{key}-{width}.jpg`
+//! (or `.png` for images with transparency) fallback for every width of
+//! [`VARIANT_WIDTHS`] narrower than the image, and for the image's own width
+//! capped at the widest one. The rows only keep the URL of the widest fallback,
+//! the other variants are derived from it.
+
+/// Widths, in pixels, images are resized to.
+pub const VARIANT_WIDTHS: [u32; 3] = [480, 960, 1920];
+
+/// Extensions of the fallback variants, next to the WebP ones.
+pub const FALLBACK_EXTENSIONS: [&str; 2] = ["jpg", "png"];
+
+pub fn variant_url(base: &str, width: u32, extension: &str) -> String {
+    format!("{base}-{width}.{extension}")
+}
+
+/// Widths of the variants of an image `width` pixels wide, narrowest first.
+pub fn variant_widths(width: u32) -> Vec<u32> {
+    let widest = width.min(VARIANT_WIDTHS[VARIANT_WIDTHS.len() - 1]);
+    let mut widths = VARIANT_WIDTHS
+        .into_iter()
+        .filter(|variant| *variant < widest)
+        .collect::<Vec<_>>();
+    widths.push(widest);
+    widths
+}
+
+/// `srcset` values of an uploaded image's variants.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Srcsets {
+    pub webp: String,
+    pub fallback: String,
+}
+
+/// Derives the variants of an uploaded image from the URL of its widest
+/// fallback, `None` for URLs not following the upload naming, e.g. external
+/// images.
+pub fn srcsets(url: &str) -> Option<Srcsets> {
+    let (stem, extension) = url.rsplit_once('.')?;
+    if !FALLBACK_EXTENSIONS.contains(&extension) {
+        return None;
+    }
+    let (base, width) = stem.rsplit_once('-')?;
+    let widths = variant_widths(width.parse().ok()?);
+
+    let srcset = |extension: &str| {
+        widths
+            .iter()
+            .map(|width| format!("{} {width}w", variant_url(base, *width, extension)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    Some(Srcsets {
+        webp: srcset("webp"),
+        fallback: srcset(extension),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variant_widths_stop_at_the_image_width() {
+        assert_eq!(variant_widths(300), [300]);
+        assert_eq!(variant_widths(480), [480]);
+        assert_eq!(variant_widths(1200), [480, 960, 1200]);
+        assert_eq!(variant_widths(4000), [480, 960, 1920]);
+    }
+
+    #[test]
+    fn srcsets_list_every_variant() {
+        let srcsets = srcsets("https://cdn.example.com/post-image/42/token-1200.jpg").unwrap();
+
+        assert_eq!(
+            srcsets.webp,
+            "https://cdn.example.com/post-image/42/token-480.webp 480w, \
+             https://cdn.example.com/post-image/42/token-960.webp 960w, \
+             https://cdn.example.com/post-image/42/token-1200.webp 1200w"
+        );
+        assert_eq!(
+            srcsets.fallback,
+            "https://cdn.example.com/post-image/42/token-480.jpg 480w, \
+             https://cdn.example.com/post-image/42/token-960.jpg 960w, \
+             https://cdn.example.com/post-image/42/token-1200.jpg 1200w"
+        );
+    }
+
+    #[test]
+    fn srcsets_keep_the_fallback_extension() {
+        let srcsets = srcsets("/media/project-image/7/token-480.png").unwrap();
+
+        assert_eq!(srcsets.webp, "/media/project-image/7/token-480.webp 480w");
+        assert_eq!(
+            srcsets.fallback,
+            "/media/project-image/7/token-480.png 480w"
+        );
+    }
+
+    #[test]
+    fn srcsets_ignore_other_images() {
+        assert_eq!(srcsets("https://example.com/diagram.svg"), None);
+        assert_eq!(srcsets("https://example.com/photo.jpg"), None);
+        assert_eq!(srcsets("https://example.com/photo-large.jpg"), None);
+    }
+}
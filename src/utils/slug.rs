@@ -0,0 +1,62 @@
+// Copyright (c) 2025 Afonso Barracha
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+/// Turns a title into a URL friendly slug, e.g. "Hello, World!" into
+/// "hello-world".
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::with_capacity(title.len());
+    for c in title.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Builds the slug of a row addressed by id, e.g. "42-hello-world".
+pub fn id_slug(id: i32, title: &str) -> String {
+    let slug = slugify(title);
+    if slug.is_empty() {
+        id.to_string()
+    } else {
+        format!("{id}-{slug}")
+    }
+}
+
+/// Extracts the id from a slug built by [`id_slug`].
+pub fn parse_id_slug(slug: &str) -> Option<i32> {
+    slug.split('-').next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_keeps_lowercase_words() {
+        assert_eq!(slugify("Hello, World!"), "hello-world");
+        assert_eq!(slugify("  Rust & WebAssembly  "), "rust-webassembly");
+        assert_eq!(slugify("Café au lait"), "café-au-lait");
+        assert_eq!(slugify("!!!"), "");
+    }
+
+    #[test]
+    fn id_slug_round_trips() {
+        assert_eq!(id_slug(42, "Hello, World!"), "42-hello-world");
+        assert_eq!(id_slug(7, "!!!"), "7");
+        assert_eq!(parse_id_slug("42-hello-world"), Some(42));
+        assert_eq!(parse_id_slug("7"), Some(7));
+    }
+
+    #[test]
+    fn parse_id_slug_rejects_other_slugs() {
+        assert_eq!(parse_id_slug("hello-world"), None);
+        assert_eq!(parse_id_slug(""), None);
+        assert_eq!(parse_id_slug("-42"), None);
+    }
+}